@@ -0,0 +1,59 @@
+//! Domain type for the amount of fungible tokens moved by the ICS20 application.
+
+use std::convert::TryFrom;
+use std::fmt;
+
+use ibc_proto::cosmos::base::v1beta1::Coin as RawCoin;
+
+use crate::application::ics20_fungible_token_transfer::error::{Error, Kind};
+
+/// An amount of tokens of a single denomination, as held in the bank of the host chain.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Coin {
+    pub denom: String,
+    pub amount: u64,
+}
+
+impl Coin {
+    pub fn new(denom: impl Into<String>, amount: u64) -> Self {
+        Self {
+            denom: denom.into(),
+            amount,
+        }
+    }
+}
+
+impl fmt::Display for Coin {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}", self.amount, self.denom)
+    }
+}
+
+impl TryFrom<RawCoin> for Coin {
+    type Error = Error;
+
+    fn try_from(raw: RawCoin) -> Result<Self, Self::Error> {
+        if raw.denom.trim().is_empty() {
+            return Err(Kind::InvalidDenomination(raw.denom).into());
+        }
+
+        let amount = raw
+            .amount
+            .parse::<u64>()
+            .map_err(|e| Kind::InvalidTokenAmount(raw.amount.clone()).context(e))?;
+
+        Ok(Coin {
+            denom: raw.denom,
+            amount,
+        })
+    }
+}
+
+impl From<Coin> for RawCoin {
+    fn from(coin: Coin) -> Self {
+        RawCoin {
+            denom: coin.denom,
+            amount: coin.amount.to_string(),
+        }
+    }
+}
@@ -1,5 +1,29 @@
+use crate::application::ics20_fungible_token_transfer::coin::Coin;
+use crate::application::ics20_fungible_token_transfer::denom::DenomTrace;
+use crate::application::ics20_fungible_token_transfer::error::Error;
 use crate::ics04_channel::context::{ChannelKeeper, ChannelReader};
+use crate::ics24_host::identifier::{ChannelId, PortId};
+use crate::signer::Signer;
 
 /// Captures all the dependencies which the ICS20 module requires to be able to dispatch and
-/// process IBC messages.
-pub trait Ics20Context: ChannelReader + ChannelKeeper + Clone {}
+/// process IBC messages. Besides the channel store, this includes the hooks into the bank module
+/// of the host chain, which the application uses to escrow, mint, and burn tokens.
+pub trait Ics20Context: ChannelReader + ChannelKeeper + Clone {
+    /// Returns the address of the account holding the tokens escrowed on the given channel end.
+    fn get_channel_escrow_address(&self, port_id: &PortId, channel_id: &ChannelId) -> Signer;
+
+    /// Returns the denomination trace whose hash is `denom_hash`, if any.
+    fn get_denom_trace(&self, denom_hash: &str) -> Option<DenomTrace>;
+
+    /// Stores the given denomination trace, indexed by its hash.
+    fn store_denom_trace(&mut self, denom_trace: DenomTrace) -> Result<(), Error>;
+
+    /// Transfers `amount` from the account `from` to the account `to`.
+    fn send_coins(&mut self, from: &Signer, to: &Signer, amount: &Coin) -> Result<(), Error>;
+
+    /// Creates `amount` new tokens and credits them to `account`.
+    fn mint_coins(&mut self, account: &Signer, amount: &Coin) -> Result<(), Error>;
+
+    /// Removes `amount` tokens from `account` and destroys them.
+    fn burn_coins(&mut self, account: &Signer, amount: &Coin) -> Result<(), Error>;
+}
@@ -0,0 +1,219 @@
+//! Denomination tracing for ICS20 fungible tokens, as described in
+//! [ADR 001](https://github.com/cosmos/ibc-go/blob/main/docs/architecture/adr-001-coin-source-tracing.md).
+
+use std::convert::TryFrom;
+use std::fmt;
+
+use sha2::{Digest, Sha256};
+use subtle_encoding::{Encoding, Hex};
+
+use ibc_proto::ibc::apps::transfer::v1::DenomTrace as RawDenomTrace;
+
+use crate::application::ics20_fungible_token_transfer::error::{Error, Kind};
+use crate::ics24_host::identifier::{ChannelId, PortId};
+
+/// The prefix of all IBC voucher denominations, i.e., `ibc/{hash}`.
+pub const DENOM_PREFIX: &str = "ibc";
+
+/// Contains the base denomination of a fungible token together with the chain of port/channel
+/// identifiers the token travelled through.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct DenomTrace {
+    /// The chain of port/channel identifiers, e.g., `transfer/channel-0/transfer/channel-4`.
+    pub path: String,
+    /// The base denomination of the token on its source chain, e.g., `uatom`.
+    pub base_denom: String,
+}
+
+impl DenomTrace {
+    /// Parses a full denomination path (e.g., `transfer/channel-0/uatom`) into a `DenomTrace`.
+    /// As in ibc-go, the trace is made of the leading port/channel identifier pairs, and the
+    /// remainder is the base denomination, which may itself contain slashes (e.g., `gamm/pool/1`).
+    pub fn parse(full_denom_path: &str) -> Result<Self, Error> {
+        if full_denom_path.trim().is_empty() {
+            return Err(Kind::InvalidDenomination(full_denom_path.to_string()).into());
+        }
+
+        let parts: Vec<&str> = full_denom_path.split('/').collect();
+
+        let mut path_len = 0;
+        while path_len + 2 < parts.len()
+            && !parts[path_len].is_empty()
+            && is_channel_id(parts[path_len + 1])
+        {
+            path_len += 2;
+        }
+
+        let base_denom = parts[path_len..].join("/");
+        if base_denom.is_empty() {
+            return Err(Kind::InvalidDenomination(full_denom_path.to_string()).into());
+        }
+
+        Ok(DenomTrace {
+            path: parts[..path_len].join("/"),
+            base_denom,
+        })
+    }
+
+    /// Returns the full denomination path, i.e., `{path}/{base_denom}`, or only the base
+    /// denomination if this token has not travelled through any channel.
+    pub fn get_full_denom_path(&self) -> String {
+        if self.path.is_empty() {
+            self.base_denom.clone()
+        } else {
+            format!("{}/{}", self.path, self.base_denom)
+        }
+    }
+
+    /// Returns the upper-case hex-encoded SHA-256 hash of the full denomination path.
+    pub fn hash(&self) -> String {
+        let digest = Sha256::digest(self.get_full_denom_path().as_bytes());
+        Hex::upper_case().encode_to_string(digest).unwrap()
+    }
+
+    /// Returns the denomination under which this token is held on the local chain: the base
+    /// denomination for native tokens, or `ibc/{hash}` for vouchers.
+    pub fn ibc_denom(&self) -> String {
+        if self.path.is_empty() {
+            self.base_denom.clone()
+        } else {
+            format!("{}/{}", DENOM_PREFIX, self.hash())
+        }
+    }
+}
+
+impl fmt::Display for DenomTrace {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.get_full_denom_path())
+    }
+}
+
+impl TryFrom<RawDenomTrace> for DenomTrace {
+    type Error = Error;
+
+    fn try_from(raw: RawDenomTrace) -> Result<Self, Self::Error> {
+        if raw.base_denom.trim().is_empty() {
+            return Err(Kind::InvalidDenomination(raw.base_denom).into());
+        }
+
+        Ok(DenomTrace {
+            path: raw.path,
+            base_denom: raw.base_denom,
+        })
+    }
+}
+
+impl From<DenomTrace> for RawDenomTrace {
+    fn from(trace: DenomTrace) -> Self {
+        RawDenomTrace {
+            path: trace.path,
+            base_denom: trace.base_denom,
+        }
+    }
+}
+
+/// Returns `true` if `id` has the format of the channel identifiers generated by the
+/// host, i.e., `channel-{n}`.
+fn is_channel_id(id: &str) -> bool {
+    id.strip_prefix(ChannelId::prefix())
+        .and_then(|rest| rest.strip_prefix('-'))
+        .map_or(false, |counter| counter.parse::<u64>().is_ok())
+}
+
+/// Returns the prefix `{port_id}/{channel_id}/` which is prepended to (or stripped from) the
+/// denomination of a token when it crosses the given channel end.
+pub fn get_denom_prefix(port_id: &PortId, channel_id: &ChannelId) -> String {
+    format!("{}/{}/", port_id, channel_id)
+}
+
+/// Returns `true` if the denomination was originally sent from the receiving chain, which is the
+/// case when the denomination is prefixed with the source port and channel of the packet.
+pub fn receiver_chain_is_source(
+    source_port: &PortId,
+    source_channel: &ChannelId,
+    denom: &str,
+) -> bool {
+    denom.starts_with(&get_denom_prefix(source_port, source_channel))
+}
+
+/// Returns `true` if the denomination did not originally come from the receiving chain, i.e., the
+/// sending chain is the source of the token (and the token must be escrowed, not burned).
+pub fn sender_chain_is_source(
+    source_port: &PortId,
+    source_channel: &ChannelId,
+    denom: &str,
+) -> bool {
+    !receiver_chain_is_source(source_port, source_channel, denom)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::{receiver_chain_is_source, sender_chain_is_source, DenomTrace};
+    use crate::ics24_host::identifier::{ChannelId, PortId};
+
+    #[test]
+    fn parse_denom_trace() {
+        let native = DenomTrace::parse("uatom").unwrap();
+        assert_eq!(native.path, "");
+        assert_eq!(native.base_denom, "uatom");
+        assert_eq!(native.ibc_denom(), "uatom");
+
+        let voucher = DenomTrace::parse("transfer/channel-0/transfer/channel-1/uatom").unwrap();
+        assert_eq!(voucher.path, "transfer/channel-0/transfer/channel-1");
+        assert_eq!(voucher.base_denom, "uatom");
+        assert_eq!(
+            voucher.get_full_denom_path(),
+            "transfer/channel-0/transfer/channel-1/uatom"
+        );
+
+        assert!(DenomTrace::parse("").is_err());
+        assert!(DenomTrace::parse("transfer/channel-0/").is_err());
+    }
+
+    #[test]
+    fn parse_denom_trace_with_slashes_in_base_denom() {
+        let native = DenomTrace::parse("gamm/pool/1").unwrap();
+        assert_eq!(native.path, "");
+        assert_eq!(native.base_denom, "gamm/pool/1");
+        assert_eq!(native.ibc_denom(), "gamm/pool/1");
+
+        let voucher =
+            DenomTrace::parse("transfer/channel-0/transfer/channel-1/gamm/pool/1").unwrap();
+        assert_eq!(voucher.path, "transfer/channel-0/transfer/channel-1");
+        assert_eq!(voucher.base_denom, "gamm/pool/1");
+        assert_eq!(
+            voucher.get_full_denom_path(),
+            "transfer/channel-0/transfer/channel-1/gamm/pool/1"
+        );
+    }
+
+    #[test]
+    fn denom_trace_hash() {
+        // Reference value computed by the Cosmos SDK for `transfer/channel-0/uatom`.
+        let trace = DenomTrace::parse("transfer/channel-0/uatom").unwrap();
+        assert_eq!(
+            trace.ibc_denom(),
+            "ibc/27394FB092D2ECCD56123C74F36E4C1F926001CEADA9CA97EA622B25F41E5EB2"
+        );
+    }
+
+    #[test]
+    fn denom_source() {
+        let port = PortId::from_str("transfer").unwrap();
+        let channel = ChannelId::from_str("channel-0").unwrap();
+
+        assert!(sender_chain_is_source(&port, &channel, "uatom"));
+        assert!(sender_chain_is_source(
+            &port,
+            &channel,
+            "transfer/channel-1/uatom"
+        ));
+        assert!(receiver_chain_is_source(
+            &port,
+            &channel,
+            "transfer/channel-0/uatom"
+        ));
+    }
+}
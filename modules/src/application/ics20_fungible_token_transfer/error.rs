@@ -2,6 +2,7 @@ use anomaly::{BoxError, Context};
 use thiserror::Error;
 
 use crate::ics24_host::identifier::{ChannelId, PortId};
+use crate::signer::Signer;
 
 pub type Error = anomaly::Error<Kind>;
 
@@ -26,6 +27,30 @@ pub enum Kind {
 
     #[error("invalid packet timeout timestamp value")]
     InvalidPacketTimestamp(u64),
+
+    #[error("missing token in transfer message")]
+    MissingToken,
+
+    #[error("invalid token amount {0}")]
+    InvalidTokenAmount(String),
+
+    #[error("invalid token denomination {0}")]
+    InvalidDenomination(String),
+
+    #[error("denomination trace not found for hash {0}")]
+    DenomTraceNotFound(String),
+
+    #[error("invalid fungible token packet data")]
+    InvalidPacketData,
+
+    #[error("invalid fungible token transfer acknowledgement")]
+    InvalidAcknowledgement,
+
+    #[error("insufficient funds: account {0} cannot spend {1}")]
+    InsufficientFunds(Signer, String),
+
+    #[error("error raised by the bank of the host chain")]
+    BankError,
 }
 
 impl Kind {
@@ -1,5 +1,14 @@
 //! ICS 20: IBC Transfer implementation
+pub mod coin;
 pub mod context;
+pub mod denom;
 pub mod error;
 pub mod msgs;
+pub mod packet;
 pub mod relay_application_logic;
+
+/// The port to which the ICS20 application is bound.
+pub const PORT_ID: &str = "transfer";
+
+/// The channel version negotiated by the ICS20 application.
+pub const VERSION: &str = "ics20-1";
@@ -0,0 +1,164 @@
+//! Payload and acknowledgement of the packets exchanged by the ICS20 application.
+
+use std::convert::TryFrom;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use ibc_proto::ibc::apps::transfer::v1::FungibleTokenPacketData as RawFungibleTokenPacketData;
+
+use crate::application::ics20_fungible_token_transfer::error::{Error, Kind};
+use crate::signer::Signer;
+
+/// The successful acknowledgement result, i.e., the base64 encoding of `[1]`.
+pub const ACK_SUCCESS_RESULT: &str = "AQ==";
+
+/// The data carried by an ICS20 packet. Serialized as (sorted) JSON, which is the encoding used
+/// by the Cosmos SDK transfer module.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FungibleTokenPacketData {
+    /// The amount of tokens to be transferred.
+    #[serde(serialize_with = "ser_amount", deserialize_with = "de_amount")]
+    pub amount: u64,
+    /// The full denomination path of the tokens, e.g., `transfer/channel-0/uatom`.
+    pub denom: String,
+    /// The recipient address on the destination chain.
+    pub receiver: Signer,
+    /// The sender address on the source chain.
+    pub sender: Signer,
+}
+
+impl FungibleTokenPacketData {
+    pub fn validate_basic(&self) -> Result<(), Error> {
+        if self.amount == 0 {
+            return Err(Kind::InvalidTokenAmount(self.amount.to_string()).into());
+        }
+        if self.denom.trim().is_empty() {
+            return Err(Kind::InvalidDenomination(self.denom.clone()).into());
+        }
+        if self.sender.as_str().trim().is_empty() || self.receiver.as_str().trim().is_empty() {
+            return Err(Kind::InvalidPacketData.into());
+        }
+        Ok(())
+    }
+
+    /// Encodes this packet data into the bytes placed in `Packet::data`.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        serde_json::to_vec(self).unwrap()
+    }
+
+    /// Decodes and validates the packet data found in `Packet::data`.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        let data: Self =
+            serde_json::from_slice(bytes).map_err(|e| Kind::InvalidPacketData.context(e))?;
+        data.validate_basic()?;
+        Ok(data)
+    }
+}
+
+impl TryFrom<RawFungibleTokenPacketData> for FungibleTokenPacketData {
+    type Error = Error;
+
+    fn try_from(raw: RawFungibleTokenPacketData) -> Result<Self, Self::Error> {
+        let data = FungibleTokenPacketData {
+            amount: raw.amount,
+            denom: raw.denom,
+            receiver: raw.receiver.into(),
+            sender: raw.sender.into(),
+        };
+        data.validate_basic()?;
+        Ok(data)
+    }
+}
+
+impl From<FungibleTokenPacketData> for RawFungibleTokenPacketData {
+    fn from(data: FungibleTokenPacketData) -> Self {
+        RawFungibleTokenPacketData {
+            denom: data.denom,
+            amount: data.amount,
+            sender: data.sender.to_string(),
+            receiver: data.receiver.to_string(),
+        }
+    }
+}
+
+/// Protobuf JSON encodes `uint64` values as strings.
+fn ser_amount<S: Serializer>(amount: &u64, serializer: S) -> Result<S::Ok, S::Error> {
+    amount.to_string().serialize(serializer)
+}
+
+fn de_amount<'de, D: Deserializer<'de>>(deserializer: D) -> Result<u64, D::Error> {
+    String::deserialize(deserializer)?
+        .parse()
+        .map_err(serde::de::Error::custom)
+}
+
+/// The acknowledgement written by the ICS20 application upon receiving a packet.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Acknowledgement {
+    /// The tokens were successfully credited to the receiver.
+    Result(String),
+    /// The packet could not be processed; the tokens must be refunded to the sender.
+    Error(String),
+}
+
+impl Acknowledgement {
+    pub fn success() -> Self {
+        Acknowledgement::Result(ACK_SUCCESS_RESULT.to_string())
+    }
+
+    pub fn from_error(e: impl ToString) -> Self {
+        Acknowledgement::Error(e.to_string())
+    }
+
+    pub fn is_successful(&self) -> bool {
+        matches!(self, Acknowledgement::Result(_))
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        serde_json::to_vec(self).unwrap()
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        serde_json::from_slice(bytes).map_err(|e| Kind::InvalidAcknowledgement.context(e).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Acknowledgement, FungibleTokenPacketData};
+
+    #[test]
+    fn packet_data_json() {
+        let data = FungibleTokenPacketData {
+            amount: 100,
+            denom: "transfer/channel-0/uatom".to_string(),
+            receiver: "cosmos1receiver".to_string().into(),
+            sender: "cosmos1sender".to_string().into(),
+        };
+
+        let bytes = data.to_bytes();
+        assert_eq!(
+            String::from_utf8(bytes.clone()).unwrap(),
+            r#"{"amount":"100","denom":"transfer/channel-0/uatom","receiver":"cosmos1receiver","sender":"cosmos1sender"}"#
+        );
+        assert_eq!(FungibleTokenPacketData::from_bytes(&bytes).unwrap(), data);
+
+        let zero = FungibleTokenPacketData { amount: 0, ..data };
+        assert!(FungibleTokenPacketData::from_bytes(&zero.to_bytes()).is_err());
+        assert!(FungibleTokenPacketData::from_bytes(&[0]).is_err());
+    }
+
+    #[test]
+    fn acknowledgement_json() {
+        let success = Acknowledgement::success();
+        assert_eq!(success.to_bytes(), br#"{"result":"AQ=="}"#.to_vec());
+        assert!(Acknowledgement::from_bytes(&success.to_bytes())
+            .unwrap()
+            .is_successful());
+
+        let error = Acknowledgement::from_bytes(br#"{"error":"insufficient funds"}"#).unwrap();
+        assert_eq!(error, Acknowledgement::from_error("insufficient funds"));
+        assert!(!error.is_successful());
+    }
+}
@@ -0,0 +1,76 @@
+use crate::application::ics20_fungible_token_transfer::context::Ics20Context;
+use crate::application::ics20_fungible_token_transfer::error::Error;
+use crate::application::ics20_fungible_token_transfer::packet::Acknowledgement;
+use crate::application::ics20_fungible_token_transfer::relay_application_logic::on_timeout_packet::refund_packet_token;
+use crate::ics04_channel::packet::Packet;
+
+/// Application callback for an acknowledged ICS20 packet. If the receiving chain failed to
+/// process the packet, the tokens are refunded to the sender.
+pub(crate) fn on_acknowledgement_packet<Ctx>(
+    ctx: &mut Ctx,
+    packet: &Packet,
+    acknowledgement: &[u8],
+) -> Result<(), Error>
+where
+    Ctx: Ics20Context,
+{
+    match Acknowledgement::from_bytes(acknowledgement)? {
+        Acknowledgement::Result(_) => Ok(()),
+        Acknowledgement::Error(_) => refund_packet_token(ctx, packet),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use crate::application::ics20_fungible_token_transfer::coin::Coin;
+    use crate::application::ics20_fungible_token_transfer::context::Ics20Context;
+    use crate::application::ics20_fungible_token_transfer::packet::{
+        Acknowledgement, FungibleTokenPacketData,
+    };
+    use crate::application::ics20_fungible_token_transfer::relay_application_logic::on_ack_packet::on_acknowledgement_packet;
+    use crate::ics04_channel::packet::Packet;
+    use crate::ics24_host::identifier::{ChannelId, PortId};
+    use crate::mock::context::MockContext;
+    use crate::test_utils::get_dummy_account_id;
+
+    #[test]
+    fn error_ack_refunds_sender() {
+        let source_port = PortId::from_str("transfer").unwrap();
+        let source_channel = ChannelId::from_str("channel-0").unwrap();
+        let sender = get_dummy_account_id();
+
+        let escrow =
+            MockContext::default().get_channel_escrow_address(&source_port, &source_channel);
+        let mut ctx = MockContext::default().with_balance(escrow.clone(), Coin::new("uatom", 10));
+
+        let data = FungibleTokenPacketData {
+            amount: 10,
+            denom: "uatom".to_string(),
+            receiver: "cosmos1receiver".to_string().into(),
+            sender: sender.clone(),
+        };
+        let packet = Packet {
+            sequence: 1.into(),
+            source_port,
+            source_channel,
+            destination_port: PortId::from_str("transfer").unwrap(),
+            destination_channel: ChannelId::from_str("channel-1").unwrap(),
+            data: data.to_bytes(),
+            timeout_height: Default::default(),
+            timeout_timestamp: Default::default(),
+        };
+
+        let success = Acknowledgement::success().to_bytes();
+        on_acknowledgement_packet(&mut ctx, &packet, &success).unwrap();
+        assert_eq!(ctx.balance(&sender, "uatom"), 0);
+
+        let error = Acknowledgement::from_error("failed").to_bytes();
+        on_acknowledgement_packet(&mut ctx, &packet, &error).unwrap();
+        assert_eq!(ctx.balance(&sender, "uatom"), 10);
+        assert_eq!(ctx.balance(&escrow, "uatom"), 0);
+
+        assert!(on_acknowledgement_packet(&mut ctx, &packet, &[0]).is_err());
+    }
+}
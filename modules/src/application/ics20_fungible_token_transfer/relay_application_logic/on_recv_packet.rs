@@ -0,0 +1,137 @@
+use crate::application::ics20_fungible_token_transfer::coin::Coin;
+use crate::application::ics20_fungible_token_transfer::context::Ics20Context;
+use crate::application::ics20_fungible_token_transfer::denom::{
+    get_denom_prefix, receiver_chain_is_source, DenomTrace,
+};
+use crate::application::ics20_fungible_token_transfer::error::Error;
+use crate::application::ics20_fungible_token_transfer::packet::{
+    Acknowledgement, FungibleTokenPacketData,
+};
+use crate::ics04_channel::packet::Packet;
+
+/// Application callback for a received ICS20 packet. Credits the receiver and returns the
+/// acknowledgement to be written for the packet. Failures of the application logic do not abort
+/// the transaction; they are reported to the sending chain as an error acknowledgement instead.
+pub(crate) fn on_recv_packet<Ctx>(ctx: &mut Ctx, packet: &Packet) -> Acknowledgement
+where
+    Ctx: Ics20Context,
+{
+    match process_recv_packet(ctx, packet) {
+        Ok(()) => Acknowledgement::success(),
+        Err(e) => Acknowledgement::from_error(e),
+    }
+}
+
+fn process_recv_packet<Ctx>(ctx: &mut Ctx, packet: &Packet) -> Result<(), Error>
+where
+    Ctx: Ics20Context,
+{
+    let data = FungibleTokenPacketData::from_bytes(&packet.data)?;
+
+    if receiver_chain_is_source(&packet.source_port, &packet.source_channel, &data.denom) {
+        // The token originated on this chain and is now coming back: remove the prefix added by
+        // the sending chain and release the corresponding amount from the escrow account.
+        let prefix = get_denom_prefix(&packet.source_port, &packet.source_channel);
+        let denom = DenomTrace::parse(&data.denom[prefix.len()..])?.ibc_denom();
+        let escrow_address =
+            ctx.get_channel_escrow_address(&packet.destination_port, &packet.destination_channel);
+
+        ctx.send_coins(
+            &escrow_address,
+            &data.receiver,
+            &Coin::new(denom, data.amount),
+        )
+    } else {
+        // The sending chain is the source: mint vouchers whose trace records this channel end.
+        let prefixed_denom = format!(
+            "{}{}",
+            get_denom_prefix(&packet.destination_port, &packet.destination_channel),
+            data.denom
+        );
+        let denom_trace = DenomTrace::parse(&prefixed_denom)?;
+        let voucher = Coin::new(denom_trace.ibc_denom(), data.amount);
+
+        if ctx.get_denom_trace(&denom_trace.hash()).is_none() {
+            ctx.store_denom_trace(denom_trace)?;
+        }
+
+        ctx.mint_coins(&data.receiver, &voucher)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use crate::application::ics20_fungible_token_transfer::coin::Coin;
+    use crate::application::ics20_fungible_token_transfer::context::Ics20Context;
+    use crate::application::ics20_fungible_token_transfer::denom::DenomTrace;
+    use crate::application::ics20_fungible_token_transfer::packet::FungibleTokenPacketData;
+    use crate::application::ics20_fungible_token_transfer::relay_application_logic::on_recv_packet::on_recv_packet;
+    use crate::ics04_channel::packet::Packet;
+    use crate::ics24_host::identifier::{ChannelId, PortId};
+    use crate::mock::context::MockContext;
+    use crate::test_utils::get_dummy_account_id;
+
+    fn transfer_packet(denom: &str) -> Packet {
+        let data = FungibleTokenPacketData {
+            amount: 10,
+            denom: denom.to_string(),
+            receiver: get_dummy_account_id(),
+            sender: "cosmos1sender".to_string().into(),
+        };
+
+        Packet {
+            sequence: 1.into(),
+            source_port: PortId::from_str("transfer").unwrap(),
+            source_channel: ChannelId::from_str("channel-1").unwrap(),
+            destination_port: PortId::from_str("transfer").unwrap(),
+            destination_channel: ChannelId::from_str("channel-0").unwrap(),
+            data: data.to_bytes(),
+            timeout_height: Default::default(),
+            timeout_timestamp: Default::default(),
+        }
+    }
+
+    #[test]
+    fn recv_mints_voucher() {
+        let mut ctx = MockContext::default();
+        let packet = transfer_packet("uatom");
+
+        let ack = on_recv_packet(&mut ctx, &packet);
+        assert!(ack.is_successful());
+
+        let trace = DenomTrace::parse("transfer/channel-0/uatom").unwrap();
+        assert_eq!(ctx.get_denom_trace(&trace.hash()), Some(trace.clone()));
+        assert_eq!(ctx.balance(&get_dummy_account_id(), &trace.ibc_denom()), 10);
+    }
+
+    #[test]
+    fn recv_unescrows_native_token() {
+        let escrow = MockContext::default().get_channel_escrow_address(
+            &PortId::from_str("transfer").unwrap(),
+            &ChannelId::from_str("channel-0").unwrap(),
+        );
+        let mut ctx = MockContext::default().with_balance(escrow.clone(), Coin::new("uatom", 15));
+        let packet = transfer_packet("transfer/channel-1/uatom");
+
+        let ack = on_recv_packet(&mut ctx, &packet);
+        assert!(ack.is_successful());
+        assert_eq!(ctx.balance(&escrow, "uatom"), 5);
+        assert_eq!(ctx.balance(&get_dummy_account_id(), "uatom"), 10);
+
+        // The escrow account no longer holds enough tokens.
+        let ack = on_recv_packet(&mut ctx, &packet);
+        assert!(!ack.is_successful());
+        assert_eq!(ctx.balance(&escrow, "uatom"), 5);
+    }
+
+    #[test]
+    fn recv_malformed_packet_data() {
+        let mut ctx = MockContext::default();
+        let mut packet = transfer_packet("uatom");
+        packet.data = vec![0];
+
+        assert!(!on_recv_packet(&mut ctx, &packet).is_successful());
+    }
+}
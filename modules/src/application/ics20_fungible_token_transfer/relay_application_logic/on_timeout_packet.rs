@@ -0,0 +1,34 @@
+use crate::application::ics20_fungible_token_transfer::coin::Coin;
+use crate::application::ics20_fungible_token_transfer::context::Ics20Context;
+use crate::application::ics20_fungible_token_transfer::denom::{
+    sender_chain_is_source, DenomTrace,
+};
+use crate::application::ics20_fungible_token_transfer::error::Error;
+use crate::application::ics20_fungible_token_transfer::packet::FungibleTokenPacketData;
+use crate::ics04_channel::packet::Packet;
+
+/// Application callback for an ICS20 packet which timed out: the tokens are refunded to the sender.
+pub(crate) fn on_timeout_packet<Ctx>(ctx: &mut Ctx, packet: &Packet) -> Result<(), Error>
+where
+    Ctx: Ics20Context,
+{
+    refund_packet_token(ctx, packet)
+}
+
+/// Reverts the effects of `send_transfer` for the given packet, either by releasing the escrowed
+/// tokens or by minting back the vouchers that were burned.
+pub(crate) fn refund_packet_token<Ctx>(ctx: &mut Ctx, packet: &Packet) -> Result<(), Error>
+where
+    Ctx: Ics20Context,
+{
+    let data = FungibleTokenPacketData::from_bytes(&packet.data)?;
+    let token = Coin::new(DenomTrace::parse(&data.denom)?.ibc_denom(), data.amount);
+
+    if sender_chain_is_source(&packet.source_port, &packet.source_channel, &data.denom) {
+        let escrow_address =
+            ctx.get_channel_escrow_address(&packet.source_port, &packet.source_channel);
+        ctx.send_coins(&escrow_address, &data.sender, &token)
+    } else {
+        ctx.mint_coins(&data.sender, &token)
+    }
+}
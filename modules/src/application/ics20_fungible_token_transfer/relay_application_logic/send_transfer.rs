@@ -1,6 +1,13 @@
+use std::convert::TryFrom;
+
+use crate::application::ics20_fungible_token_transfer::coin::Coin;
 use crate::application::ics20_fungible_token_transfer::context::Ics20Context;
+use crate::application::ics20_fungible_token_transfer::denom::{
+    sender_chain_is_source, DENOM_PREFIX,
+};
 use crate::application::ics20_fungible_token_transfer::error::{Error, Kind};
 use crate::application::ics20_fungible_token_transfer::msgs::transfer::MsgTransfer;
+use crate::application::ics20_fungible_token_transfer::packet::FungibleTokenPacketData;
 use crate::handler::HandlerOutput;
 use crate::ics04_channel::handler::send_packet::send_packet;
 use crate::ics04_channel::packet::Packet;
@@ -8,12 +15,18 @@ use crate::ics04_channel::packet::PacketResult;
 use crate::timestamp::Timestamp;
 
 pub(crate) fn send_transfer<Ctx>(
-    ctx: &Ctx,
+    ctx: &mut Ctx,
     msg: MsgTransfer,
 ) -> Result<HandlerOutput<PacketResult>, Error>
 where
     Ctx: Ics20Context,
 {
+    let token = msg.token.clone().ok_or(Kind::MissingToken)?;
+    let token = Coin::try_from(token)?;
+    if token.amount == 0 {
+        return Err(Kind::InvalidTokenAmount(token.amount.to_string()).into());
+    }
+
     let source_channel_end = ctx
         .channel_end(&(msg.source_port.clone(), msg.source_channel.clone()))
         .ok_or_else(|| {
@@ -38,22 +51,176 @@ where
     let timeout_timestamp = Timestamp::from_nanoseconds(msg.timeout_timestamp)
         .map_err(|_| Kind::InvalidPacketTimestamp(msg.timeout_timestamp))?;
 
-    //TODO: Application LOGIC.
+    // Vouchers are held under their `ibc/{hash}` denomination, but the packet must carry the
+    // full denomination path so that the receiving chain can trace the token back to its source.
+    let full_denom_path = match token
+        .denom
+        .strip_prefix(DENOM_PREFIX)
+        .and_then(|d| d.strip_prefix('/'))
+    {
+        Some(hash) => ctx
+            .get_denom_trace(hash)
+            .ok_or_else(|| Kind::DenomTraceNotFound(hash.to_string()))?
+            .get_full_denom_path(),
+        None => token.denom.clone(),
+    };
+
+    let packet_data = FungibleTokenPacketData {
+        amount: token.amount,
+        denom: full_denom_path,
+        receiver: msg.receiver.clone(),
+        sender: msg.sender.clone(),
+    };
+    packet_data.validate_basic()?;
 
     let packet = Packet {
         sequence,
-        source_port: msg.source_port,
-        source_channel: msg.source_channel,
+        source_port: msg.source_port.clone(),
+        source_channel: msg.source_channel.clone(),
         destination_port,
         destination_channel: destination_channel.clone(),
-        data: vec![0],
+        data: packet_data.to_bytes(),
         timeout_height: msg.timeout_height,
         timeout_timestamp,
     };
 
-    let handler_output =
-        send_packet(ctx, packet).map_err(|e| Kind::HandlerRaisedError.context(e))?;
+    let mut handler_output =
+        send_packet(&*ctx, packet).map_err(|e| Kind::HandlerRaisedError.context(e))?;
+
+    // The packet is valid, so the tokens can now leave the sender account: native tokens are
+    // escrowed on the channel, vouchers coming back towards their source chain are burned.
+    if sender_chain_is_source(&msg.source_port, &msg.source_channel, &packet_data.denom) {
+        let escrow_address = ctx.get_channel_escrow_address(&msg.source_port, &msg.source_channel);
+        ctx.send_coins(&msg.sender, &escrow_address, &token)?;
+        handler_output
+            .log
+            .push(format!("success: escrowed {} from {}", token, msg.sender));
+    } else {
+        ctx.burn_coins(&msg.sender, &token)?;
+        handler_output
+            .log
+            .push(format!("success: burned {} from {}", token, msg.sender));
+    }
 
-    //TODO:  add event/atributes and writes to the store issued by the application logic for packet sending.
     Ok(handler_output)
 }
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::application::ics20_fungible_token_transfer::coin::Coin;
+    use crate::application::ics20_fungible_token_transfer::context::Ics20Context;
+    use crate::application::ics20_fungible_token_transfer::denom::DenomTrace;
+    use crate::application::ics20_fungible_token_transfer::msgs::transfer::test_util::get_dummy_msg_transfer;
+    use crate::application::ics20_fungible_token_transfer::packet::FungibleTokenPacketData;
+    use crate::application::ics20_fungible_token_transfer::relay_application_logic::send_transfer::send_transfer;
+    use crate::ics02_client::height::Height;
+    use crate::ics03_connection::connection::ConnectionEnd;
+    use crate::ics03_connection::connection::Counterparty as ConnectionCounterparty;
+    use crate::ics03_connection::connection::State as ConnectionState;
+    use crate::ics03_connection::version::get_compatible_versions;
+    use crate::ics04_channel::channel::{ChannelEnd, Counterparty, Order, State};
+    use crate::ics04_channel::packet::PacketResult;
+    use crate::ics24_host::identifier::{ChannelId, ClientId, ConnectionId, PortId};
+    use crate::mock::context::MockContext;
+    use crate::test_utils::get_dummy_account_id;
+
+    fn context_with_channel() -> MockContext {
+        let channel_end = ChannelEnd::new(
+            State::Open,
+            Order::default(),
+            Counterparty::new(PortId::default(), Some(ChannelId::default())),
+            vec![ConnectionId::default()],
+            "ics20-1".to_string(),
+        );
+
+        let connection_end = ConnectionEnd::new(
+            ConnectionState::Open,
+            ClientId::default(),
+            ConnectionCounterparty::new(
+                ClientId::default(),
+                Some(ConnectionId::default()),
+                Default::default(),
+            ),
+            get_compatible_versions(),
+            Duration::from_secs(0),
+        );
+
+        MockContext::default()
+            .with_client(&ClientId::default(), Height::default())
+            .with_connection(ConnectionId::default(), connection_end)
+            .with_port_capability(PortId::default())
+            .with_channel(PortId::default(), ChannelId::default(), channel_end)
+            .with_send_sequence(PortId::default(), ChannelId::default(), 1.into())
+    }
+
+    fn packet_data(result: &PacketResult) -> FungibleTokenPacketData {
+        match result {
+            PacketResult::Send(res) => FungibleTokenPacketData::from_bytes(&res.data).unwrap(),
+            _ => panic!("unexpected packet result"),
+        }
+    }
+
+    #[test]
+    fn send_native_token_escrows() {
+        let sender = get_dummy_account_id();
+        let mut ctx = context_with_channel().with_balance(sender.clone(), Coin::new("uatom", 100));
+
+        let output = send_transfer(&mut ctx, get_dummy_msg_transfer(10)).unwrap();
+        assert_eq!(packet_data(&output.result).denom, "uatom");
+        assert_eq!(packet_data(&output.result).amount, 10);
+
+        let escrow = ctx.get_channel_escrow_address(&PortId::default(), &ChannelId::default());
+        assert_eq!(ctx.balance(&sender, "uatom"), 90);
+        assert_eq!(ctx.balance(&escrow, "uatom"), 10);
+    }
+
+    #[test]
+    fn send_voucher_burns() {
+        let sender = get_dummy_account_id();
+        let trace = DenomTrace::parse(&format!(
+            "{}/{}/uatom",
+            PortId::default(),
+            ChannelId::default()
+        ))
+        .unwrap();
+
+        let mut ctx =
+            context_with_channel().with_balance(sender.clone(), Coin::new(trace.ibc_denom(), 50));
+        ctx.store_denom_trace(trace.clone()).unwrap();
+
+        let mut msg = get_dummy_msg_transfer(10);
+        msg.token = Some(Coin::new(trace.ibc_denom(), 20).into());
+
+        let output = send_transfer(&mut ctx, msg).unwrap();
+        assert_eq!(
+            packet_data(&output.result).denom,
+            trace.get_full_denom_path()
+        );
+
+        let escrow = ctx.get_channel_escrow_address(&PortId::default(), &ChannelId::default());
+        assert_eq!(ctx.balance(&sender, &trace.ibc_denom()), 30);
+        assert_eq!(ctx.balance(&escrow, &trace.ibc_denom()), 0);
+    }
+
+    #[test]
+    fn send_transfer_failures() {
+        let sender = get_dummy_account_id();
+
+        // The sender does not have enough funds.
+        let mut ctx = context_with_channel().with_balance(sender.clone(), Coin::new("uatom", 5));
+        assert!(send_transfer(&mut ctx, get_dummy_msg_transfer(10)).is_err());
+        assert_eq!(ctx.balance(&sender, "uatom"), 5);
+
+        // The message carries no token.
+        let mut msg = get_dummy_msg_transfer(10);
+        msg.token = None;
+        assert!(send_transfer(&mut ctx, msg).is_err());
+
+        // The voucher denomination has no known trace.
+        let mut msg = get_dummy_msg_transfer(10);
+        msg.token = Some(Coin::new("ibc/DEADBEEF", 1).into());
+        assert!(send_transfer(&mut ctx, msg).is_err());
+    }
+}
@@ -1,13 +1,21 @@
+use ibc_proto::ibc::core::commitment::v1::MerkleProof;
+
 use crate::downcast;
 use crate::ics02_client::client_consensus::{AnyConsensusState, ConsensusState};
 use crate::ics02_client::client_state::{AnyClientState, ClientState};
 use crate::ics02_client::client_type::ClientType;
+use crate::ics02_client::context::ClientReader;
 use crate::ics02_client::error::Kind;
 use crate::ics02_client::header::{AnyHeader, Header};
+use crate::ics02_client::misbehaviour::{AnyMisbehaviour, Misbehaviour};
 use crate::ics03_connection::connection::ConnectionEnd;
+use crate::ics03_connection::context::ConnectionReader;
 use crate::ics04_channel::channel::ChannelEnd;
-use crate::ics04_channel::packet::Sequence;
+use crate::ics04_channel::context::ChannelReader;
+use crate::ics04_channel::packet::{Packet, Sequence};
+use crate::ics06_solomachine::client_def::SoloMachineClient;
 use crate::ics07_tendermint::client_def::TendermintClient;
+use crate::ics09_localhost::client_def::LocalhostClient;
 use crate::ics23_commitment::commitment::{CommitmentPrefix, CommitmentProofBytes, CommitmentRoot};
 use crate::ics24_host::identifier::{ChannelId, ClientId, ConnectionId, PortId};
 use crate::Height;
@@ -19,40 +27,79 @@ pub trait ClientDef: Clone {
     type Header: Header;
     type ClientState: ClientState;
     type ConsensusState: ConsensusState;
+    type Misbehaviour: Misbehaviour;
 
-    /// TODO
+    /// Validates an incoming `header` against the latest consensus state of this client, with
+    /// read access to the host chain through `ctx`. On success, returns the new client state and
+    /// the consensus state that the client should store for the height of the header.
     fn check_header_and_update_state(
         &self,
+        ctx: &dyn ClientReader,
         client_state: Self::ClientState,
         header: Self::Header,
     ) -> Result<(Self::ClientState, Self::ConsensusState), Box<dyn std::error::Error>>;
 
+    /// Checks that `misbehaviour` is valid evidence of misbehaviour of the chain tracked by the
+    /// client `client_id`, whose trusted consensus states are read from `ctx`. On success, returns
+    /// the client state, frozen at the height of the misbehaviour.
+    fn check_misbehaviour_and_update_state(
+        &self,
+        ctx: &dyn ClientReader,
+        client_id: ClientId,
+        client_state: Self::ClientState,
+        misbehaviour: Self::Misbehaviour,
+    ) -> Result<Self::ClientState, Box<dyn std::error::Error>>;
+
+    /// Verifies the proofs that `upgraded_client_state` and `upgraded_consensus_state` have been
+    /// committed by the counterparty chain under its upgrade path, against the root of the given
+    /// (latest) `consensus_state` of the client. On success, returns the client state and
+    /// consensus state that the client should store after the upgrade.
+    fn verify_upgrade_and_update_state(
+        &self,
+        client_state: &Self::ClientState,
+        consensus_state: &Self::ConsensusState,
+        upgraded_client_state: Self::ClientState,
+        upgraded_consensus_state: Self::ConsensusState,
+        proof_upgrade_client: &MerkleProof,
+        proof_upgrade_consensus_state: &MerkleProof,
+    ) -> Result<(Self::ClientState, Self::ConsensusState), Box<dyn std::error::Error>>;
+
     /// Verification functions as specified in:
     /// https://github.com/cosmos/ics/tree/master/spec/ics-002-client-semantics
     ///
     /// Verify a `proof` that the consensus state of a given client (at height `consensus_height`)
     /// matches the input `consensus_state`. The parameter `counterparty_height` represent the
     /// height of the counterparty chain that this proof assumes (i.e., the height at which this
-    /// proof was computed).
+    /// proof was computed). The `root` is the commitment root of the consensus state that this
+    /// client stores at `height`, against which all proofs are checked.
+    ///
+    /// All verification functions get read access to the host chain through `ctx`; clients which
+    /// verify the state of the host chain itself (e.g., the localhost client) check it directly
+    /// instead of checking the `proof`.
     #[allow(clippy::too_many_arguments)]
     fn verify_client_consensus_state(
         &self,
+        ctx: &dyn ConnectionReader,
         client_state: &Self::ClientState,
         height: Height,
         prefix: &CommitmentPrefix,
         proof: &CommitmentProofBytes,
+        root: &CommitmentRoot,
         client_id: &ClientId,
         consensus_height: Height,
         expected_consensus_state: &AnyConsensusState,
     ) -> Result<(), Box<dyn std::error::Error>>;
 
     /// Verify a `proof` that a connection state matches that of the input `connection_end`.
+    #[allow(clippy::too_many_arguments)]
     fn verify_connection_state(
         &self,
+        ctx: &dyn ConnectionReader,
         client_state: &Self::ClientState,
         height: Height,
         prefix: &CommitmentPrefix,
         proof: &CommitmentProofBytes,
+        root: &CommitmentRoot,
         connection_id: Option<&ConnectionId>,
         expected_connection_end: &ConnectionEnd,
     ) -> Result<(), Box<dyn std::error::Error>>;
@@ -61,10 +108,12 @@ pub trait ClientDef: Clone {
     #[allow(clippy::too_many_arguments)]
     fn verify_channel_state(
         &self,
+        ctx: &dyn ChannelReader,
         client_state: &Self::ClientState,
         height: Height,
         prefix: &CommitmentPrefix,
         proof: &CommitmentProofBytes,
+        root: &CommitmentRoot,
         port_id: &PortId,
         channel_id: &ChannelId,
         expected_channel_end: &ChannelEnd,
@@ -74,6 +123,7 @@ pub trait ClientDef: Clone {
     #[allow(clippy::too_many_arguments)]
     fn verify_client_full_state(
         &self,
+        ctx: &dyn ConnectionReader,
         _client_state: &Self::ClientState,
         height: Height,
         root: &CommitmentRoot,
@@ -83,29 +133,31 @@ pub trait ClientDef: Clone {
         client_state: &AnyClientState,
     ) -> Result<(), Box<dyn std::error::Error>>;
 
-    /// Verify a `proof` that a packet has been commited.
+    /// Verify a `proof` that the `packet` has been committed to by the sending chain.
     #[allow(clippy::too_many_arguments)]
     fn verify_packet_data(
         &self,
+        ctx: &dyn ChannelReader,
         client_state: &Self::ClientState,
         height: Height,
+        prefix: &CommitmentPrefix,
         proof: &CommitmentProofBytes,
-        port_id: &PortId,
-        channel_id: &ChannelId,
-        seq: &Sequence,
-        commitment: String,
+        root: &CommitmentRoot,
+        packet: &Packet,
     ) -> Result<(), Box<dyn std::error::Error>>;
 
-    /// Verify a `proof` that a packet has been commited.
+    /// Verify a `proof` that the acknowledgement `ack` of the `packet` has been written by the
+    /// receiving chain.
     #[allow(clippy::too_many_arguments)]
     fn verify_packet_acknowledgement(
         &self,
+        ctx: &dyn ChannelReader,
         client_state: &Self::ClientState,
         height: Height,
+        prefix: &CommitmentPrefix,
         proof: &CommitmentProofBytes,
-        port_id: &PortId,
-        channel_id: &ChannelId,
-        seq: &Sequence,
+        root: &CommitmentRoot,
+        packet: &Packet,
         ack: Vec<u8>,
     ) -> Result<(), Box<dyn std::error::Error>>;
 
@@ -113,9 +165,12 @@ pub trait ClientDef: Clone {
     #[allow(clippy::too_many_arguments)]
     fn verify_next_sequence_recv(
         &self,
+        ctx: &dyn ChannelReader,
         client_state: &Self::ClientState,
         height: Height,
+        prefix: &CommitmentPrefix,
         proof: &CommitmentProofBytes,
+        root: &CommitmentRoot,
         port_id: &PortId,
         channel_id: &ChannelId,
         seq: &Sequence,
@@ -125,18 +180,33 @@ pub trait ClientDef: Clone {
     #[allow(clippy::too_many_arguments)]
     fn verify_packet_receipt_absence(
         &self,
+        ctx: &dyn ChannelReader,
         client_state: &Self::ClientState,
         height: Height,
+        prefix: &CommitmentPrefix,
         proof: &CommitmentProofBytes,
+        root: &CommitmentRoot,
         port_id: &PortId,
         channel_id: &ChannelId,
         seq: &Sequence,
     ) -> Result<(), Box<dyn std::error::Error>>;
+
+    /// Returns the client state to store after one of the `verify_*` methods above succeeded
+    /// against `client_state`, or `None` if verification leaves the client state unchanged (the
+    /// default). Solo machines, for instance, advance their sequence on every verified proof.
+    fn update_state_after_verification(
+        &self,
+        _client_state: &Self::ClientState,
+    ) -> Result<Option<Self::ClientState>, Box<dyn std::error::Error>> {
+        Ok(None)
+    }
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum AnyClient {
     Tendermint(TendermintClient),
+    SoloMachine(SoloMachineClient),
+    Localhost(LocalhostClient),
 
     #[cfg(any(test, feature = "mocks"))]
     Mock(MockClient),
@@ -146,6 +216,8 @@ impl AnyClient {
     pub fn from_client_type(client_type: ClientType) -> AnyClient {
         match client_type {
             ClientType::Tendermint => Self::Tendermint(TendermintClient),
+            ClientType::SoloMachine => Self::SoloMachine(SoloMachineClient),
+            ClientType::Localhost => Self::Localhost(LocalhostClient),
 
             #[cfg(any(test, feature = "mocks"))]
             ClientType::Mock => Self::Mock(MockClient),
@@ -158,10 +230,12 @@ impl ClientDef for AnyClient {
     type Header = AnyHeader;
     type ClientState = AnyClientState;
     type ConsensusState = AnyConsensusState;
+    type Misbehaviour = AnyMisbehaviour;
 
     /// Validates an incoming `header` against the latest consensus state of this client.
     fn check_header_and_update_state(
         &self,
+        ctx: &dyn ClientReader,
         client_state: AnyClientState,
         header: AnyHeader,
     ) -> Result<(AnyClientState, AnyConsensusState), Box<dyn std::error::Error>> {
@@ -174,7 +248,7 @@ impl ClientDef for AnyClient {
                 .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::Tendermint))?;
 
                 let (new_state, new_consensus) =
-                    client.check_header_and_update_state(client_state, header)?;
+                    client.check_header_and_update_state(ctx, client_state, header)?;
 
                 Ok((
                     AnyClientState::Tendermint(new_state),
@@ -182,6 +256,32 @@ impl ClientDef for AnyClient {
                 ))
             }
 
+            Self::SoloMachine(client) => {
+                let (client_state, header) = downcast!(
+                    client_state => AnyClientState::SoloMachine,
+                    header => AnyHeader::SoloMachine,
+                )
+                .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::SoloMachine))?;
+
+                let (new_state, new_consensus) =
+                    client.check_header_and_update_state(ctx, client_state, header)?;
+
+                Ok((
+                    AnyClientState::SoloMachine(new_state),
+                    AnyConsensusState::SoloMachine(new_consensus),
+                ))
+            }
+
+            Self::Localhost(client) => {
+                let client_state = downcast!(client_state => AnyClientState::Localhost)
+                    .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::Localhost))?;
+
+                let (new_state, new_consensus) =
+                    client.check_header_and_update_state(ctx, client_state, header)?;
+
+                Ok((AnyClientState::Localhost(new_state), new_consensus))
+            }
+
             #[cfg(any(test, feature = "mocks"))]
             Self::Mock(client) => {
                 let (client_state, header) = downcast!(
@@ -191,7 +291,196 @@ impl ClientDef for AnyClient {
                 .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::Mock))?;
 
                 let (new_state, new_consensus) =
-                    client.check_header_and_update_state(client_state, header)?;
+                    client.check_header_and_update_state(ctx, client_state, header)?;
+
+                Ok((
+                    AnyClientState::Mock(new_state),
+                    AnyConsensusState::Mock(new_consensus),
+                ))
+            }
+        }
+    }
+
+    fn check_misbehaviour_and_update_state(
+        &self,
+        ctx: &dyn ClientReader,
+        client_id: ClientId,
+        client_state: AnyClientState,
+        misbehaviour: AnyMisbehaviour,
+    ) -> Result<AnyClientState, Box<dyn std::error::Error>> {
+        match self {
+            Self::Tendermint(client) => {
+                let (client_state, misbehaviour) = downcast!(
+                    client_state => AnyClientState::Tendermint,
+                    misbehaviour => AnyMisbehaviour::Tendermint,
+                )
+                .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::Tendermint))?;
+
+                let new_state = client.check_misbehaviour_and_update_state(
+                    ctx,
+                    client_id,
+                    client_state,
+                    misbehaviour,
+                )?;
+
+                Ok(AnyClientState::Tendermint(new_state))
+            }
+
+            Self::SoloMachine(client) => {
+                let (client_state, misbehaviour) = downcast!(
+                    client_state => AnyClientState::SoloMachine,
+                    misbehaviour => AnyMisbehaviour::SoloMachine,
+                )
+                .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::SoloMachine))?;
+
+                let new_state = client.check_misbehaviour_and_update_state(
+                    ctx,
+                    client_id,
+                    client_state,
+                    misbehaviour,
+                )?;
+
+                Ok(AnyClientState::SoloMachine(new_state))
+            }
+
+            Self::Localhost(client) => {
+                let client_state = downcast!(client_state => AnyClientState::Localhost)
+                    .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::Localhost))?;
+
+                let new_state = client.check_misbehaviour_and_update_state(
+                    ctx,
+                    client_id,
+                    client_state,
+                    misbehaviour,
+                )?;
+
+                Ok(AnyClientState::Localhost(new_state))
+            }
+
+            #[cfg(any(test, feature = "mocks"))]
+            Self::Mock(client) => {
+                let (client_state, misbehaviour) = downcast!(
+                    client_state => AnyClientState::Mock,
+                    misbehaviour => AnyMisbehaviour::Mock,
+                )
+                .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::Mock))?;
+
+                let new_state = client.check_misbehaviour_and_update_state(
+                    ctx,
+                    client_id,
+                    client_state,
+                    misbehaviour,
+                )?;
+
+                Ok(AnyClientState::Mock(new_state))
+            }
+        }
+    }
+
+    fn verify_upgrade_and_update_state(
+        &self,
+        client_state: &AnyClientState,
+        consensus_state: &AnyConsensusState,
+        upgraded_client_state: AnyClientState,
+        upgraded_consensus_state: AnyConsensusState,
+        proof_upgrade_client: &MerkleProof,
+        proof_upgrade_consensus_state: &MerkleProof,
+    ) -> Result<(AnyClientState, AnyConsensusState), Box<dyn std::error::Error>> {
+        match self {
+            Self::Tendermint(client) => {
+                let (
+                    client_state,
+                    (consensus_state, (upgraded_client_state, upgraded_consensus_state)),
+                ) = downcast!(
+                    client_state => AnyClientState::Tendermint,
+                    consensus_state => AnyConsensusState::Tendermint,
+                    upgraded_client_state => AnyClientState::Tendermint,
+                    upgraded_consensus_state => AnyConsensusState::Tendermint,
+                )
+                .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::Tendermint))?;
+
+                let (new_state, new_consensus) = client.verify_upgrade_and_update_state(
+                    client_state,
+                    consensus_state,
+                    upgraded_client_state,
+                    upgraded_consensus_state,
+                    proof_upgrade_client,
+                    proof_upgrade_consensus_state,
+                )?;
+
+                Ok((
+                    AnyClientState::Tendermint(new_state),
+                    AnyConsensusState::Tendermint(new_consensus),
+                ))
+            }
+
+            Self::SoloMachine(client) => {
+                let (
+                    client_state,
+                    (consensus_state, (upgraded_client_state, upgraded_consensus_state)),
+                ) = downcast!(
+                    client_state => AnyClientState::SoloMachine,
+                    consensus_state => AnyConsensusState::SoloMachine,
+                    upgraded_client_state => AnyClientState::SoloMachine,
+                    upgraded_consensus_state => AnyConsensusState::SoloMachine,
+                )
+                .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::SoloMachine))?;
+
+                let (new_state, new_consensus) = client.verify_upgrade_and_update_state(
+                    client_state,
+                    consensus_state,
+                    upgraded_client_state,
+                    upgraded_consensus_state,
+                    proof_upgrade_client,
+                    proof_upgrade_consensus_state,
+                )?;
+
+                Ok((
+                    AnyClientState::SoloMachine(new_state),
+                    AnyConsensusState::SoloMachine(new_consensus),
+                ))
+            }
+
+            Self::Localhost(client) => {
+                let (client_state, upgraded_client_state) = downcast!(
+                    client_state => AnyClientState::Localhost,
+                    upgraded_client_state => AnyClientState::Localhost,
+                )
+                .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::Localhost))?;
+
+                let (new_state, new_consensus) = client.verify_upgrade_and_update_state(
+                    client_state,
+                    consensus_state,
+                    upgraded_client_state,
+                    upgraded_consensus_state,
+                    proof_upgrade_client,
+                    proof_upgrade_consensus_state,
+                )?;
+
+                Ok((AnyClientState::Localhost(new_state), new_consensus))
+            }
+
+            #[cfg(any(test, feature = "mocks"))]
+            Self::Mock(client) => {
+                let (
+                    client_state,
+                    (consensus_state, (upgraded_client_state, upgraded_consensus_state)),
+                ) = downcast!(
+                    client_state => AnyClientState::Mock,
+                    consensus_state => AnyConsensusState::Mock,
+                    upgraded_client_state => AnyClientState::Mock,
+                    upgraded_consensus_state => AnyConsensusState::Mock,
+                )
+                .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::Mock))?;
+
+                let (new_state, new_consensus) = client.verify_upgrade_and_update_state(
+                    client_state,
+                    consensus_state,
+                    upgraded_client_state,
+                    upgraded_consensus_state,
+                    proof_upgrade_client,
+                    proof_upgrade_consensus_state,
+                )?;
 
                 Ok((
                     AnyClientState::Mock(new_state),
@@ -203,10 +492,12 @@ impl ClientDef for AnyClient {
 
     fn verify_client_consensus_state(
         &self,
+        ctx: &dyn ConnectionReader,
         client_state: &Self::ClientState,
         height: Height,
         prefix: &CommitmentPrefix,
         proof: &CommitmentProofBytes,
+        root: &CommitmentRoot,
         client_id: &ClientId,
         consensus_height: Height,
         expected_consensus_state: &AnyConsensusState,
@@ -219,10 +510,50 @@ impl ClientDef for AnyClient {
                 .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::Tendermint))?;
 
                 client.verify_client_consensus_state(
+                    ctx,
+                    client_state,
+                    height,
+                    prefix,
+                    proof,
+                    root,
+                    client_id,
+                    consensus_height,
+                    expected_consensus_state,
+                )
+            }
+
+            Self::SoloMachine(client) => {
+                let client_state = downcast!(
+                    client_state => AnyClientState::SoloMachine
+                )
+                .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::SoloMachine))?;
+
+                client.verify_client_consensus_state(
+                    ctx,
+                    client_state,
+                    height,
+                    prefix,
+                    proof,
+                    root,
+                    client_id,
+                    consensus_height,
+                    expected_consensus_state,
+                )
+            }
+
+            Self::Localhost(client) => {
+                let client_state = downcast!(
+                    client_state => AnyClientState::Localhost
+                )
+                .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::Localhost))?;
+
+                client.verify_client_consensus_state(
+                    ctx,
                     client_state,
                     height,
                     prefix,
                     proof,
+                    root,
                     client_id,
                     consensus_height,
                     expected_consensus_state,
@@ -237,10 +568,12 @@ impl ClientDef for AnyClient {
                 .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::Mock))?;
 
                 client.verify_client_consensus_state(
+                    ctx,
                     client_state,
                     height,
                     prefix,
                     proof,
+                    root,
                     client_id,
                     consensus_height,
                     expected_consensus_state,
@@ -251,10 +584,12 @@ impl ClientDef for AnyClient {
 
     fn verify_connection_state(
         &self,
+        ctx: &dyn ConnectionReader,
         client_state: &AnyClientState,
         height: Height,
         prefix: &CommitmentPrefix,
         proof: &CommitmentProofBytes,
+        root: &CommitmentRoot,
         connection_id: Option<&ConnectionId>,
         expected_connection_end: &ConnectionEnd,
     ) -> Result<(), Box<dyn std::error::Error>> {
@@ -264,10 +599,44 @@ impl ClientDef for AnyClient {
                     .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::Tendermint))?;
 
                 client.verify_connection_state(
+                    ctx,
                     client_state,
                     height,
                     prefix,
                     proof,
+                    root,
+                    connection_id,
+                    expected_connection_end,
+                )
+            }
+
+            Self::SoloMachine(client) => {
+                let client_state = downcast!(client_state => AnyClientState::SoloMachine)
+                    .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::SoloMachine))?;
+
+                client.verify_connection_state(
+                    ctx,
+                    client_state,
+                    height,
+                    prefix,
+                    proof,
+                    root,
+                    connection_id,
+                    expected_connection_end,
+                )
+            }
+
+            Self::Localhost(client) => {
+                let client_state = downcast!(client_state => AnyClientState::Localhost)
+                    .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::Localhost))?;
+
+                client.verify_connection_state(
+                    ctx,
+                    client_state,
+                    height,
+                    prefix,
+                    proof,
+                    root,
                     connection_id,
                     expected_connection_end,
                 )
@@ -279,10 +648,12 @@ impl ClientDef for AnyClient {
                     .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::Mock))?;
 
                 client.verify_connection_state(
+                    ctx,
                     client_state,
                     height,
                     prefix,
                     proof,
+                    root,
                     connection_id,
                     expected_connection_end,
                 )
@@ -292,10 +663,12 @@ impl ClientDef for AnyClient {
 
     fn verify_channel_state(
         &self,
+        ctx: &dyn ChannelReader,
         client_state: &AnyClientState,
         height: Height,
         prefix: &CommitmentPrefix,
         proof: &CommitmentProofBytes,
+        root: &CommitmentRoot,
         port_id: &PortId,
         channel_id: &ChannelId,
         expected_channel_end: &ChannelEnd,
@@ -306,10 +679,46 @@ impl ClientDef for AnyClient {
                     .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::Tendermint))?;
 
                 client.verify_channel_state(
+                    ctx,
+                    client_state,
+                    height,
+                    prefix,
+                    proof,
+                    root,
+                    port_id,
+                    channel_id,
+                    expected_channel_end,
+                )
+            }
+
+            Self::SoloMachine(client) => {
+                let client_state = downcast!(client_state => AnyClientState::SoloMachine)
+                    .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::SoloMachine))?;
+
+                client.verify_channel_state(
+                    ctx,
                     client_state,
                     height,
                     prefix,
                     proof,
+                    root,
+                    port_id,
+                    channel_id,
+                    expected_channel_end,
+                )
+            }
+
+            Self::Localhost(client) => {
+                let client_state = downcast!(client_state => AnyClientState::Localhost)
+                    .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::Localhost))?;
+
+                client.verify_channel_state(
+                    ctx,
+                    client_state,
+                    height,
+                    prefix,
+                    proof,
+                    root,
                     port_id,
                     channel_id,
                     expected_channel_end,
@@ -322,10 +731,12 @@ impl ClientDef for AnyClient {
                     .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::Mock))?;
 
                 client.verify_channel_state(
+                    ctx,
                     client_state,
                     height,
                     prefix,
                     proof,
+                    root,
                     port_id,
                     channel_id,
                     expected_channel_end,
@@ -336,6 +747,7 @@ impl ClientDef for AnyClient {
 
     fn verify_client_full_state(
         &self,
+        ctx: &dyn ConnectionReader,
         client_state: &Self::ClientState,
         height: Height,
         root: &CommitmentRoot,
@@ -352,6 +764,43 @@ impl ClientDef for AnyClient {
                 .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::Tendermint))?;
 
                 client.verify_client_full_state(
+                    ctx,
+                    client_state,
+                    height,
+                    root,
+                    prefix,
+                    client_id,
+                    proof,
+                    client_state_on_counterparty,
+                )
+            }
+
+            Self::SoloMachine(client) => {
+                let client_state = downcast!(
+                    client_state => AnyClientState::SoloMachine
+                )
+                .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::SoloMachine))?;
+
+                client.verify_client_full_state(
+                    ctx,
+                    client_state,
+                    height,
+                    root,
+                    prefix,
+                    client_id,
+                    proof,
+                    client_state_on_counterparty,
+                )
+            }
+
+            Self::Localhost(client) => {
+                let client_state = downcast!(
+                    client_state => AnyClientState::Localhost
+                )
+                .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::Localhost))?;
+
+                client.verify_client_full_state(
+                    ctx,
                     client_state,
                     height,
                     root,
@@ -370,6 +819,7 @@ impl ClientDef for AnyClient {
                 .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::Mock))?;
 
                 client.verify_client_full_state(
+                    ctx,
                     client_state,
                     height,
                     root,
@@ -383,13 +833,13 @@ impl ClientDef for AnyClient {
     }
     fn verify_packet_data(
         &self,
+        ctx: &dyn ChannelReader,
         client_state: &Self::ClientState,
         height: Height,
+        prefix: &CommitmentPrefix,
         proof: &CommitmentProofBytes,
-        port_id: &PortId,
-        channel_id: &ChannelId,
-        seq: &Sequence,
-        commitment: String,
+        root: &CommitmentRoot,
+        packet: &Packet,
     ) -> Result<(), Box<dyn std::error::Error>> {
         match self {
             Self::Tendermint(client) => {
@@ -398,15 +848,25 @@ impl ClientDef for AnyClient {
                 )
                 .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::Tendermint))?;
 
-                client.verify_packet_data(
-                    client_state,
-                    height,
-                    proof,
-                    port_id,
-                    channel_id,
-                    seq,
-                    commitment,
+                client.verify_packet_data(ctx, client_state, height, prefix, proof, root, packet)
+            }
+
+            Self::SoloMachine(client) => {
+                let client_state = downcast!(
+                    client_state => AnyClientState::SoloMachine
                 )
+                .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::SoloMachine))?;
+
+                client.verify_packet_data(ctx, client_state, height, prefix, proof, root, packet)
+            }
+
+            Self::Localhost(client) => {
+                let client_state = downcast!(
+                    client_state => AnyClientState::Localhost
+                )
+                .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::Localhost))?;
+
+                client.verify_packet_data(ctx, client_state, height, prefix, proof, root, packet)
             }
 
             #[cfg(any(test, feature = "mocks"))]
@@ -416,27 +876,20 @@ impl ClientDef for AnyClient {
                 )
                 .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::Mock))?;
 
-                client.verify_packet_data(
-                    client_state,
-                    height,
-                    proof,
-                    port_id,
-                    channel_id,
-                    seq,
-                    commitment,
-                )
+                client.verify_packet_data(ctx, client_state, height, prefix, proof, root, packet)
             }
         }
     }
 
     fn verify_packet_acknowledgement(
         &self,
+        ctx: &dyn ChannelReader,
         client_state: &Self::ClientState,
         height: Height,
+        prefix: &CommitmentPrefix,
         proof: &CommitmentProofBytes,
-        port_id: &PortId,
-        channel_id: &ChannelId,
-        seq: &Sequence,
+        root: &CommitmentRoot,
+        packet: &Packet,
         ack: Vec<u8>,
     ) -> Result<(), Box<dyn std::error::Error>> {
         match self {
@@ -447,12 +900,49 @@ impl ClientDef for AnyClient {
                 .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::Tendermint))?;
 
                 client.verify_packet_acknowledgement(
+                    ctx,
                     client_state,
                     height,
+                    prefix,
                     proof,
-                    port_id,
-                    channel_id,
-                    seq,
+                    root,
+                    packet,
+                    ack,
+                )
+            }
+
+            Self::SoloMachine(client) => {
+                let client_state = downcast!(
+                    client_state => AnyClientState::SoloMachine
+                )
+                .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::SoloMachine))?;
+
+                client.verify_packet_acknowledgement(
+                    ctx,
+                    client_state,
+                    height,
+                    prefix,
+                    proof,
+                    root,
+                    packet,
+                    ack,
+                )
+            }
+
+            Self::Localhost(client) => {
+                let client_state = downcast!(
+                    client_state => AnyClientState::Localhost
+                )
+                .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::Localhost))?;
+
+                client.verify_packet_acknowledgement(
+                    ctx,
+                    client_state,
+                    height,
+                    prefix,
+                    proof,
+                    root,
+                    packet,
                     ack,
                 )
             }
@@ -465,12 +955,13 @@ impl ClientDef for AnyClient {
                 .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::Mock))?;
 
                 client.verify_packet_acknowledgement(
+                    ctx,
                     client_state,
                     height,
+                    prefix,
                     proof,
-                    port_id,
-                    channel_id,
-                    seq,
+                    root,
+                    packet,
                     ack,
                 )
             }
@@ -479,9 +970,12 @@ impl ClientDef for AnyClient {
 
     fn verify_next_sequence_recv(
         &self,
+        ctx: &dyn ChannelReader,
         client_state: &Self::ClientState,
         height: Height,
+        prefix: &CommitmentPrefix,
         proof: &CommitmentProofBytes,
+        root: &CommitmentRoot,
         port_id: &PortId,
         channel_id: &ChannelId,
         seq: &Sequence,
@@ -494,9 +988,50 @@ impl ClientDef for AnyClient {
                 .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::Tendermint))?;
 
                 client.verify_next_sequence_recv(
+                    ctx,
                     client_state,
                     height,
+                    prefix,
                     proof,
+                    root,
+                    port_id,
+                    channel_id,
+                    seq,
+                )
+            }
+
+            Self::SoloMachine(client) => {
+                let client_state = downcast!(
+                    client_state => AnyClientState::SoloMachine
+                )
+                .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::SoloMachine))?;
+
+                client.verify_next_sequence_recv(
+                    ctx,
+                    client_state,
+                    height,
+                    prefix,
+                    proof,
+                    root,
+                    port_id,
+                    channel_id,
+                    seq,
+                )
+            }
+
+            Self::Localhost(client) => {
+                let client_state = downcast!(
+                    client_state => AnyClientState::Localhost
+                )
+                .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::Localhost))?;
+
+                client.verify_next_sequence_recv(
+                    ctx,
+                    client_state,
+                    height,
+                    prefix,
+                    proof,
+                    root,
                     port_id,
                     channel_id,
                     seq,
@@ -511,9 +1046,12 @@ impl ClientDef for AnyClient {
                 .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::Mock))?;
 
                 client.verify_next_sequence_recv(
+                    ctx,
                     client_state,
                     height,
+                    prefix,
                     proof,
+                    root,
                     port_id,
                     channel_id,
                     seq,
@@ -523,9 +1061,12 @@ impl ClientDef for AnyClient {
     }
     fn verify_packet_receipt_absence(
         &self,
+        ctx: &dyn ChannelReader,
         client_state: &Self::ClientState,
         height: Height,
+        prefix: &CommitmentPrefix,
         proof: &CommitmentProofBytes,
+        root: &CommitmentRoot,
         port_id: &PortId,
         channel_id: &ChannelId,
         seq: &Sequence,
@@ -538,9 +1079,50 @@ impl ClientDef for AnyClient {
                 .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::Tendermint))?;
 
                 client.verify_packet_receipt_absence(
+                    ctx,
                     client_state,
                     height,
+                    prefix,
                     proof,
+                    root,
+                    port_id,
+                    channel_id,
+                    seq,
+                )
+            }
+
+            Self::SoloMachine(client) => {
+                let client_state = downcast!(
+                    client_state => AnyClientState::SoloMachine
+                )
+                .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::SoloMachine))?;
+
+                client.verify_packet_receipt_absence(
+                    ctx,
+                    client_state,
+                    height,
+                    prefix,
+                    proof,
+                    root,
+                    port_id,
+                    channel_id,
+                    seq,
+                )
+            }
+
+            Self::Localhost(client) => {
+                let client_state = downcast!(
+                    client_state => AnyClientState::Localhost
+                )
+                .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::Localhost))?;
+
+                client.verify_packet_receipt_absence(
+                    ctx,
+                    client_state,
+                    height,
+                    prefix,
+                    proof,
+                    root,
                     port_id,
                     channel_id,
                     seq,
@@ -555,9 +1137,12 @@ impl ClientDef for AnyClient {
                 .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::Mock))?;
 
                 client.verify_packet_receipt_absence(
+                    ctx,
                     client_state,
                     height,
+                    prefix,
                     proof,
+                    root,
                     port_id,
                     channel_id,
                     seq,
@@ -565,4 +1150,56 @@ impl ClientDef for AnyClient {
             }
         }
     }
+
+    fn update_state_after_verification(
+        &self,
+        client_state: &Self::ClientState,
+    ) -> Result<Option<Self::ClientState>, Box<dyn std::error::Error>> {
+        match self {
+            Self::Tendermint(client) => {
+                let client_state = downcast!(
+                    client_state => AnyClientState::Tendermint
+                )
+                .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::Tendermint))?;
+
+                Ok(client
+                    .update_state_after_verification(client_state)?
+                    .map(AnyClientState::Tendermint))
+            }
+
+            Self::SoloMachine(client) => {
+                let client_state = downcast!(
+                    client_state => AnyClientState::SoloMachine
+                )
+                .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::SoloMachine))?;
+
+                Ok(client
+                    .update_state_after_verification(client_state)?
+                    .map(AnyClientState::SoloMachine))
+            }
+
+            Self::Localhost(client) => {
+                let client_state = downcast!(
+                    client_state => AnyClientState::Localhost
+                )
+                .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::Localhost))?;
+
+                Ok(client
+                    .update_state_after_verification(client_state)?
+                    .map(AnyClientState::Localhost))
+            }
+
+            #[cfg(any(test, feature = "mocks"))]
+            Self::Mock(client) => {
+                let client_state = downcast!(
+                    client_state => AnyClientState::Mock
+                )
+                .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::Mock))?;
+
+                Ok(client
+                    .update_state_after_verification(client_state)?
+                    .map(AnyClientState::Mock))
+            }
+        }
+    }
 }
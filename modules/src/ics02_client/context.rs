@@ -6,8 +6,9 @@ use crate::ics02_client::client_consensus::AnyConsensusState;
 use crate::ics02_client::client_state::AnyClientState;
 use crate::ics02_client::client_type::ClientType;
 use crate::ics02_client::error::Error;
-use crate::ics02_client::handler::ClientResult::{self, Create, Update, Upgrade};
+use crate::ics02_client::handler::ClientResult::{self, Create, Misbehaviour, Update, Upgrade};
 use crate::ics24_host::identifier::ClientId;
+use crate::timestamp::Timestamp;
 use crate::Height;
 
 /// Defines the read-only part of ICS2 (client functions) context.
@@ -16,6 +17,15 @@ pub trait ClientReader {
     fn client_state(&self, client_id: &ClientId) -> Option<AnyClientState>;
     fn consensus_state(&self, client_id: &ClientId, height: Height) -> Option<AnyConsensusState>;
 
+    /// Returns the current height of the local chain.
+    fn host_height(&self) -> Height;
+
+    /// Returns the current timestamp of the local chain.
+    fn host_timestamp(&self) -> Timestamp;
+
+    /// Returns the ConsensusState of the host (local) chain at a specific height.
+    fn host_consensus_state(&self, height: Height) -> Option<AnyConsensusState>;
+
     /// Returns a natural number, counting how many clients have been created thus far.
     /// The value of this counter should increase only via method `ClientKeeper::increase_client_counter`.
     fn client_counter(&self) -> u64;
@@ -47,7 +57,19 @@ pub trait ClientKeeper {
                 )?;
                 Ok(())
             }
-            Upgrade(_) => unimplemented!(),
+            Upgrade(res) => {
+                self.store_client_state(res.client_id.clone(), res.client_state.clone())?;
+                self.store_consensus_state(
+                    res.client_id.clone(),
+                    res.client_state.latest_height(),
+                    res.consensus_state,
+                )?;
+                Ok(())
+            }
+            Misbehaviour(res) => {
+                self.store_client_state(res.client_id, res.client_state)?;
+                Ok(())
+            }
         }
     }
 
@@ -58,14 +80,14 @@ pub trait ClientKeeper {
         client_type: ClientType,
     ) -> Result<(), Error>;
 
-    /// Called upon successful client creation and update
+    /// Called upon successful client creation, update and upgrade
     fn store_client_state(
         &mut self,
         client_id: ClientId,
         client_state: AnyClientState,
     ) -> Result<(), Error>;
 
-    /// Called upon successful client creation and update
+    /// Called upon successful client creation, update and upgrade
     fn store_consensus_state(
         &mut self,
         client_id: ClientId,
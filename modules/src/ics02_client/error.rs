@@ -35,6 +35,12 @@ pub enum Kind {
     #[error("header verification failed")]
     HeaderVerificationFailure,
 
+    #[error("misbehaviour handling failed")]
+    MisbehaviourHandlingFailure,
+
+    #[error("upgrade verification failed")]
+    UpgradeVerificationFailure,
+
     #[error("unknown client state type: {0}")]
     UnknownClientStateType(String),
 
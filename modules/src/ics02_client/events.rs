@@ -285,6 +285,12 @@ impl TryFrom<RawObject> for ClientMisbehaviour {
     }
 }
 
+impl From<Attributes> for ClientMisbehaviour {
+    fn from(attrs: Attributes) -> Self {
+        ClientMisbehaviour(attrs)
+    }
+}
+
 impl From<ClientMisbehaviour> for IbcEvent {
     fn from(v: ClientMisbehaviour) -> Self {
         IbcEvent::ClientMisbehaviour(v)
@@ -296,6 +302,15 @@ impl From<ClientMisbehaviour> for IbcEvent {
 pub struct UpgradeClient(Attributes);
 
 impl UpgradeClient {
+    pub fn client_id(&self) -> &ClientId {
+        &self.0.client_id
+    }
+    pub fn height(&self) -> Height {
+        self.0.height
+    }
+    pub fn consensus_height(&self) -> Height {
+        self.0.consensus_height
+    }
     pub fn set_height(&mut self, height: Height) {
         self.0.height = height;
     }
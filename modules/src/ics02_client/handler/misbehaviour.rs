@@ -0,0 +1,166 @@
+//! Protocol logic specific to processing ICS2 messages of type `MsgSubmitAnyMisbehaviour`.
+
+use crate::events::IbcEvent;
+use crate::handler::{HandlerOutput, HandlerResult};
+use crate::ics02_client::client_def::{AnyClient, ClientDef};
+use crate::ics02_client::client_state::{AnyClientState, ClientState};
+use crate::ics02_client::context::ClientReader;
+use crate::ics02_client::error::{Error, Kind};
+use crate::ics02_client::events::Attributes;
+use crate::ics02_client::handler::ClientResult;
+use crate::ics02_client::misbehaviour::Misbehaviour;
+use crate::ics02_client::msgs::misbehavior::MsgSubmitAnyMisbehaviour;
+use crate::ics24_host::identifier::ClientId;
+
+/// The result following the successful processing of a `MsgSubmitAnyMisbehaviour` message.
+/// This data type should be used with a qualified name `misbehaviour::Result` to avoid ambiguity.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Result {
+    pub client_id: ClientId,
+    pub client_state: AnyClientState,
+}
+
+pub fn process(
+    ctx: &dyn ClientReader,
+    msg: MsgSubmitAnyMisbehaviour,
+) -> HandlerResult<ClientResult, Error> {
+    let mut output = HandlerOutput::builder();
+
+    let MsgSubmitAnyMisbehaviour {
+        client_id,
+        misbehaviour,
+        signer: _,
+    } = msg;
+
+    // Read client type from the host chain store. The client should already exist.
+    let client_type = ctx
+        .client_type(&client_id)
+        .ok_or_else(|| Kind::ClientNotFound(client_id.clone()))?;
+
+    let client_def = AnyClient::from_client_type(client_type);
+
+    // Read client state from the host chain store.
+    let client_state = ctx
+        .client_state(&client_id)
+        .ok_or_else(|| Kind::ClientNotFound(client_id.clone()))?;
+
+    if client_state.is_frozen() {
+        return Err(Kind::ClientFrozen(client_id).into());
+    }
+
+    let consensus_height = misbehaviour.height();
+
+    // Check the evidence against the trusted consensus states of the client. This returns the
+    // client state frozen at the height of the misbehaviour, to be persisted by the keeper.
+    let new_client_state = client_def
+        .check_misbehaviour_and_update_state(ctx, client_id.clone(), client_state, misbehaviour)
+        .map_err(|e| Kind::MisbehaviourHandlingFailure.context(e.to_string()))?;
+
+    let result = ClientResult::Misbehaviour(Result {
+        client_id: client_id.clone(),
+        client_state: new_client_state,
+    });
+
+    let event_attributes = Attributes {
+        client_id,
+        client_type,
+        consensus_height,
+        ..Default::default()
+    };
+    output.emit(IbcEvent::ClientMisbehaviour(event_attributes.into()));
+
+    Ok(output.with_result(result))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use crate::events::IbcEvent;
+    use crate::ics02_client::client_state::{AnyClientState, ClientState};
+    use crate::ics02_client::error::Kind;
+    use crate::ics02_client::handler::dispatch;
+    use crate::ics02_client::handler::ClientResult::Misbehaviour;
+    use crate::ics02_client::msgs::misbehavior::MsgSubmitAnyMisbehaviour;
+    use crate::ics02_client::msgs::ClientMsg;
+    use crate::ics24_host::identifier::ClientId;
+    use crate::mock::context::MockContext;
+    use crate::mock::header::MockHeader;
+    use crate::mock::misbehaviour::Misbehaviour as MockMisbehaviour;
+    use crate::test_utils::get_dummy_account_id;
+    use crate::timestamp::Timestamp;
+    use crate::Height;
+
+    fn mock_misbehaviour(client_id: &ClientId, height: Height) -> MockMisbehaviour {
+        MockMisbehaviour {
+            client_id: client_id.clone(),
+            header1: MockHeader::new(height),
+            header2: MockHeader {
+                height,
+                timestamp: Timestamp::from_nanoseconds(1).unwrap(),
+            },
+        }
+    }
+
+    #[test]
+    fn test_misbehaviour_freezes_client() {
+        let client_id = ClientId::default();
+        let ctx = MockContext::default().with_client(&client_id, Height::new(0, 42));
+
+        let msg = MsgSubmitAnyMisbehaviour {
+            client_id: client_id.clone(),
+            misbehaviour: mock_misbehaviour(&client_id, Height::new(0, 42)).into(),
+            signer: get_dummy_account_id(),
+        };
+
+        let output = dispatch(&ctx, ClientMsg::Misbehaviour(msg)).unwrap();
+
+        assert_eq!(output.events.len(), 1);
+        assert!(
+            matches!(&output.events[0], IbcEvent::ClientMisbehaviour(e) if e.client_id() == &client_id)
+        );
+
+        match output.result {
+            Misbehaviour(res) => {
+                assert_eq!(res.client_id, client_id);
+                assert!(res.client_state.is_frozen());
+                assert!(matches!(res.client_state, AnyClientState::Mock(_)));
+            }
+            _ => panic!("misbehaviour handler result has incorrect type"),
+        }
+    }
+
+    #[test]
+    fn test_misbehaviour_invalid_evidence() {
+        let client_id = ClientId::default();
+        let ctx = MockContext::default().with_client(&client_id, Height::new(0, 42));
+
+        let mut misbehaviour = mock_misbehaviour(&client_id, Height::new(0, 42));
+        misbehaviour.header2 = misbehaviour.header1;
+
+        let msg = MsgSubmitAnyMisbehaviour {
+            client_id,
+            misbehaviour: misbehaviour.into(),
+            signer: get_dummy_account_id(),
+        };
+
+        let err = dispatch(&ctx, ClientMsg::Misbehaviour(msg)).unwrap_err();
+        assert_eq!(err.kind(), &Kind::MisbehaviourHandlingFailure);
+    }
+
+    #[test]
+    fn test_misbehaviour_nonexisting_client() {
+        let client_id = ClientId::from_str("mockclient1").unwrap();
+        let ctx = MockContext::default().with_client(&client_id, Height::new(0, 42));
+
+        let unknown_client_id = ClientId::from_str("nonexistingclient").unwrap();
+        let msg = MsgSubmitAnyMisbehaviour {
+            client_id: unknown_client_id.clone(),
+            misbehaviour: mock_misbehaviour(&unknown_client_id, Height::new(0, 42)).into(),
+            signer: get_dummy_account_id(),
+        };
+
+        let err = dispatch(&ctx, ClientMsg::Misbehaviour(msg)).unwrap_err();
+        assert_eq!(err.kind(), &Kind::ClientNotFound(unknown_client_id));
+    }
+}
@@ -1,11 +1,13 @@
 //! Protocol logic specific to processing ICS2 messages of type `MsgUpgradeAnyClient`.
 //!
-use crate::handler::HandlerResult;
+use crate::events::IbcEvent;
+use crate::handler::{HandlerOutput, HandlerResult};
 use crate::ics02_client::client_consensus::AnyConsensusState;
-use crate::ics02_client::client_state::AnyClientState;
-use crate::ics02_client::client_state::ClientState;
+use crate::ics02_client::client_def::{AnyClient, ClientDef};
+use crate::ics02_client::client_state::{AnyClientState, ClientState};
 use crate::ics02_client::context::ClientReader;
 use crate::ics02_client::error::{Error, Kind};
+use crate::ics02_client::events::Attributes;
 use crate::ics02_client::handler::ClientResult;
 use crate::ics02_client::msgs::upgrade_client::MsgUpgradeAnyClient;
 use crate::ics24_host::identifier::ClientId;
@@ -23,7 +25,23 @@ pub fn process(
     ctx: &dyn ClientReader,
     msg: MsgUpgradeAnyClient,
 ) -> HandlerResult<ClientResult, Error> {
-    let MsgUpgradeAnyClient { client_id, .. } = msg;
+    let mut output = HandlerOutput::builder();
+
+    let MsgUpgradeAnyClient {
+        client_id,
+        client_state: upgraded_client_state,
+        consensus_state: upgraded_consensus_state,
+        proof_upgrade_client,
+        proof_upgrade_consensus_state,
+        signer: _,
+    } = msg;
+
+    // Read client type from the host chain store. The client should already exist.
+    let client_type = ctx
+        .client_type(&client_id)
+        .ok_or_else(|| Kind::ClientNotFound(client_id.clone()))?;
+
+    let client_def = AnyClient::from_client_type(client_type);
 
     // Read client state from the host chain store.
     let client_state = ctx
@@ -34,20 +52,126 @@ pub fn process(
         return Err(Kind::ClientFrozen(client_id).into());
     }
 
-    // Not implemented yet: https://github.com/informalsystems/ibc-rs/issues/722
-    todo!()
-
-    // let result = ClientResult::Upgrade(Result {
-    //     client_id: client_id.clone(),
-    //     client_state: new_client_state,
-    //     consensus_state: new_consensus_state,
-    // });
-    //
-    // let event_attributes = Attributes {
-    //     client_id,
-    //     ..Default::default()
-    // };
-    // output.emit(IbcEvent::UpgradeClient(event_attributes.into()));
-    //
-    // Ok(output.with_result(result))
+    // The upgraded client must be of the same type as the client being upgraded.
+    if upgraded_client_state.client_type() != client_type {
+        return Err(Kind::ClientArgsTypeMismatch(client_type).into());
+    }
+
+    // The proofs are verified against the root of the latest consensus state of the client.
+    let latest_height = client_state.latest_height();
+    let consensus_state = ctx
+        .consensus_state(&client_id, latest_height)
+        .ok_or_else(|| Kind::ConsensusStateNotFound(client_id.clone(), latest_height))?;
+
+    // This function will return the new client state and consensus state, as they should be
+    // stored after the upgrade. These will be later persisted by the keeper.
+    let (new_client_state, new_consensus_state) = client_def
+        .verify_upgrade_and_update_state(
+            &client_state,
+            &consensus_state,
+            upgraded_client_state,
+            upgraded_consensus_state,
+            &proof_upgrade_client,
+            &proof_upgrade_consensus_state,
+        )
+        .map_err(|e| Kind::UpgradeVerificationFailure.context(e.to_string()))?;
+
+    let consensus_height = new_client_state.latest_height();
+
+    let result = ClientResult::Upgrade(Result {
+        client_id: client_id.clone(),
+        client_state: new_client_state,
+        consensus_state: new_consensus_state,
+    });
+
+    let event_attributes = Attributes {
+        client_id,
+        client_type,
+        consensus_height,
+        ..Default::default()
+    };
+    output.emit(IbcEvent::UpgradeClient(event_attributes.into()));
+
+    Ok(output.with_result(result))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use ibc_proto::ibc::core::commitment::v1::MerkleProof;
+
+    use crate::events::IbcEvent;
+    use crate::ics02_client::client_state::{AnyClientState, ClientState};
+    use crate::ics02_client::error::Kind;
+    use crate::ics02_client::handler::dispatch;
+    use crate::ics02_client::handler::ClientResult::Upgrade;
+    use crate::ics02_client::msgs::upgrade_client::MsgUpgradeAnyClient;
+    use crate::ics02_client::msgs::ClientMsg;
+    use crate::ics24_host::identifier::ClientId;
+    use crate::mock::client_state::{MockClientState, MockConsensusState};
+    use crate::mock::context::MockContext;
+    use crate::mock::header::MockHeader;
+    use crate::test_utils::get_dummy_account_id;
+    use crate::Height;
+
+    fn upgrade_msg(client_id: &ClientId, height: Height) -> MsgUpgradeAnyClient {
+        MsgUpgradeAnyClient {
+            client_id: client_id.clone(),
+            client_state: MockClientState::new(MockHeader::new(height)).into(),
+            consensus_state: MockConsensusState::new(MockHeader::new(height)).into(),
+            proof_upgrade_client: MerkleProof { proofs: vec![] },
+            proof_upgrade_consensus_state: MerkleProof { proofs: vec![] },
+            signer: get_dummy_account_id(),
+        }
+    }
+
+    #[test]
+    fn test_upgrade_client_ok() {
+        let client_id = ClientId::default();
+        let ctx = MockContext::default().with_client(&client_id, Height::new(0, 42));
+
+        let msg = upgrade_msg(&client_id, Height::new(1, 1));
+        let output = dispatch(&ctx, ClientMsg::UpgradeClient(msg)).unwrap();
+
+        assert_eq!(output.events.len(), 1);
+        assert!(
+            matches!(&output.events[0], IbcEvent::UpgradeClient(e) if e.client_id() == &client_id)
+        );
+
+        match output.result {
+            Upgrade(res) => {
+                assert_eq!(res.client_id, client_id);
+                assert_eq!(
+                    res.client_state,
+                    AnyClientState::Mock(MockClientState::new(MockHeader::new(Height::new(1, 1))))
+                );
+                assert_eq!(res.client_state.latest_height(), Height::new(1, 1));
+            }
+            _ => panic!("upgrade handler result has incorrect type"),
+        }
+    }
+
+    #[test]
+    fn test_upgrade_client_low_height() {
+        let client_id = ClientId::default();
+        let ctx = MockContext::default().with_client(&client_id, Height::new(0, 42));
+
+        let msg = upgrade_msg(&client_id, Height::new(0, 26));
+        let err = dispatch(&ctx, ClientMsg::UpgradeClient(msg)).unwrap_err();
+
+        assert_eq!(err.kind(), &Kind::UpgradeVerificationFailure);
+    }
+
+    #[test]
+    fn test_upgrade_nonexisting_client() {
+        let client_id = ClientId::from_str("mockclient1").unwrap();
+        let ctx = MockContext::default().with_client(&client_id, Height::new(0, 42));
+
+        let unknown_client_id = ClientId::from_str("nonexistingclient").unwrap();
+        let msg = upgrade_msg(&unknown_client_id, Height::new(1, 1));
+        let err = dispatch(&ctx, ClientMsg::UpgradeClient(msg)).unwrap_err();
+
+        assert_eq!(err.kind(), &Kind::ClientNotFound(unknown_client_id));
+    }
 }
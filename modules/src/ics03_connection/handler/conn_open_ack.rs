@@ -72,7 +72,7 @@ pub(crate) fn process(
     );
 
     // 2. Pass the details to the verification function.
-    verify_proofs(
+    let client_state = verify_proofs(
         ctx,
         msg.client_state(),
         &new_conn_end,
@@ -88,6 +88,7 @@ pub(crate) fn process(
     let result = ConnectionResult {
         connection_id: msg.connection_id().clone(),
         connection_id_state: ConnectionIdState::Reused,
+        client_state: client_state.map(|state| (new_conn_end.client_id().clone(), state)),
         connection_end: new_conn_end,
     };
 
@@ -10,16 +10,30 @@ use crate::ics23_commitment::commitment::CommitmentProofBytes;
 use crate::proofs::{ConsensusProof, Proofs};
 use crate::Height;
 
-/// Entry point for verifying all proofs bundled in any ICS3 message.
+/// Entry point for verifying all proofs bundled in any ICS3 message. Each proof is verified against
+/// the state of the local client left by the previous verification. On success, returns the new
+/// state of the local client if verification changed it (see
+/// `ClientDef::update_state_after_verification`).
 pub fn verify_proofs(
     ctx: &dyn ConnectionReader,
     client_state: Option<AnyClientState>,
     connection_end: &ConnectionEnd,
     expected_conn: &ConnectionEnd,
     proofs: &Proofs,
-) -> Result<(), Error> {
-    verify_connection_proof(
+) -> Result<Option<AnyClientState>, Error> {
+    // Fetch the client state (IBC client on the local/host chain).
+    let initial_client_state = ctx
+        .client_state(connection_end.client_id())
+        .ok_or_else(|| Kind::MissingClient(connection_end.client_id().clone()))?;
+
+    // The client must not be frozen.
+    if initial_client_state.is_frozen() {
+        return Err(Kind::FrozenClient(connection_end.client_id().clone()).into());
+    }
+
+    let mut local_client_state = verify_connection_proof(
         ctx,
+        initial_client_state.clone(),
         connection_end,
         expected_conn,
         proofs.height(),
@@ -28,8 +42,9 @@ pub fn verify_proofs(
 
     // If the message includes a client state, then verify the proof for that state.
     if let Some(expected_client_state) = client_state {
-        verify_client_proof(
+        local_client_state = verify_client_proof(
             ctx,
+            local_client_state,
             connection_end,
             expected_client_state,
             proofs.height(),
@@ -42,64 +57,56 @@ pub fn verify_proofs(
 
     // If a consensus proof is attached to the message, then verify it.
     if let Some(proof) = proofs.consensus_proof() {
-        Ok(verify_consensus_proof(
+        local_client_state = verify_consensus_proof(
             ctx,
+            local_client_state,
             connection_end,
             proofs.height(),
             &proof,
-        )?)
-    } else {
-        Ok(())
+        )?;
     }
+
+    Ok(Some(local_client_state).filter(|state| *state != initial_client_state))
 }
 
 /// Verifies the authenticity and semantic correctness of a commitment `proof`. The commitment
 /// claims to prove that an object of type connection exists on the source chain (i.e., the chain
-/// which created this proof). This object must match the state of `expected_conn`.
+/// which created this proof). This object must match the state of `expected_conn`. On success,
+/// returns the state of the local client after verification.
 pub fn verify_connection_proof(
     ctx: &dyn ConnectionReader,
+    client_state: AnyClientState,
     connection_end: &ConnectionEnd,
     expected_conn: &ConnectionEnd,
     proof_height: Height,
     proof: &CommitmentProofBytes,
-) -> Result<(), Error> {
-    // Fetch the client state (IBC client on the local/host chain).
-    let client_state = ctx
-        .client_state(connection_end.client_id())
-        .ok_or_else(|| Kind::MissingClient(connection_end.client_id().clone()))?;
-
-    // The client must not be frozen.
-    if client_state.is_frozen() {
-        return Err(Kind::FrozenClient(connection_end.client_id().clone()).into());
-    }
-
+) -> Result<AnyClientState, Error> {
     // The client must have the consensus state for the height where this proof was created.
-    if ctx
+    let consensus_state = ctx
         .client_consensus_state(connection_end.client_id(), proof_height)
-        .is_none()
-    {
-        return Err(Kind::MissingClientConsensusState(
-            proof_height,
-            connection_end.client_id().clone(),
-        )
-        .into());
-    }
+        .ok_or_else(|| {
+            Kind::MissingClientConsensusState(proof_height, connection_end.client_id().clone())
+        })?;
 
     let client_def = AnyClient::from_client_type(client_state.client_type());
 
     // Verify the proof for the connection state against the expected connection end.
     // A counterparty connection id of None causes `unwrap()` below and indicates an internal
     // error as this is the connection id on the counterparty chain that must always be present.
-    Ok(client_def
+    client_def
         .verify_connection_state(
+            ctx,
             &client_state,
             proof_height,
             connection_end.counterparty().prefix(),
             proof,
+            consensus_state.root(),
             connection_end.counterparty().connection_id(),
             expected_conn,
         )
-        .map_err(|_| Kind::InvalidProof)?)
+        .map_err(|_| Kind::InvalidProof)?;
+
+    client_state_after_verification(&client_def, client_state)
 }
 
 /// Verifies the client `proof` from a connection handshake message, typically from a
@@ -108,23 +115,15 @@ pub fn verify_connection_proof(
 /// is running on the counterparty chain (the chain which sent this message). This method does a
 /// complete verification: that the client state the counterparty stores is valid (i.e., not frozen,
 /// at the same revision as the current chain, with matching chain identifiers, etc) and that the
-/// `proof` is correct.
+/// `proof` is correct. On success, returns the state of the local client after verification.
 pub fn verify_client_proof(
     ctx: &dyn ConnectionReader,
+    client_state: AnyClientState,
     connection_end: &ConnectionEnd,
     expected_client_state: AnyClientState,
     proof_height: Height,
     proof: &CommitmentProofBytes,
-) -> Result<(), Error> {
-    // Fetch the local client state (IBC client running on the host chain).
-    let client_state = ctx
-        .client_state(connection_end.client_id())
-        .ok_or_else(|| Kind::MissingClient(connection_end.client_id().clone()))?;
-
-    if client_state.is_frozen() {
-        return Err(Kind::FrozenClient(connection_end.client_id().clone()).into());
-    }
-
+) -> Result<AnyClientState, Error> {
     let consensus_state = ctx
         .client_consensus_state(connection_end.client_id(), proof_height)
         .ok_or_else(|| {
@@ -133,8 +132,9 @@ pub fn verify_client_proof(
 
     let client_def = AnyClient::from_client_type(client_state.client_type());
 
-    Ok(client_def
+    client_def
         .verify_client_full_state(
+            ctx,
             &client_state,
             proof_height,
             consensus_state.root(),
@@ -146,23 +146,26 @@ pub fn verify_client_proof(
         .map_err(|e| {
             Kind::ClientStateVerificationFailure(connection_end.client_id().clone())
                 .context(e.to_string())
-        })?)
+        })?;
+
+    client_state_after_verification(&client_def, client_state)
 }
 
+/// Verifies the `proof` that the counterparty chain stores the consensus state of the current
+/// chain at the height of the proof. On success, returns the state of the local client after
+/// verification.
 pub fn verify_consensus_proof(
     ctx: &dyn ConnectionReader,
+    client_state: AnyClientState,
     connection_end: &ConnectionEnd,
     proof_height: Height,
     proof: &ConsensusProof,
-) -> Result<(), Error> {
-    // Fetch the client state (IBC client on the local chain).
-    let client_state = ctx
-        .client_state(connection_end.client_id())
-        .ok_or_else(|| Kind::MissingClient(connection_end.client_id().clone()))?;
-
-    if client_state.is_frozen() {
-        return Err(Kind::FrozenClient(connection_end.client_id().clone()).into());
-    }
+) -> Result<AnyClientState, Error> {
+    let consensus_state = ctx
+        .client_consensus_state(connection_end.client_id(), proof_height)
+        .ok_or_else(|| {
+            Kind::MissingClientConsensusState(proof_height, connection_end.client_id().clone())
+        })?;
 
     // Fetch the expected consensus state from the historical (local) header data.
     let expected_consensus = ctx
@@ -171,19 +174,35 @@ pub fn verify_consensus_proof(
 
     let client = AnyClient::from_client_type(client_state.client_type());
 
-    Ok(client
+    client
         .verify_client_consensus_state(
+            ctx,
             &client_state,
             proof_height,
             connection_end.counterparty().prefix(),
             proof.proof(),
+            consensus_state.root(),
             connection_end.counterparty().client_id(),
             proof.height(),
             &expected_consensus,
         )
         .map_err(|e| {
             Kind::ConsensusStateVerificationFailure(proof.height()).context(e.to_string())
-        })?)
+        })?;
+
+    client_state_after_verification(&client, client_state)
+}
+
+/// Returns the state of the local client after it successfully verified a proof.
+fn client_state_after_verification(
+    client_def: &AnyClient,
+    client_state: AnyClientState,
+) -> Result<AnyClientState, Error> {
+    let updated_client_state = client_def
+        .update_state_after_verification(&client_state)
+        .map_err(|e| Kind::InvalidProof.context(e.to_string()))?;
+
+    Ok(updated_client_state.unwrap_or(client_state))
 }
 
 /// Checks that `claimed_height` is within normal bounds, i.e., fresh enough so that the chain has
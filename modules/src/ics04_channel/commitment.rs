@@ -0,0 +1,61 @@
+//! Commitments to packets and acknowledgements, as stored by Cosmos SDK chains (`ibc-go`).
+
+use sha2::{Digest, Sha256};
+
+use crate::timestamp::Timestamp;
+use crate::Height;
+
+/// Computes the commitment to a packet stored by the sending chain:
+/// `sha256(timeout_timestamp ‖ timeout_revision_number ‖ timeout_revision_height ‖ sha256(data))`,
+/// with the integers encoded in big-endian.
+pub fn compute_packet_commitment(
+    data: &[u8],
+    timeout_height: &Height,
+    timeout_timestamp: &Timestamp,
+) -> Vec<u8> {
+    let mut hash_input = timeout_timestamp.as_nanoseconds().to_be_bytes().to_vec();
+    hash_input.extend_from_slice(&timeout_height.revision_number.to_be_bytes());
+    hash_input.extend_from_slice(&timeout_height.revision_height.to_be_bytes());
+    hash_input.extend_from_slice(&Sha256::digest(data));
+
+    Sha256::digest(&hash_input).to_vec()
+}
+
+/// Computes the commitment to an acknowledgement stored by the receiving chain: `sha256(ack)`.
+pub fn compute_ack_commitment(ack: &[u8]) -> Vec<u8> {
+    Sha256::digest(ack).to_vec()
+}
+
+#[cfg(test)]
+mod tests {
+    use subtle_encoding::hex;
+
+    use crate::timestamp::Timestamp;
+    use crate::Height;
+
+    use super::{compute_ack_commitment, compute_packet_commitment};
+
+    #[test]
+    fn packet_commitment() {
+        let data = br#"{"amount":"100","denom":"stake","receiver":"cosmos1wxeyh7zgn4tctjzs0vtqpc6p5cxq5t2muzl7ng","sender":"cosmos1wxeyh7zgn4tctjzs0vtqpc6p5cxq5t2muzl7ng"}"#;
+        let timeout_height = Height::new(1, 1000);
+        let timeout_timestamp = Timestamp::from_nanoseconds(1_624_961_044_000_000_000).unwrap();
+
+        let commitment = compute_packet_commitment(data, &timeout_height, &timeout_timestamp);
+        assert_eq!(
+            hex::encode(commitment),
+            b"168b620088d16061300a876dd6f0c2c15e70eeeae7d0af9afb84ba704de48626".to_vec()
+        );
+    }
+
+    #[test]
+    fn ack_commitment() {
+        // The commitment of the successful ICS-20 acknowledgement, as found in the store of
+        // Cosmos SDK chains (`CPdVftUYJv4Y2EUSvyTsdQAe268hI6R333KgqfNkCnw=` in base64).
+        let commitment = compute_ack_commitment(br#"{"result":"AQ=="}"#);
+        assert_eq!(
+            hex::encode(commitment),
+            b"08f7557ed51826fe18d84512bf24ec75001edbaf2123a477df72a0a9f3640a7c".to_vec()
+        );
+    }
+}
@@ -185,6 +185,9 @@ impl Default for Attributes {
 pub struct OpenInit(Attributes);
 
 impl OpenInit {
+    pub fn attributes(&self) -> &Attributes {
+        &self.0
+    }
     pub fn channel_id(&self) -> &Option<ChannelId> {
         &self.0.channel_id
     }
@@ -229,6 +232,9 @@ impl From<OpenInit> for IbcEvent {
 pub struct OpenTry(Attributes);
 
 impl OpenTry {
+    pub fn attributes(&self) -> &Attributes {
+        &self.0
+    }
     pub fn channel_id(&self) -> &Option<ChannelId> {
         &self.0.channel_id
     }
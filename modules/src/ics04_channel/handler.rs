@@ -1,13 +1,14 @@
 //! This module implements the processing logic for ICS4 (channel) messages.
 
 use crate::handler::HandlerOutput;
+use crate::ics02_client::client_state::AnyClientState;
 use crate::ics04_channel::channel::ChannelEnd;
 use crate::ics04_channel::context::ChannelReader;
 use crate::ics04_channel::error::Error;
 use crate::ics04_channel::msgs::ChannelMsg;
 use crate::ics04_channel::{msgs::PacketMsg, packet::PacketResult};
 use crate::ics05_port::capabilities::Capability;
-use crate::ics24_host::identifier::{ChannelId, PortId};
+use crate::ics24_host::identifier::{ChannelId, ClientId, PortId};
 
 pub mod acknowledgement;
 pub mod chan_close_confirm;
@@ -41,6 +42,9 @@ pub struct ChannelResult {
     pub channel_id_state: ChannelIdState,
     pub channel_cap: Capability,
     pub channel_end: ChannelEnd,
+    /// The new state of the client of the channel's connection, if verifying the proofs of the
+    /// message changed it.
+    pub client_state: Option<(ClientId, AnyClientState)>,
 }
 
 /// General entry point for processing any type of message related to the ICS4 channel open and
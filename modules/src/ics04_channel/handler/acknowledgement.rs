@@ -1,5 +1,6 @@
 use crate::events::IbcEvent;
 use crate::handler::{HandlerOutput, HandlerResult};
+use crate::ics02_client::client_state::AnyClientState;
 use crate::ics02_client::height::Height;
 use crate::ics03_connection::connection::State as ConnectionState;
 use crate::ics04_channel::channel::State;
@@ -9,7 +10,7 @@ use crate::ics04_channel::handler::verify::verify_packet_acknowledgement_proofs;
 use crate::ics04_channel::msgs::acknowledgement::MsgAcknowledgement;
 use crate::ics04_channel::packet::{PacketResult, Sequence};
 use crate::ics04_channel::{context::ChannelReader, error::Error, error::Kind};
-use crate::ics24_host::identifier::{ChannelId, PortId};
+use crate::ics24_host::identifier::{ChannelId, ClientId, PortId};
 
 #[derive(Clone, Debug)]
 pub struct AckPacketResult {
@@ -17,6 +18,9 @@ pub struct AckPacketResult {
     pub channel_id: ChannelId,
     pub seq: Sequence,
     pub seq_number: Option<Sequence>,
+    /// The new state of the client of the channel's connection, if verifying the proofs of the
+    /// message changed it.
+    pub client_state: Option<(ClientId, AnyClientState)>,
 }
 
 pub fn process(
@@ -63,8 +67,6 @@ pub fn process(
         );
     }
 
-    let client_id = connection_end.client_id().clone();
-
     // Verify packet commitment
     let packet_commitment = ctx
         .get_packet_commitment(&(
@@ -84,13 +86,14 @@ pub fn process(
     }
 
     // Verify the acknowledgement proof
-    verify_packet_acknowledgement_proofs(
+    let client_state = verify_packet_acknowledgement_proofs(
         ctx,
         &packet,
         msg.acknowledgement().clone(),
-        client_id,
+        &connection_end,
         msg.proofs(),
-    )?;
+    )?
+    .map(|state| (connection_end.client_id().clone(), state));
 
     let result = if source_channel_end.order_matches(&Order::Ordered) {
         let next_seq_ack = ctx
@@ -106,6 +109,7 @@ pub fn process(
             channel_id: packet.source_channel.clone(),
             seq: packet.sequence,
             seq_number: Some(next_seq_ack.increment()),
+            client_state,
         })
     } else {
         PacketResult::Ack(AckPacketResult {
@@ -113,6 +117,7 @@ pub fn process(
             channel_id: packet.source_channel.clone(),
             seq: packet.sequence,
             seq_number: None,
+            client_state,
         })
     };
 
@@ -63,7 +63,7 @@ pub(crate) fn process(
         channel_end.version(),
     );
 
-    verify_channel_proofs(
+    let client_state = verify_channel_proofs(
         ctx,
         &channel_end,
         &conn,
@@ -83,6 +83,7 @@ pub(crate) fn process(
         channel_id_state: ChannelIdState::Reused,
         channel_cap,
         channel_end,
+        client_state: client_state.map(|state| (conn.client_id().clone(), state)),
     };
 
     let event_attributes = Attributes {
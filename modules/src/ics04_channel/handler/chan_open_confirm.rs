@@ -65,7 +65,7 @@ pub(crate) fn process(
         channel_end.version(),
     );
     //2. Verify proofs
-    verify_channel_proofs(
+    let client_state = verify_channel_proofs(
         ctx,
         &channel_end,
         &conn,
@@ -85,6 +85,7 @@ pub(crate) fn process(
         channel_id_state: ChannelIdState::Reused,
         channel_cap,
         channel_end,
+        client_state: client_state.map(|state| (conn.client_id().clone(), state)),
     };
 
     let event_attributes = Attributes {
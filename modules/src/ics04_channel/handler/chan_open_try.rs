@@ -127,7 +127,7 @@ pub(crate) fn process(
     );
 
     // 2. Actual proofs are verified now.
-    verify_channel_proofs(
+    let client_state = verify_channel_proofs(
         ctx,
         &new_channel_end,
         &conn,
@@ -151,6 +151,7 @@ pub(crate) fn process(
         },
         channel_id: channel_id.clone(),
         channel_end: new_channel_end,
+        client_state: client_state.map(|state| (conn.client_id().clone(), state)),
     };
 
     let event_attributes = Attributes {
@@ -1,5 +1,6 @@
 use crate::events::IbcEvent;
 use crate::handler::{HandlerOutput, HandlerResult};
+use crate::ics02_client::client_state::AnyClientState;
 use crate::ics02_client::height::Height;
 use crate::ics03_connection::connection::State as ConnectionState;
 use crate::ics04_channel::channel::{Counterparty, Order, State};
@@ -9,7 +10,7 @@ use crate::ics04_channel::events::ReceivePacket;
 use crate::ics04_channel::handler::verify::verify_packet_recv_proofs;
 use crate::ics04_channel::msgs::recv_packet::MsgRecvPacket;
 use crate::ics04_channel::packet::{PacketResult, Receipt, Sequence};
-use crate::ics24_host::identifier::{ChannelId, PortId};
+use crate::ics24_host::identifier::{ChannelId, ClientId, PortId};
 use crate::timestamp::Expiry;
 
 #[derive(Clone, Debug)]
@@ -19,6 +20,9 @@ pub struct RecvPacketResult {
     pub seq: Sequence,
     pub seq_number: Sequence,
     pub receipt: Option<Receipt>,
+    /// The new state of the client of the channel's connection, if verifying the proofs of the
+    /// message changed it.
+    pub client_state: Option<(ClientId, AnyClientState)>,
 }
 
 pub fn process(ctx: &dyn ChannelReader, msg: MsgRecvPacket) -> HandlerResult<PacketResult, Error> {
@@ -69,8 +73,6 @@ pub fn process(ctx: &dyn ChannelReader, msg: MsgRecvPacket) -> HandlerResult<Pac
         return Err(Kind::ConnectionNotOpen(dest_channel_end.connection_hops()[0].clone()).into());
     }
 
-    let client_id = connection_end.client_id().clone();
-
     // Check if packet height is newer than the height of the local host chain
     let latest_height = ctx.host_height();
     if (!packet.timeout_height.is_zero()) && (packet.timeout_height <= latest_height) {
@@ -83,7 +85,8 @@ pub fn process(ctx: &dyn ChannelReader, msg: MsgRecvPacket) -> HandlerResult<Pac
         return Err(Kind::LowPacketTimestamp.into());
     }
 
-    verify_packet_recv_proofs(ctx, &packet, client_id, &msg.proofs)?;
+    let client_state = verify_packet_recv_proofs(ctx, &packet, &connection_end, &msg.proofs)?
+        .map(|state| (connection_end.client_id().clone(), state));
 
     let result = if dest_channel_end.order_matches(&Order::Ordered) {
         let next_seq_recv = ctx
@@ -100,6 +103,7 @@ pub fn process(ctx: &dyn ChannelReader, msg: MsgRecvPacket) -> HandlerResult<Pac
             seq: packet.sequence,
             seq_number: next_seq_recv.increment(),
             receipt: None,
+            client_state,
         })
     } else {
         let packet_rec = ctx.get_packet_receipt(&(
@@ -118,6 +122,7 @@ pub fn process(ctx: &dyn ChannelReader, msg: MsgRecvPacket) -> HandlerResult<Pac
                     seq: packet.sequence,
                     seq_number: 1.into(),
                     receipt: Some(Receipt::Ok),
+                    client_state,
                 })
             }
         }
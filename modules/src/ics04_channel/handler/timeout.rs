@@ -1,5 +1,6 @@
 use crate::events::IbcEvent;
 use crate::handler::{HandlerOutput, HandlerResult};
+use crate::ics02_client::client_state::AnyClientState;
 use crate::ics04_channel::channel::State;
 use crate::ics04_channel::channel::{ChannelEnd, Counterparty, Order};
 use crate::ics04_channel::events::TimeoutPacket;
@@ -9,7 +10,7 @@ use crate::ics04_channel::handler::verify::{
 use crate::ics04_channel::msgs::timeout::MsgTimeout;
 use crate::ics04_channel::packet::{PacketResult, Sequence};
 use crate::ics04_channel::{context::ChannelReader, error::Error, error::Kind};
-use crate::ics24_host::identifier::{ChannelId, PortId};
+use crate::ics24_host::identifier::{ChannelId, ClientId, PortId};
 use crate::timestamp::Expiry;
 
 #[derive(Clone, Debug)]
@@ -18,6 +19,9 @@ pub struct TimeoutPacketResult {
     pub channel_id: ChannelId,
     pub seq: Sequence,
     pub channel: Option<ChannelEnd>,
+    /// The new state of the client of the channel's connection, if verifying the proofs of the
+    /// message changed it.
+    pub client_state: Option<(ClientId, AnyClientState)>,
 }
 
 pub fn process(ctx: &dyn ChannelReader, msg: MsgTimeout) -> HandlerResult<PacketResult, Error> {
@@ -104,9 +108,10 @@ pub fn process(ctx: &dyn ChannelReader, msg: MsgTimeout) -> HandlerResult<Packet
                 Kind::InvalidPacketSequence(packet.sequence, msg.next_sequence_recv).into(),
             );
         }
-        verify_next_sequence_recv(
+        let client_state = verify_next_sequence_recv(
             ctx,
-            client_id,
+            &connection_end,
+            None,
             packet.clone(),
             msg.next_sequence_recv,
             &msg.proofs.clone(),
@@ -118,15 +123,23 @@ pub fn process(ctx: &dyn ChannelReader, msg: MsgTimeout) -> HandlerResult<Packet
             channel_id: packet.source_channel.clone(),
             seq: packet.sequence,
             channel: Some(source_channel_end),
+            client_state: client_state.map(|state| (connection_end.client_id().clone(), state)),
         })
     } else {
-        verify_packet_receipt_absence(ctx, client_id, packet.clone(), &msg.proofs.clone())?;
+        let client_state = verify_packet_receipt_absence(
+            ctx,
+            &connection_end,
+            None,
+            packet.clone(),
+            &msg.proofs.clone(),
+        )?;
 
         PacketResult::Timeout(TimeoutPacketResult {
             port_id: packet.source_port.clone(),
             channel_id: packet.source_channel.clone(),
             seq: packet.sequence,
             channel: None,
+            client_state: client_state.map(|state| (connection_end.client_id().clone(), state)),
         })
     };
 
@@ -47,8 +47,6 @@ pub fn process(
         .connection_end(&source_channel_end.connection_hops()[0])
         .ok_or_else(|| Kind::MissingConnection(source_channel_end.connection_hops()[0].clone()))?;
 
-    let client_id = connection_end.client_id().clone();
-
     //verify the packet was sent, check the store
     let packet_commitment = ctx
         .get_packet_commitment(&(
@@ -87,7 +85,7 @@ pub fn process(
         source_channel_end.version(),
     );
 
-    verify_channel_proofs(
+    let client_state = verify_channel_proofs(
         ctx,
         &source_channel_end,
         &connection_end,
@@ -101,9 +99,10 @@ pub fn process(
                 Kind::InvalidPacketSequence(packet.sequence, msg.next_sequence_recv).into(),
             );
         }
-        verify_next_sequence_recv(
+        let client_state = verify_next_sequence_recv(
             ctx,
-            client_id,
+            &connection_end,
+            client_state,
             packet.clone(),
             msg.next_sequence_recv,
             &msg.proofs.clone(),
@@ -114,15 +113,23 @@ pub fn process(
             channel_id: packet.source_channel.clone(),
             seq: packet.sequence,
             channel: Some(source_channel_end),
+            client_state: client_state.map(|state| (connection_end.client_id().clone(), state)),
         })
     } else {
-        verify_packet_receipt_absence(ctx, client_id, packet.clone(), &msg.proofs.clone())?;
+        let client_state = verify_packet_receipt_absence(
+            ctx,
+            &connection_end,
+            client_state,
+            packet.clone(),
+            &msg.proofs.clone(),
+        )?;
 
         PacketResult::Timeout(TimeoutPacketResult {
             port_id: packet.source_port.clone(),
             channel_id: packet.source_channel.clone(),
             seq: packet.sequence,
             channel: None,
+            client_state: client_state.map(|state| (connection_end.client_id().clone(), state)),
         })
     };
 
@@ -1,100 +1,84 @@
-use crate::ics02_client::client_state::ClientState;
+use crate::ics02_client::client_consensus::ConsensusState;
+use crate::ics02_client::client_state::{AnyClientState, ClientState};
 use crate::ics02_client::{client_def::AnyClient, client_def::ClientDef};
 use crate::ics03_connection::connection::ConnectionEnd;
 use crate::ics04_channel::channel::ChannelEnd;
 use crate::ics04_channel::context::ChannelReader;
 use crate::ics04_channel::error::{Error, Kind};
 use crate::ics04_channel::packet::{Packet, Sequence};
-use crate::ics24_host::identifier::ClientId;
 use crate::proofs::Proofs;
 
-/// Entry point for verifying all proofs bundled in any ICS4 message for channel protocols.
+/// Entry point for verifying all proofs bundled in any ICS4 message for channel protocols. On
+/// success, returns the new state of the client if verification changed it (see
+/// `ClientDef::update_state_after_verification`).
 pub fn verify_channel_proofs(
     ctx: &dyn ChannelReader,
     channel_end: &ChannelEnd,
     connection_end: &ConnectionEnd,
     expected_chan: &ChannelEnd,
     proofs: &Proofs,
-) -> Result<(), Error> {
+) -> Result<Option<AnyClientState>, Error> {
     // This is the client which will perform proof verification.
     let client_id = connection_end.client_id().clone();
 
-    let client_state = ctx
-        .client_state(&client_id)
-        .ok_or_else(|| Kind::MissingClientState(client_id.clone()))?;
+    let client_state = verifying_client_state(ctx, connection_end, None)?;
 
-    // The client must not be frozen.
-    if client_state.is_frozen() {
-        return Err(Kind::FrozenClient(client_id).into());
-    }
-
-    if ctx
+    let consensus_state = ctx
         .client_consensus_state(&client_id, proofs.height())
-        .is_none()
-    {
-        return Err(Kind::MissingClientConsensusState(client_id, proofs.height()).into());
-    }
+        .ok_or_else(|| Kind::MissingClientConsensusState(client_id.clone(), proofs.height()))?;
 
     let client_def = AnyClient::from_client_type(client_state.client_type());
 
     // Verify the proof for the channel state against the expected channel end.
     // A counterparty channel id of None in not possible, and is checked by validate_basic in msg.
-    Ok(client_def
+    client_def
         .verify_channel_state(
+            ctx,
             &client_state,
             proofs.height(),
             connection_end.counterparty().prefix(),
             proofs.object_proof(),
+            consensus_state.root(),
             &channel_end.counterparty().port_id(),
             &channel_end.counterparty().channel_id().unwrap(),
             expected_chan,
         )
-        .map_err(|_| Kind::InvalidProof)?)
+        .map_err(|_| Kind::InvalidProof)?;
+
+    client_state_after_verification(&client_def, &client_state, None)
 }
 
 /// Entry point for verifying all proofs bundled in a ICS4 packet recv. message.
 pub fn verify_packet_recv_proofs(
     ctx: &dyn ChannelReader,
     packet: &Packet,
-    client_id: ClientId,
+    connection_end: &ConnectionEnd,
     proofs: &Proofs,
-) -> Result<(), Error> {
-    let client_state = ctx
-        .client_state(&client_id)
-        .ok_or_else(|| Kind::MissingClientState(client_id.clone()))?;
+) -> Result<Option<AnyClientState>, Error> {
+    let client_id = connection_end.client_id().clone();
 
-    // The client must not be frozen.
-    if client_state.is_frozen() {
-        return Err(Kind::FrozenClient(client_id).into());
-    }
+    let client_state = verifying_client_state(ctx, connection_end, None)?;
 
-    if ctx
+    let consensus_state = ctx
         .client_consensus_state(&client_id, proofs.height())
-        .is_none()
-    {
-        return Err(Kind::MissingClientConsensusState(client_id, proofs.height()).into());
-    }
+        .ok_or_else(|| Kind::MissingClientConsensusState(client_id.clone(), proofs.height()))?;
 
     let client_def = AnyClient::from_client_type(client_state.client_type());
 
-    let input = format!(
-        "{:?},{:?},{:?}",
-        packet.timeout_timestamp, packet.timeout_height, packet.data
-    );
-    let commitment = ctx.hash(input);
-
     // Verify the proof for the packet against the chain store.
-    Ok(client_def
+    client_def
         .verify_packet_data(
+            ctx,
             &client_state,
             proofs.height(),
+            connection_end.counterparty().prefix(),
             proofs.object_proof(),
-            &packet.source_port,
-            &packet.source_channel,
-            &packet.sequence,
-            commitment,
+            consensus_state.root(),
+            packet,
         )
-        .map_err(|_| Kind::PacketVerificationFailed(packet.sequence))?)
+        .map_err(|_| Kind::PacketVerificationFailed(packet.sequence))?;
+
+    client_state_after_verification(&client_def, &client_state, None)
 }
 
 /// Entry point for verifying all proofs bundled in an ICS4 packet ack message.
@@ -102,92 +86,148 @@ pub fn verify_packet_acknowledgement_proofs(
     ctx: &dyn ChannelReader,
     packet: &Packet,
     acknowledgement: Vec<u8>,
-    client_id: ClientId,
+    connection_end: &ConnectionEnd,
     proofs: &Proofs,
-) -> Result<(), Error> {
-    let client_state = ctx
-        .client_state(&client_id)
-        .ok_or_else(|| Kind::MissingClientState(client_id.clone()))?;
+) -> Result<Option<AnyClientState>, Error> {
+    let client_id = connection_end.client_id().clone();
 
-    // The client must not be frozen.
-    if client_state.is_frozen() {
-        return Err(Kind::FrozenClient(client_id).into());
-    }
+    let client_state = verifying_client_state(ctx, connection_end, None)?;
+
+    let consensus_state = ctx
+        .client_consensus_state(&client_id, proofs.height())
+        .ok_or_else(|| Kind::MissingClientConsensusState(client_id.clone(), proofs.height()))?;
 
     let client_def = AnyClient::from_client_type(client_state.client_type());
 
     // Verify the proof for the packet against the chain store.
-    Ok(client_def
+    client_def
         .verify_packet_acknowledgement(
+            ctx,
             &client_state,
             proofs.height(),
+            connection_end.counterparty().prefix(),
             proofs.object_proof(),
-            &packet.source_port,
-            &packet.source_channel,
-            &packet.sequence,
+            consensus_state.root(),
+            packet,
             acknowledgement,
         )
-        .map_err(|_| Kind::PacketVerificationFailed(packet.sequence))?)
+        .map_err(|_| Kind::PacketVerificationFailed(packet.sequence))?;
+
+    client_state_after_verification(&client_def, &client_state, None)
 }
 
-/// Entry point for verifying all timeout proofs.
+/// Entry point for verifying all timeout proofs. If a previous verification of the same message
+/// already updated the state of the client, that state is passed as `updated_client_state`.
 pub fn verify_next_sequence_recv(
     ctx: &dyn ChannelReader,
-    client_id: ClientId,
+    connection_end: &ConnectionEnd,
+    updated_client_state: Option<AnyClientState>,
     packet: Packet,
     seq: Sequence,
     proofs: &Proofs,
-) -> Result<(), Error> {
-    let client_state = ctx
-        .client_state(&client_id)
-        .ok_or_else(|| Kind::MissingClientState(client_id.clone()))?;
+) -> Result<Option<AnyClientState>, Error> {
+    let client_id = connection_end.client_id().clone();
 
-    // The client must not be frozen.
-    if client_state.is_frozen() {
-        return Err(Kind::FrozenClient(client_id).into());
-    }
+    let client_state = verifying_client_state(ctx, connection_end, updated_client_state.clone())?;
+
+    let consensus_state = ctx
+        .client_consensus_state(&client_id, proofs.height())
+        .ok_or_else(|| Kind::MissingClientConsensusState(client_id.clone(), proofs.height()))?;
 
     let client_def = AnyClient::from_client_type(client_state.client_type());
 
     // Verify the proof for the packet against the chain store.
-    Ok(client_def
+    client_def
         .verify_next_sequence_recv(
+            ctx,
             &client_state,
             proofs.height(),
+            connection_end.counterparty().prefix(),
             proofs.object_proof(),
+            consensus_state.root(),
             &packet.destination_port,
             &packet.destination_channel,
             &seq,
         )
-        .map_err(|_| Kind::PacketVerificationFailed(seq))?)
+        .map_err(|_| Kind::PacketVerificationFailed(seq))?;
+
+    client_state_after_verification(&client_def, &client_state, updated_client_state)
 }
 
+/// Verifies that the packet was not received by the counterparty. If a previous verification of
+/// the same message already updated the state of the client, that state is passed as
+/// `updated_client_state`.
 pub fn verify_packet_receipt_absence(
     ctx: &dyn ChannelReader,
-    client_id: ClientId,
+    connection_end: &ConnectionEnd,
+    updated_client_state: Option<AnyClientState>,
     packet: Packet,
     proofs: &Proofs,
-) -> Result<(), Error> {
-    let client_state = ctx
-        .client_state(&client_id)
-        .ok_or_else(|| Kind::MissingClientState(client_id.clone()))?;
+) -> Result<Option<AnyClientState>, Error> {
+    let client_id = connection_end.client_id().clone();
 
-    // The client must not be frozen.
-    if client_state.is_frozen() {
-        return Err(Kind::FrozenClient(client_id).into());
-    }
+    let client_state = verifying_client_state(ctx, connection_end, updated_client_state.clone())?;
+
+    let consensus_state = ctx
+        .client_consensus_state(&client_id, proofs.height())
+        .ok_or_else(|| Kind::MissingClientConsensusState(client_id.clone(), proofs.height()))?;
 
     let client_def = AnyClient::from_client_type(client_state.client_type());
 
     // Verify the proof for the packet against the chain store.
-    Ok(client_def
+    client_def
         .verify_packet_receipt_absence(
+            ctx,
             &client_state,
             proofs.height(),
+            connection_end.counterparty().prefix(),
             proofs.object_proof(),
+            consensus_state.root(),
             &packet.destination_port,
             &packet.destination_channel,
             &packet.sequence,
         )
-        .map_err(|_| Kind::PacketVerificationFailed(packet.sequence))?)
+        .map_err(|_| Kind::PacketVerificationFailed(packet.sequence))?;
+
+    client_state_after_verification(&client_def, &client_state, updated_client_state)
+}
+
+/// Returns the state of the client which verifies the proofs on `connection_end`: either the
+/// state `updated` by a previous verification, or the state stored in `ctx`. The client must not
+/// be frozen.
+fn verifying_client_state(
+    ctx: &dyn ChannelReader,
+    connection_end: &ConnectionEnd,
+    updated: Option<AnyClientState>,
+) -> Result<AnyClientState, Error> {
+    let client_id = connection_end.client_id().clone();
+
+    let client_state = match updated {
+        Some(client_state) => client_state,
+        None => ctx
+            .client_state(&client_id)
+            .ok_or_else(|| Kind::MissingClientState(client_id.clone()))?,
+    };
+
+    // The client must not be frozen.
+    if client_state.is_frozen() {
+        return Err(Kind::FrozenClient(client_id).into());
+    }
+
+    Ok(client_state)
+}
+
+/// Returns the new state of the client after it successfully verified a proof, if it differs from
+/// the stored state: either the state produced by this verification or the state `updated` by a
+/// previous one.
+fn client_state_after_verification(
+    client_def: &AnyClient,
+    client_state: &AnyClientState,
+    updated: Option<AnyClientState>,
+) -> Result<Option<AnyClientState>, Error> {
+    let client_state = client_def
+        .update_state_after_verification(client_state)
+        .map_err(|e| Kind::InvalidProof.context(e.to_string()))?;
+
+    Ok(client_state.or(updated))
 }
@@ -5,8 +5,9 @@ use serde_derive::{Deserialize, Serialize};
 
 use ibc_proto::ibc::core::channel::v1::Packet as RawPacket;
 
+use crate::ics02_client::client_state::AnyClientState;
 use crate::ics04_channel::error::Kind;
-use crate::ics24_host::identifier::{ChannelId, PortId};
+use crate::ics24_host::identifier::{ChannelId, ClientId, PortId};
 use crate::timestamp::Timestamp;
 use crate::Height;
 
@@ -34,6 +35,19 @@ pub enum PacketResult {
     Timeout(TimeoutPacketResult),
 }
 
+impl PacketResult {
+    /// The new state of the client that verified the proofs of the message, if verification
+    /// changed it.
+    pub fn client_state(&self) -> Option<(ClientId, AnyClientState)> {
+        match self {
+            PacketResult::Recv(res) => res.client_state.clone(),
+            PacketResult::Ack(res) => res.client_state.clone(),
+            PacketResult::Timeout(res) => res.client_state.clone(),
+            PacketResult::Send(_) | PacketResult::WriteAck(_) => None,
+        }
+    }
+}
+
 #[derive(Clone, Debug)]
 pub enum Receipt {
     Ok,
@@ -0,0 +1,729 @@
+use ibc_proto::ibc::core::commitment::v1::MerkleProof;
+use ibc_proto::ibc::lightclients::solomachine::v1::{
+    ChannelStateData, ClientStateData, ConnectionStateData, ConsensusStateData, DataType,
+    NextSequenceRecvData, PacketAcknowledgementData, PacketCommitmentData,
+    PacketReceiptAbsenceData,
+};
+
+use crate::ics02_client::client_consensus::AnyConsensusState;
+use crate::ics02_client::client_def::ClientDef;
+use crate::ics02_client::client_state::AnyClientState;
+use crate::ics02_client::context::ClientReader;
+use crate::ics03_connection::connection::ConnectionEnd;
+use crate::ics03_connection::context::ConnectionReader;
+use crate::ics04_channel::channel::ChannelEnd;
+use crate::ics04_channel::context::ChannelReader;
+use crate::ics04_channel::packet::{Packet, Sequence};
+use crate::ics06_solomachine::client_state::ClientState;
+use crate::ics06_solomachine::consensus_state::ConsensusState;
+use crate::ics06_solomachine::error::Kind;
+use crate::ics06_solomachine::header::Header;
+use crate::ics06_solomachine::misbehaviour::Misbehaviour;
+use crate::ics06_solomachine::proof::{
+    decode_signature, decode_timestamped_signature, encode, path_bytes, sign_bytes,
+};
+use crate::ics23_commitment::commitment::{CommitmentPrefix, CommitmentProofBytes, CommitmentRoot};
+use crate::ics24_host::identifier::{ChannelId, ClientId, ConnectionId, PortId};
+use crate::ics24_host::Path;
+use crate::Height;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SoloMachineClient;
+
+impl ClientDef for SoloMachineClient {
+    type Header = Header;
+    type ClientState = ClientState;
+    type ConsensusState = ConsensusState;
+    type Misbehaviour = Misbehaviour;
+
+    fn check_header_and_update_state(
+        &self,
+        _ctx: &dyn ClientReader,
+        client_state: Self::ClientState,
+        header: Self::Header,
+    ) -> Result<(Self::ClientState, Self::ConsensusState), Box<dyn std::error::Error>> {
+        header.validate_basic()?;
+
+        if header.sequence != client_state.sequence {
+            return Err(Kind::SequenceMismatch(header.sequence, client_state.sequence).into());
+        }
+
+        let consensus_state = &client_state.consensus_state;
+        if header.timestamp < consensus_state.timestamp {
+            return Err(Kind::TimestampTooLow(header.timestamp, consensus_state.timestamp).into());
+        }
+
+        // The header must be signed by the current key of the solo machine.
+        let sign_bytes = sign_bytes(
+            header.sequence,
+            header.timestamp,
+            &consensus_state.diversifier,
+            DataType::Header,
+            header.data(),
+        );
+        let signature = decode_signature(&header.signature)?;
+        consensus_state.public_key.verify(&sign_bytes, &signature)?;
+
+        let new_consensus_state = ConsensusState::new(
+            header.new_public_key,
+            header.new_diversifier,
+            header.timestamp,
+        );
+        let new_client_state = ClientState {
+            sequence: client_state.sequence + 1,
+            consensus_state: new_consensus_state.clone(),
+            ..client_state
+        };
+
+        Ok((new_client_state, new_consensus_state))
+    }
+
+    fn check_misbehaviour_and_update_state(
+        &self,
+        _ctx: &dyn ClientReader,
+        _client_id: ClientId,
+        client_state: Self::ClientState,
+        misbehaviour: Self::Misbehaviour,
+    ) -> Result<Self::ClientState, Box<dyn std::error::Error>> {
+        misbehaviour.validate_basic()?;
+
+        // Both signatures must have been made by the current key of the solo machine, for the
+        // same sequence.
+        let consensus_state = &client_state.consensus_state;
+        for signature in &[&misbehaviour.signature_one, &misbehaviour.signature_two] {
+            let sign_bytes = sign_bytes(
+                misbehaviour.sequence,
+                signature.timestamp,
+                &consensus_state.diversifier,
+                signature.data_type,
+                signature.data.clone(),
+            );
+            let raw_signature = decode_signature(&signature.signature)?;
+            consensus_state
+                .public_key
+                .verify(&sign_bytes, &raw_signature)?;
+        }
+
+        Ok(client_state.with_frozen_sequence(misbehaviour.sequence))
+    }
+
+    fn verify_upgrade_and_update_state(
+        &self,
+        _client_state: &Self::ClientState,
+        _consensus_state: &Self::ConsensusState,
+        _upgraded_client_state: Self::ClientState,
+        _upgraded_consensus_state: Self::ConsensusState,
+        _proof_upgrade_client: &MerkleProof,
+        _proof_upgrade_consensus_state: &MerkleProof,
+    ) -> Result<(Self::ClientState, Self::ConsensusState), Box<dyn std::error::Error>> {
+        Err(Kind::UpgradeNotSupported.into())
+    }
+
+    fn verify_client_consensus_state(
+        &self,
+        _ctx: &dyn ConnectionReader,
+        client_state: &Self::ClientState,
+        height: Height,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProofBytes,
+        _root: &CommitmentRoot,
+        client_id: &ClientId,
+        consensus_height: Height,
+        expected_consensus_state: &AnyConsensusState,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let path = Path::ClientConsensusState {
+            client_id: client_id.clone(),
+            epoch: consensus_height.revision_number,
+            height: consensus_height.revision_height,
+        };
+        let data = ConsensusStateData {
+            path: path_bytes(prefix, path)?,
+            consensus_state: Some(expected_consensus_state.clone().into()),
+        };
+
+        verify_signed_data(
+            client_state,
+            height,
+            proof,
+            DataType::ConsensusState,
+            encode(&data),
+        )
+    }
+
+    fn verify_connection_state(
+        &self,
+        _ctx: &dyn ConnectionReader,
+        client_state: &Self::ClientState,
+        height: Height,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProofBytes,
+        _root: &CommitmentRoot,
+        connection_id: Option<&ConnectionId>,
+        expected_connection_end: &ConnectionEnd,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let connection_id = connection_id.ok_or("missing connection identifier")?;
+        let path = Path::Connections(connection_id.clone());
+        let data = ConnectionStateData {
+            path: path_bytes(prefix, path)?,
+            connection: Some(expected_connection_end.clone().into()),
+        };
+
+        verify_signed_data(
+            client_state,
+            height,
+            proof,
+            DataType::ConnectionState,
+            encode(&data),
+        )
+    }
+
+    fn verify_channel_state(
+        &self,
+        _ctx: &dyn ChannelReader,
+        client_state: &Self::ClientState,
+        height: Height,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProofBytes,
+        _root: &CommitmentRoot,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        expected_channel_end: &ChannelEnd,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let path = Path::ChannelEnds(port_id.clone(), channel_id.clone());
+        let data = ChannelStateData {
+            path: path_bytes(prefix, path)?,
+            channel: Some(expected_channel_end.clone().into()),
+        };
+
+        verify_signed_data(
+            client_state,
+            height,
+            proof,
+            DataType::ChannelState,
+            encode(&data),
+        )
+    }
+
+    fn verify_client_full_state(
+        &self,
+        _ctx: &dyn ConnectionReader,
+        client_state: &Self::ClientState,
+        height: Height,
+        _root: &CommitmentRoot,
+        prefix: &CommitmentPrefix,
+        client_id: &ClientId,
+        proof: &CommitmentProofBytes,
+        expected_client_state: &AnyClientState,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let path = Path::ClientState(client_id.clone());
+        let data = ClientStateData {
+            path: path_bytes(prefix, path)?,
+            client_state: Some(expected_client_state.clone().into()),
+        };
+
+        verify_signed_data(
+            client_state,
+            height,
+            proof,
+            DataType::ClientState,
+            encode(&data),
+        )
+    }
+
+    fn verify_packet_data(
+        &self,
+        _ctx: &dyn ChannelReader,
+        client_state: &Self::ClientState,
+        height: Height,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProofBytes,
+        _root: &CommitmentRoot,
+        packet: &Packet,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let path = Path::Commitments {
+            port_id: packet.source_port.clone(),
+            channel_id: packet.source_channel.clone(),
+            sequence: packet.sequence,
+        };
+        let data = PacketCommitmentData {
+            path: path_bytes(prefix, path)?,
+            commitment: compute_packet_commitment(
+                &packet.data,
+                &packet.timeout_height,
+                &packet.timeout_timestamp,
+            ),
+        };
+
+        verify_signed_data(
+            client_state,
+            height,
+            proof,
+            DataType::PacketCommitment,
+            encode(&data),
+        )
+    }
+
+    fn verify_packet_acknowledgement(
+        &self,
+        _ctx: &dyn ChannelReader,
+        client_state: &Self::ClientState,
+        height: Height,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProofBytes,
+        _root: &CommitmentRoot,
+        packet: &Packet,
+        ack: Vec<u8>,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let path = Path::Acks {
+            port_id: packet.destination_port.clone(),
+            channel_id: packet.destination_channel.clone(),
+            sequence: packet.sequence,
+        };
+        let data = PacketAcknowledgementData {
+            path: path_bytes(prefix, path)?,
+            acknowledgement: ack,
+        };
+
+        verify_signed_data(
+            client_state,
+            height,
+            proof,
+            DataType::PacketAcknowledgement,
+            encode(&data),
+        )
+    }
+
+    fn verify_next_sequence_recv(
+        &self,
+        _ctx: &dyn ChannelReader,
+        client_state: &Self::ClientState,
+        height: Height,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProofBytes,
+        _root: &CommitmentRoot,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        seq: &Sequence,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let path = Path::SeqRecvs(port_id.clone(), channel_id.clone());
+        let data = NextSequenceRecvData {
+            path: path_bytes(prefix, path)?,
+            next_seq_recv: u64::from(*seq),
+        };
+
+        verify_signed_data(
+            client_state,
+            height,
+            proof,
+            DataType::NextSequenceRecv,
+            encode(&data),
+        )
+    }
+
+    fn verify_packet_receipt_absence(
+        &self,
+        _ctx: &dyn ChannelReader,
+        client_state: &Self::ClientState,
+        height: Height,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProofBytes,
+        _root: &CommitmentRoot,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        seq: &Sequence,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let path = Path::Receipts {
+            port_id: port_id.clone(),
+            channel_id: channel_id.clone(),
+            sequence: *seq,
+        };
+        let data = PacketReceiptAbsenceData {
+            path: path_bytes(prefix, path)?,
+        };
+
+        verify_signed_data(
+            client_state,
+            height,
+            proof,
+            DataType::PacketReceiptAbsence,
+            encode(&data),
+        )
+    }
+
+    /// Every verified proof consumes the current sequence of the solo machine, so that its
+    /// signature cannot be replayed.
+    fn update_state_after_verification(
+        &self,
+        client_state: &Self::ClientState,
+    ) -> Result<Option<Self::ClientState>, Box<dyn std::error::Error>> {
+        Ok(Some(ClientState {
+            sequence: client_state.sequence + 1,
+            ..client_state.clone()
+        }))
+    }
+}
+
+/// Verifies that `proof` is a signature of `data` by the current key of the solo machine, made at
+/// the current sequence of the client. The sequence advances after every verified proof (see
+/// `update_state_after_verification`), hence the proofs of a message share the proof `height` (the
+/// sequence of the first one) but are signed at consecutive sequences.
+fn verify_signed_data(
+    client_state: &ClientState,
+    height: Height,
+    proof: &CommitmentProofBytes,
+    data_type: DataType,
+    data: Vec<u8>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    if client_state.frozen_sequence != 0 {
+        return Err(Kind::ClientFrozen(client_state.frozen_sequence).into());
+    }
+    if height.revision_height > client_state.sequence {
+        return Err(Kind::ProofHeightTooHigh(height.revision_height, client_state.sequence).into());
+    }
+
+    let (signature, timestamp) = decode_timestamped_signature(proof)?;
+
+    let consensus_state = &client_state.consensus_state;
+    if timestamp < consensus_state.timestamp {
+        return Err(Kind::TimestampTooLow(timestamp, consensus_state.timestamp).into());
+    }
+
+    let sign_bytes = sign_bytes(
+        client_state.sequence,
+        timestamp,
+        &consensus_state.diversifier,
+        data_type,
+        data,
+    );
+    consensus_state.public_key.verify(&sign_bytes, &signature)?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use k256::ecdsa::{signature::Signer, Signature, SigningKey};
+
+    use ibc_proto::cosmos::tx::signing::v1beta1::signature_descriptor::data::{Single, Sum};
+    use ibc_proto::cosmos::tx::signing::v1beta1::signature_descriptor::Data as SignatureData;
+    use ibc_proto::cosmos::tx::signing::v1beta1::SignMode;
+    use ibc_proto::ibc::lightclients::solomachine::v1::{DataType, TimestampedSignatureData};
+
+    use std::time::Duration;
+
+    use crate::ics02_client::client_def::ClientDef;
+    use crate::ics02_client::client_state::ClientState as _;
+    use crate::ics02_client::client_type::ClientType;
+    use crate::ics02_client::context::ClientKeeper;
+    use crate::ics03_connection::connection::{ConnectionEnd, Counterparty, State};
+    use crate::ics03_connection::context::ConnectionReader;
+    use crate::ics03_connection::handler::dispatch;
+    use crate::ics03_connection::msgs::conn_open_confirm::MsgConnectionOpenConfirm;
+    use crate::ics03_connection::msgs::ConnectionMsg;
+    use crate::ics03_connection::version::get_compatible_versions;
+    use crate::ics06_solomachine::client_state::test_util::{
+        get_dummy_signing_key, DUMMY_DIVERSIFIER,
+    };
+    use crate::ics06_solomachine::client_state::ClientState;
+    use crate::ics06_solomachine::consensus_state::ConsensusState;
+    use crate::ics06_solomachine::header::Header;
+    use crate::ics06_solomachine::misbehaviour::{Misbehaviour, SignatureAndData};
+    use crate::ics06_solomachine::proof::{encode, path_bytes, sign_bytes};
+    use crate::ics06_solomachine::public_key::PublicKey;
+    use crate::ics23_commitment::commitment::{CommitmentPrefix, CommitmentRoot};
+    use crate::ics24_host::identifier::{ClientId, ConnectionId};
+    use crate::ics24_host::Path;
+    use crate::mock::context::MockContext;
+    use crate::proofs::Proofs;
+    use crate::signer::Signer;
+    use crate::Height;
+
+    use super::SoloMachineClient;
+
+    const DIVERSIFIER: &str = "diversifier";
+
+    fn signing_key(seed: u8) -> SigningKey {
+        SigningKey::from_bytes(&[seed; 32]).unwrap()
+    }
+
+    fn public_key(key: &SigningKey) -> PublicKey {
+        PublicKey::Secp256k1(key.verify_key().to_encoded_point(true).as_bytes().to_vec())
+    }
+
+    fn sign(key: &SigningKey, sign_bytes: &[u8]) -> Vec<u8> {
+        let signature: Signature = key.sign(sign_bytes);
+        let data = SignatureData {
+            sum: Some(Sum::Single(Single {
+                mode: SignMode::Unspecified as i32,
+                signature: signature.as_ref().to_vec(),
+            })),
+        };
+        encode(&data)
+    }
+
+    fn client_state(key: &SigningKey) -> ClientState {
+        let consensus_state = ConsensusState::new(public_key(key), DIVERSIFIER.to_string(), 10);
+        ClientState::new(1, consensus_state, false).unwrap()
+    }
+
+    fn header(key: &SigningKey, new_key: &SigningKey, sequence: u64) -> Header {
+        let mut header = Header {
+            sequence,
+            timestamp: 20,
+            signature: vec![],
+            new_public_key: public_key(new_key),
+            new_diversifier: "new-diversifier".to_string(),
+        };
+        let sign_bytes = sign_bytes(
+            sequence,
+            header.timestamp,
+            DIVERSIFIER,
+            DataType::Header,
+            header.data(),
+        );
+        header.signature = sign(key, &sign_bytes);
+        header
+    }
+
+    #[test]
+    fn header_rotates_key() {
+        let (key, new_key) = (signing_key(1), signing_key(2));
+        let client_state = client_state(&key);
+
+        let ctx = MockContext::default();
+        let (new_client_state, new_consensus_state) = SoloMachineClient
+            .check_header_and_update_state(&ctx, client_state, header(&key, &new_key, 1))
+            .unwrap();
+
+        assert_eq!(new_client_state.sequence, 2);
+        assert_eq!(new_client_state.latest_height(), Height::new(0, 2));
+        assert_eq!(new_consensus_state.public_key, public_key(&new_key));
+        assert_eq!(new_client_state.consensus_state, new_consensus_state);
+    }
+
+    #[test]
+    fn header_with_invalid_signature_fails() {
+        let (key, new_key) = (signing_key(1), signing_key(2));
+        let client_state = client_state(&key);
+        let ctx = MockContext::default();
+
+        // The header is signed by the new key instead of the current one.
+        let result = SoloMachineClient.check_header_and_update_state(
+            &ctx,
+            client_state.clone(),
+            header(&new_key, &new_key, 1),
+        );
+        assert!(result.is_err());
+
+        // The header does not have the client sequence.
+        let result = SoloMachineClient.check_header_and_update_state(
+            &ctx,
+            client_state,
+            header(&key, &key, 2),
+        );
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn misbehaviour_freezes_client() {
+        let key = signing_key(1);
+        let client_state = client_state(&key);
+
+        let signature_and_data = |data: &[u8]| {
+            let sign_bytes = sign_bytes(1, 20, DIVERSIFIER, DataType::Header, data.to_vec());
+            SignatureAndData {
+                signature: sign(&key, &sign_bytes),
+                data_type: DataType::Header,
+                data: data.to_vec(),
+                timestamp: 20,
+            }
+        };
+        let misbehaviour = Misbehaviour {
+            client_id: ClientId::default(),
+            sequence: 1,
+            signature_one: signature_and_data(b"one"),
+            signature_two: signature_and_data(b"two"),
+        };
+
+        let ctx = MockContext::default();
+        let new_client_state = SoloMachineClient
+            .check_misbehaviour_and_update_state(
+                &ctx,
+                ClientId::default(),
+                client_state,
+                misbehaviour,
+            )
+            .unwrap();
+
+        assert!(new_client_state.is_frozen());
+        assert_eq!(new_client_state.frozen_sequence, 1);
+    }
+
+    #[test]
+    fn verify_connection_state_signature() {
+        let key = signing_key(1);
+        let client_state = client_state(&key);
+
+        let prefix = CommitmentPrefix::from(b"ibc".to_vec());
+        let connection_id = ConnectionId::new(0);
+        let connection_end = ConnectionEnd::default();
+
+        let data = ibc_proto::ibc::lightclients::solomachine::v1::ConnectionStateData {
+            path: b"/ibc/connections%2Fconnection-0".to_vec(),
+            connection: Some(connection_end.clone().into()),
+        };
+        let sign_bytes = sign_bytes(1, 20, DIVERSIFIER, DataType::ConnectionState, encode(&data));
+        let proof = encode(&TimestampedSignatureData {
+            signature_data: sign(&key, &sign_bytes),
+            timestamp: 20,
+        });
+
+        let ctx = MockContext::default();
+        let verify = |height: Height| {
+            SoloMachineClient.verify_connection_state(
+                &ctx,
+                &client_state,
+                height,
+                &prefix,
+                &proof.clone().into(),
+                &CommitmentRoot::from_bytes(&[]),
+                Some(&connection_id),
+                &connection_end,
+            )
+        };
+
+        assert!(verify(Height::new(0, 1)).is_ok());
+        // The proof height cannot be ahead of the sequence of the client.
+        assert!(verify(Height::new(0, 2)).is_err());
+    }
+
+    #[test]
+    fn verified_proof_cannot_be_replayed() {
+        let key = signing_key(1);
+        let client_state = client_state(&key);
+
+        let prefix = CommitmentPrefix::from(b"ibc".to_vec());
+        let connection_id = ConnectionId::new(0);
+        let connection_end = ConnectionEnd::default();
+
+        let data = ibc_proto::ibc::lightclients::solomachine::v1::ConnectionStateData {
+            path: b"/ibc/connections%2Fconnection-0".to_vec(),
+            connection: Some(connection_end.clone().into()),
+        };
+        let proof = |sequence: u64| {
+            let sign_bytes = sign_bytes(
+                sequence,
+                20,
+                DIVERSIFIER,
+                DataType::ConnectionState,
+                encode(&data),
+            );
+            encode(&TimestampedSignatureData {
+                signature_data: sign(&key, &sign_bytes),
+                timestamp: 20,
+            })
+        };
+
+        let ctx = MockContext::default();
+        let verify = |client_state: &ClientState, proof: Vec<u8>| {
+            SoloMachineClient.verify_connection_state(
+                &ctx,
+                client_state,
+                Height::new(0, 1),
+                &prefix,
+                &proof.into(),
+                &CommitmentRoot::from_bytes(&[]),
+                Some(&connection_id),
+                &connection_end,
+            )
+        };
+
+        assert!(verify(&client_state, proof(1)).is_ok());
+        let new_client_state = SoloMachineClient
+            .update_state_after_verification(&client_state)
+            .unwrap()
+            .unwrap();
+        assert_eq!(new_client_state.sequence, 2);
+
+        // The signature made at the consumed sequence is rejected, the next proof is signed at
+        // the new sequence.
+        assert!(verify(&new_client_state, proof(1)).is_err());
+        assert!(verify(&new_client_state, proof(2)).is_ok());
+    }
+
+    #[test]
+    fn handler_advances_sequence_and_rejects_replay() {
+        let client_id = ClientId::new(ClientType::SoloMachine, 0).unwrap();
+        let connection_id = ConnectionId::new(0);
+        let counterparty_prefix = CommitmentPrefix::from(b"ibc".to_vec());
+
+        let mut ctx = MockContext::default()
+            .with_client_parametrized(
+                &client_id,
+                Height::new(0, 1),
+                Some(ClientType::SoloMachine),
+                None,
+            )
+            .with_connection(
+                connection_id.clone(),
+                ConnectionEnd::new(
+                    State::TryOpen,
+                    client_id.clone(),
+                    Counterparty::new(
+                        ClientId::default(),
+                        Some(ConnectionId::new(1)),
+                        counterparty_prefix.clone(),
+                    ),
+                    get_compatible_versions(),
+                    Duration::from_secs(0),
+                ),
+            );
+
+        // The solo machine signs the connection end as the handler expects it.
+        let expected_connection_end = ConnectionEnd::new(
+            State::Open,
+            ClientId::default(),
+            Counterparty::new(
+                client_id.clone(),
+                Some(connection_id.clone()),
+                ctx.commitment_prefix(),
+            ),
+            get_compatible_versions(),
+            Duration::from_secs(0),
+        );
+        let data = ibc_proto::ibc::lightclients::solomachine::v1::ConnectionStateData {
+            path: path_bytes(
+                &counterparty_prefix,
+                Path::Connections(ConnectionId::new(1)),
+            )
+            .unwrap(),
+            connection: Some(expected_connection_end.into()),
+        };
+        let sign_bytes = sign_bytes(
+            1,
+            20,
+            DUMMY_DIVERSIFIER,
+            DataType::ConnectionState,
+            encode(&data),
+        );
+        let proof = encode(&TimestampedSignatureData {
+            signature_data: sign(&get_dummy_signing_key(), &sign_bytes),
+            timestamp: 20,
+        });
+        let msg = ConnectionMsg::ConnectionOpenConfirm(MsgConnectionOpenConfirm {
+            connection_id,
+            proofs: Proofs::new(proof.into(), None, None, None, Height::new(0, 1)).unwrap(),
+            signer: Signer::new("signer"),
+        });
+
+        let output = dispatch(&ctx, msg.clone()).unwrap();
+        let (updated_client_id, updated_client_state) = output.result.client_state.unwrap();
+        assert_eq!(updated_client_id, client_id);
+        assert_eq!(updated_client_state.latest_height(), Height::new(0, 2));
+
+        // Once the new sequence is stored, the same message is rejected.
+        ctx.store_client_state(client_id, updated_client_state)
+            .unwrap();
+        assert!(dispatch(&ctx, msg).is_err());
+    }
+}
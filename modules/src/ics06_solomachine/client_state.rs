@@ -0,0 +1,152 @@
+use std::convert::{TryFrom, TryInto};
+use std::str::FromStr;
+use std::time::Duration;
+
+use serde_derive::{Deserialize, Serialize};
+use tendermint_proto::Protobuf;
+
+use ibc_proto::ibc::lightclients::solomachine::v1::ClientState as RawClientState;
+
+use crate::ics02_client::client_state::AnyClientState;
+use crate::ics02_client::client_type::ClientType;
+use crate::ics06_solomachine::consensus_state::ConsensusState;
+use crate::ics06_solomachine::error::{Error, Kind};
+use crate::ics24_host::identifier::ChainId;
+use crate::Height;
+
+/// The state of a solo machine client. Solo machines have no notion of height: the client tracks
+/// instead the `sequence` of the next expected signature, which is used as the height (with
+/// revision number 0) whenever the IBC handlers expect one.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ClientState {
+    pub sequence: u64,
+    /// The sequence at which misbehaviour was detected, or 0 if the client is not frozen.
+    pub frozen_sequence: u64,
+    pub consensus_state: ConsensusState,
+    pub allow_update_after_proposal: bool,
+}
+
+impl ClientState {
+    pub fn new(
+        sequence: u64,
+        consensus_state: ConsensusState,
+        allow_update_after_proposal: bool,
+    ) -> Result<ClientState, Error> {
+        if sequence == 0 {
+            return Err(Kind::InvalidRawClientState
+                .context("sequence cannot be 0")
+                .into());
+        }
+
+        Ok(Self {
+            sequence,
+            frozen_sequence: 0,
+            consensus_state,
+            allow_update_after_proposal,
+        })
+    }
+
+    pub fn latest_height(&self) -> Height {
+        Height::new(0, self.sequence)
+    }
+
+    /// Returns this client state, frozen at sequence `sequence`.
+    pub fn with_frozen_sequence(self, sequence: u64) -> Self {
+        ClientState {
+            frozen_sequence: sequence,
+            ..self
+        }
+    }
+
+    /// Solo machine clients do not expire, hence need no refresh.
+    pub fn refresh_time(&self) -> Option<Duration> {
+        None
+    }
+
+    pub fn expired(&self, _elapsed: Duration) -> bool {
+        false
+    }
+}
+
+impl crate::ics02_client::client_state::ClientState for ClientState {
+    fn chain_id(&self) -> ChainId {
+        // Solo machines are not chains, hence have no chain identifier.
+        ChainId::from_str("").unwrap()
+    }
+
+    fn client_type(&self) -> ClientType {
+        ClientType::SoloMachine
+    }
+
+    fn latest_height(&self) -> Height {
+        self.latest_height()
+    }
+
+    fn is_frozen(&self) -> bool {
+        self.frozen_sequence != 0
+    }
+
+    fn wrap_any(self) -> AnyClientState {
+        AnyClientState::SoloMachine(self)
+    }
+}
+
+impl From<ClientState> for AnyClientState {
+    fn from(cs: ClientState) -> Self {
+        AnyClientState::SoloMachine(cs)
+    }
+}
+
+impl Protobuf<RawClientState> for ClientState {}
+
+impl TryFrom<RawClientState> for ClientState {
+    type Error = Error;
+
+    fn try_from(raw: RawClientState) -> Result<Self, Self::Error> {
+        Ok(Self {
+            sequence: raw.sequence,
+            frozen_sequence: raw.frozen_sequence,
+            consensus_state: raw
+                .consensus_state
+                .ok_or_else(|| Kind::InvalidRawClientState.context("missing consensus state"))?
+                .try_into()?,
+            allow_update_after_proposal: raw.allow_update_after_proposal,
+        })
+    }
+}
+
+impl From<ClientState> for RawClientState {
+    fn from(value: ClientState) -> Self {
+        RawClientState {
+            sequence: value.sequence,
+            frozen_sequence: value.frozen_sequence,
+            consensus_state: Some(value.consensus_state.into()),
+            allow_update_after_proposal: value.allow_update_after_proposal,
+        }
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
+pub mod test_util {
+    use k256::ecdsa::SigningKey;
+
+    use crate::ics06_solomachine::client_state::ClientState;
+    use crate::ics06_solomachine::consensus_state::ConsensusState;
+    use crate::ics06_solomachine::public_key::PublicKey;
+
+    pub const DUMMY_DIVERSIFIER: &str = "diversifier";
+
+    /// The key of the dummy solo machine, derived from a fixed seed so that tests can sign proofs.
+    pub fn get_dummy_signing_key() -> SigningKey {
+        SigningKey::from_bytes(&[1; 32]).unwrap()
+    }
+
+    pub fn get_dummy_solomachine_client_state(sequence: u64) -> ClientState {
+        let key = get_dummy_signing_key();
+        let public_key =
+            PublicKey::Secp256k1(key.verify_key().to_encoded_point(true).as_bytes().to_vec());
+        let consensus_state = ConsensusState::new(public_key, DUMMY_DIVERSIFIER.to_string(), 1);
+
+        ClientState::new(sequence, consensus_state, false).unwrap()
+    }
+}
@@ -0,0 +1,110 @@
+use std::convert::{TryFrom, TryInto};
+
+use serde_derive::{Deserialize, Serialize};
+use tendermint_proto::Protobuf;
+
+use ibc_proto::ibc::lightclients::solomachine::v1::ConsensusState as RawConsensusState;
+
+use crate::ics02_client::client_consensus::AnyConsensusState;
+use crate::ics02_client::client_type::ClientType;
+use crate::ics06_solomachine::error::{Error, Kind};
+use crate::ics06_solomachine::public_key::PublicKey;
+use crate::ics23_commitment::commitment::CommitmentRoot;
+use crate::timestamp::Timestamp;
+
+/// The consensus state of a solo machine: the key that currently signs for the solo machine,
+/// together with the diversifier that prevents signatures from being replayed across clients.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConsensusState {
+    pub public_key: PublicKey,
+    pub diversifier: String,
+    /// Unix timestamp in nanoseconds.
+    pub timestamp: u64,
+    /// Solo machines do not commit to their state with a Merkle root, their proofs are signatures
+    /// instead. The root is always empty and is not part of the raw consensus state.
+    #[serde(skip, default = "empty_root")]
+    root: CommitmentRoot,
+}
+
+fn empty_root() -> CommitmentRoot {
+    CommitmentRoot::from_bytes(&[])
+}
+
+impl ConsensusState {
+    pub fn new(public_key: PublicKey, diversifier: String, timestamp: u64) -> Self {
+        Self {
+            public_key,
+            diversifier,
+            timestamp,
+            root: empty_root(),
+        }
+    }
+
+    pub fn timestamp(&self) -> Timestamp {
+        Timestamp::from_nanoseconds(self.timestamp).unwrap_or_default()
+    }
+}
+
+impl crate::ics02_client::client_consensus::ConsensusState for ConsensusState {
+    fn client_type(&self) -> ClientType {
+        ClientType::SoloMachine
+    }
+
+    fn root(&self) -> &CommitmentRoot {
+        &self.root
+    }
+
+    fn validate_basic(&self) -> Result<(), Box<dyn std::error::Error>> {
+        if self.timestamp == 0 {
+            return Err(Kind::InvalidRawConsensusState
+                .context("timestamp cannot be 0")
+                .into());
+        }
+        if self.diversifier.trim().is_empty() {
+            return Err(Kind::InvalidRawConsensusState
+                .context("diversifier cannot contain only spaces")
+                .into());
+        }
+
+        Ok(())
+    }
+
+    fn wrap_any(self) -> AnyConsensusState {
+        AnyConsensusState::SoloMachine(self)
+    }
+}
+
+impl From<ConsensusState> for AnyConsensusState {
+    fn from(cs: ConsensusState) -> Self {
+        AnyConsensusState::SoloMachine(cs)
+    }
+}
+
+impl Protobuf<RawConsensusState> for ConsensusState {}
+
+impl TryFrom<RawConsensusState> for ConsensusState {
+    type Error = Error;
+
+    fn try_from(raw: RawConsensusState) -> Result<Self, Self::Error> {
+        let public_key = raw
+            .public_key
+            .ok_or_else(|| Kind::InvalidRawConsensusState.context("missing public key"))?
+            .try_into()?;
+
+        Ok(ConsensusState::new(
+            public_key,
+            raw.diversifier,
+            raw.timestamp,
+        ))
+    }
+}
+
+impl From<ConsensusState> for RawConsensusState {
+    fn from(value: ConsensusState) -> Self {
+        RawConsensusState {
+            public_key: Some(value.public_key.into()),
+            diversifier: value.diversifier,
+            timestamp: value.timestamp,
+        }
+    }
+}
@@ -0,0 +1,61 @@
+use anomaly::{BoxError, Context};
+use thiserror::Error;
+
+pub type Error = anomaly::Error<Kind>;
+
+#[derive(Clone, Debug, Error)]
+pub enum Kind {
+    #[error("invalid raw client state")]
+    InvalidRawClientState,
+
+    #[error("invalid raw client consensus state")]
+    InvalidRawConsensusState,
+
+    #[error("invalid raw header")]
+    InvalidRawHeader,
+
+    #[error("invalid raw misbehaviour")]
+    InvalidRawMisbehaviour,
+
+    #[error("invalid header, failed basic validation")]
+    InvalidHeader,
+
+    #[error("invalid misbehaviour, failed basic validation")]
+    InvalidMisbehaviour,
+
+    #[error("unknown public key type: {0}")]
+    UnknownPublicKeyType(String),
+
+    #[error("invalid public key")]
+    InvalidPublicKey,
+
+    #[error("invalid signature data")]
+    InvalidSignatureData,
+
+    #[error("invalid proof")]
+    InvalidProof,
+
+    #[error("signature verification failed")]
+    SignatureVerificationFailed,
+
+    #[error("the sequence {0} does not match the client sequence {1}")]
+    SequenceMismatch(u64, u64),
+
+    #[error("the proof height {0} is ahead of the client sequence {1}")]
+    ProofHeightTooHigh(u64, u64),
+
+    #[error("the timestamp {0} is lower than the consensus state timestamp {1}")]
+    TimestampTooLow(u64, u64),
+
+    #[error("the client is frozen at sequence {0}")]
+    ClientFrozen(u64),
+
+    #[error("solo machine clients cannot be upgraded")]
+    UpgradeNotSupported,
+}
+
+impl Kind {
+    pub fn context(self, source: impl Into<BoxError>) -> Context<Self> {
+        Context::new(self, Some(source.into()))
+    }
+}
@@ -0,0 +1,116 @@
+use std::convert::{TryFrom, TryInto};
+
+use serde_derive::{Deserialize, Serialize};
+use tendermint_proto::Protobuf;
+
+use ibc_proto::ibc::lightclients::solomachine::v1::{
+    Header as RawHeader, HeaderData as RawHeaderData,
+};
+
+use crate::ics02_client::client_type::ClientType;
+use crate::ics02_client::header::AnyHeader;
+use crate::ics06_solomachine::error::{Error, Kind};
+use crate::ics06_solomachine::public_key::PublicKey;
+use crate::Height;
+
+/// A solo machine header, signed with the current key of the solo machine, which rotates the key
+/// and diversifier of the client to the new ones.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct Header {
+    pub sequence: u64,
+    /// Unix timestamp in nanoseconds.
+    pub timestamp: u64,
+    pub signature: Vec<u8>,
+    pub new_public_key: PublicKey,
+    pub new_diversifier: String,
+}
+
+impl Header {
+    pub fn height(&self) -> Height {
+        Height::new(0, self.sequence)
+    }
+
+    /// Returns the data signed by the solo machine in this header.
+    pub fn data(&self) -> Vec<u8> {
+        let data = RawHeaderData {
+            new_pub_key: Some(self.new_public_key.clone().into()),
+            new_diversifier: self.new_diversifier.clone(),
+        };
+
+        let mut buf = Vec::new();
+        prost::Message::encode(&data, &mut buf).unwrap();
+        buf
+    }
+
+    pub fn validate_basic(&self) -> Result<(), Error> {
+        if self.sequence == 0 {
+            return Err(Kind::InvalidHeader.context("sequence cannot be 0").into());
+        }
+        if self.timestamp == 0 {
+            return Err(Kind::InvalidHeader.context("timestamp cannot be 0").into());
+        }
+        if self.signature.is_empty() {
+            return Err(Kind::InvalidHeader
+                .context("signature cannot be empty")
+                .into());
+        }
+        if self.new_diversifier.trim().is_empty() {
+            return Err(Kind::InvalidHeader
+                .context("diversifier cannot contain only spaces")
+                .into());
+        }
+
+        Ok(())
+    }
+}
+
+impl crate::ics02_client::header::Header for Header {
+    fn client_type(&self) -> ClientType {
+        ClientType::SoloMachine
+    }
+
+    fn height(&self) -> Height {
+        self.height()
+    }
+
+    fn wrap_any(self) -> AnyHeader {
+        AnyHeader::SoloMachine(self)
+    }
+}
+
+impl From<Header> for AnyHeader {
+    fn from(header: Header) -> Self {
+        AnyHeader::SoloMachine(header)
+    }
+}
+
+impl Protobuf<RawHeader> for Header {}
+
+impl TryFrom<RawHeader> for Header {
+    type Error = Error;
+
+    fn try_from(raw: RawHeader) -> Result<Self, Self::Error> {
+        Ok(Self {
+            sequence: raw.sequence,
+            timestamp: raw.timestamp,
+            signature: raw.signature,
+            new_public_key: raw
+                .new_public_key
+                .ok_or_else(|| Kind::InvalidRawHeader.context("missing new public key"))?
+                .try_into()?,
+            new_diversifier: raw.new_diversifier,
+        })
+    }
+}
+
+impl From<Header> for RawHeader {
+    fn from(value: Header) -> Self {
+        RawHeader {
+            sequence: value.sequence,
+            timestamp: value.timestamp,
+            signature: value.signature,
+            new_public_key: Some(value.new_public_key.into()),
+            new_diversifier: value.new_diversifier,
+        }
+    }
+}
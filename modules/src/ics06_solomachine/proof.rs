@@ -0,0 +1,114 @@
+//! Construction of the bytes signed by a solo machine, and decoding of its signatures.
+//!
+//! A solo machine proves its state by signing it: the signed bytes are a protobuf-encoded
+//! `SignBytes`, which binds the signed data to the sequence, timestamp and diversifier of the
+//! solo machine. Signatures are transmitted as protobuf-encoded `SignatureDescriptor.Data`,
+//! wrapped together with their timestamp in a `TimestampedSignatureData` when used as proofs.
+
+use ibc_proto::cosmos::tx::signing::v1beta1::signature_descriptor::data::Sum;
+use ibc_proto::cosmos::tx::signing::v1beta1::signature_descriptor::Data as SignatureData;
+use ibc_proto::ibc::lightclients::solomachine::v1::{
+    DataType, SignBytes, TimestampedSignatureData,
+};
+
+use crate::ics06_solomachine::error::{Error, Kind};
+use crate::ics23_commitment::commitment::{CommitmentPrefix, CommitmentProofBytes};
+use crate::ics23_commitment::merkle::apply_prefix;
+use crate::ics24_host::Path;
+
+/// Returns the bytes that the solo machine signs for `data` of type `data_type`.
+pub fn sign_bytes(
+    sequence: u64,
+    timestamp: u64,
+    diversifier: &str,
+    data_type: DataType,
+    data: Vec<u8>,
+) -> Vec<u8> {
+    let sign_bytes = SignBytes {
+        sequence,
+        timestamp,
+        diversifier: diversifier.to_string(),
+        data_type: data_type as i32,
+        data,
+    };
+
+    encode(&sign_bytes)
+}
+
+/// Extracts the raw signature out of the encoded `SignatureDescriptor.Data` in `signature_data`.
+/// Only single signatures are supported.
+pub fn decode_signature(signature_data: &[u8]) -> Result<Vec<u8>, Error> {
+    let data: SignatureData = prost::Message::decode(signature_data)
+        .map_err(|e| Kind::InvalidSignatureData.context(e))?;
+
+    match data.sum {
+        Some(Sum::Single(single)) => Ok(single.signature),
+        Some(Sum::Multi(_)) => Err(Kind::InvalidSignatureData
+            .context("multi-signatures are not supported")
+            .into()),
+        None => Err(Kind::InvalidSignatureData
+            .context("missing signature")
+            .into()),
+    }
+}
+
+/// Decodes a solo machine `proof` into the raw signature and the timestamp at which it was made.
+pub fn decode_timestamped_signature(proof: &CommitmentProofBytes) -> Result<(Vec<u8>, u64), Error> {
+    let bytes: Vec<u8> = proof.clone().into();
+    let data: TimestampedSignatureData =
+        prost::Message::decode(bytes.as_slice()).map_err(|e| Kind::InvalidProof.context(e))?;
+
+    Ok((decode_signature(&data.signature_data)?, data.timestamp))
+}
+
+/// Returns the bytes of the (prefixed) `path` as signed by the solo machine, i.e., the keys of
+/// the Merkle path joined by `/`, with each key escaped as an URL path segment.
+pub fn path_bytes(prefix: &CommitmentPrefix, path: Path) -> Result<Vec<u8>, Error> {
+    let merkle_path =
+        apply_prefix(prefix, vec![path.to_string()]).map_err(|e| Kind::InvalidProof.context(e))?;
+
+    let path: String = merkle_path
+        .key_path
+        .iter()
+        .map(|key| format!("/{}", escape_path_segment(key)))
+        .collect();
+
+    Ok(path.into_bytes())
+}
+
+pub(crate) fn encode<M: prost::Message>(message: &M) -> Vec<u8> {
+    let mut buf = Vec::new();
+    prost::Message::encode(message, &mut buf).unwrap();
+    buf
+}
+
+fn escape_path_segment(segment: &str) -> String {
+    segment
+        .bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' => (b as char).to_string(),
+            b'-' | b'_' | b'.' | b'~' | b'$' | b'&' | b'+' | b',' | b';' | b'=' | b':' | b'@' => {
+                (b as char).to_string()
+            }
+            _ => format!("%{:02X}", b),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ics23_commitment::commitment::CommitmentPrefix;
+    use crate::ics24_host::identifier::ConnectionId;
+    use crate::ics24_host::Path;
+
+    use super::path_bytes;
+
+    #[test]
+    fn path_bytes_escapes_keys() {
+        let prefix = CommitmentPrefix::from(b"ibc".to_vec());
+        let path = Path::Connections(ConnectionId::new(0));
+
+        let bytes = path_bytes(&prefix, path).unwrap();
+        assert_eq!(bytes, b"/ibc/connections%2Fconnection-0".to_vec());
+    }
+}
@@ -0,0 +1,97 @@
+use std::convert::TryFrom;
+
+use prost_types::Any;
+use serde_derive::{Deserialize, Serialize};
+use tendermint::signature::Ed25519Signature;
+
+use crate::ics06_solomachine::error::{Error, Kind};
+
+pub const SECP256K1_PUB_KEY_TYPE_URL: &str = "/cosmos.crypto.secp256k1.PubKey";
+pub const ED25519_PUB_KEY_TYPE_URL: &str = "/cosmos.crypto.ed25519.PubKey";
+
+/// The public key of a solo machine, against which the signatures of its headers, proofs and
+/// misbehaviour evidence are verified.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PublicKey {
+    /// A secp256k1 public key, in compressed SEC1 encoding.
+    Secp256k1(Vec<u8>),
+    /// An Ed25519 public key.
+    Ed25519(Vec<u8>),
+}
+
+impl PublicKey {
+    pub fn as_bytes(&self) -> &[u8] {
+        match self {
+            PublicKey::Secp256k1(bytes) => bytes,
+            PublicKey::Ed25519(bytes) => bytes,
+        }
+    }
+
+    /// Verifies that `signature` is a valid signature of `msg` by this key.
+    pub fn verify(&self, msg: &[u8], signature: &[u8]) -> Result<(), Error> {
+        match self {
+            PublicKey::Secp256k1(bytes) => {
+                use k256::ecdsa::signature::Verifier as _;
+
+                let key = k256::ecdsa::VerifyingKey::from_sec1_bytes(bytes)
+                    .map_err(|e| Kind::InvalidPublicKey.context(e.to_string()))?;
+                let signature = k256::ecdsa::Signature::try_from(signature)
+                    .map_err(|e| Kind::InvalidSignatureData.context(e.to_string()))?;
+
+                key.verify(msg, &signature).map_err(|e| {
+                    Kind::SignatureVerificationFailed
+                        .context(e.to_string())
+                        .into()
+                })
+            }
+            PublicKey::Ed25519(bytes) => {
+                use tendermint::signature::Verifier as _;
+
+                let key = tendermint::PublicKey::from_raw_ed25519(bytes)
+                    .and_then(|key| key.ed25519())
+                    .ok_or(Kind::InvalidPublicKey)?;
+                let signature = Ed25519Signature::try_from(signature)
+                    .map_err(|e| Kind::InvalidSignatureData.context(e.to_string()))?;
+
+                key.verify(msg, &signature).map_err(|e| {
+                    Kind::SignatureVerificationFailed
+                        .context(e.to_string())
+                        .into()
+                })
+            }
+        }
+    }
+}
+
+impl TryFrom<Any> for PublicKey {
+    type Error = Error;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        // Both key types are encoded as a message holding the key bytes as its only field.
+        let bytes = <Vec<u8> as prost::Message>::decode(raw.value.as_slice())
+            .map_err(|e| Kind::InvalidPublicKey.context(e))?;
+
+        match raw.type_url.as_str() {
+            SECP256K1_PUB_KEY_TYPE_URL => Ok(PublicKey::Secp256k1(bytes)),
+            ED25519_PUB_KEY_TYPE_URL => Ok(PublicKey::Ed25519(bytes)),
+            _ => Err(Kind::UnknownPublicKeyType(raw.type_url).into()),
+        }
+    }
+}
+
+impl From<PublicKey> for Any {
+    fn from(value: PublicKey) -> Self {
+        let (type_url, bytes) = match value {
+            PublicKey::Secp256k1(bytes) => (SECP256K1_PUB_KEY_TYPE_URL, bytes),
+            PublicKey::Ed25519(bytes) => (ED25519_PUB_KEY_TYPE_URL, bytes),
+        };
+
+        let mut buf = Vec::new();
+        prost::Message::encode(&bytes, &mut buf).unwrap();
+
+        Any {
+            type_url: type_url.to_string(),
+            value: buf,
+        }
+    }
+}
@@ -1,17 +1,40 @@
+use std::convert::TryFrom;
+
+use chrono::{DateTime, Utc};
+use tendermint::trust_threshold::TrustThresholdFraction as TrustThreshold;
+use tendermint_light_client::operations::voting_power::ProdVotingPowerCalculator;
+use tendermint_light_client::predicates::{ProdPredicates, VerificationPredicates};
+use tendermint_proto::Protobuf;
+
+use ibc_proto::ibc::core::commitment::v1::{MerklePath, MerkleProof as RawMerkleProof};
+
 use crate::ics02_client::client_consensus::AnyConsensusState;
 use crate::ics02_client::client_def::ClientDef;
 use crate::ics02_client::client_state::AnyClientState;
+use crate::ics02_client::context::ClientReader;
 use crate::ics03_connection::connection::ConnectionEnd;
+use crate::ics03_connection::context::ConnectionReader;
 use crate::ics04_channel::channel::ChannelEnd;
-use crate::ics04_channel::packet::Sequence;
+use crate::ics04_channel::commitment::{compute_ack_commitment, compute_packet_commitment};
+use crate::ics04_channel::context::ChannelReader;
+use crate::ics04_channel::packet::{Packet, Sequence};
 use crate::ics07_tendermint::client_state::ClientState;
 use crate::ics07_tendermint::consensus_state::ConsensusState;
+use crate::ics07_tendermint::error::{Error, Kind};
 use crate::ics07_tendermint::header::Header;
+use crate::ics07_tendermint::misbehaviour::Misbehaviour;
 use crate::ics23_commitment::commitment::{CommitmentPrefix, CommitmentProofBytes, CommitmentRoot};
+use crate::ics23_commitment::merkle::{apply_prefix, MerkleProof};
+use crate::ics23_commitment::specs::ProofSpecs;
 use crate::ics24_host::identifier::ConnectionId;
 use crate::ics24_host::identifier::{ChannelId, ClientId, PortId};
+use crate::ics24_host::{ClientUpgradePath, Path};
 use crate::Height;
 
+/// Placeholder commitment root of the consensus state stored upon a client upgrade, as the root
+/// of the upgraded chain is unknown until the client is updated past the upgrade height.
+pub const SENTINEL_ROOT: &[u8] = b"sentinel_root";
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct TendermintClient;
 
@@ -19,9 +42,11 @@ impl ClientDef for TendermintClient {
     type Header = Header;
     type ClientState = ClientState;
     type ConsensusState = ConsensusState;
+    type Misbehaviour = Misbehaviour;
 
     fn check_header_and_update_state(
         &self,
+        _ctx: &dyn ClientReader,
         client_state: Self::ClientState,
         header: Self::Header,
     ) -> Result<(Self::ClientState, Self::ConsensusState), Box<dyn std::error::Error>> {
@@ -40,104 +65,528 @@ impl ClientDef for TendermintClient {
         ))
     }
 
+    fn check_misbehaviour_and_update_state(
+        &self,
+        ctx: &dyn ClientReader,
+        client_id: ClientId,
+        client_state: Self::ClientState,
+        misbehaviour: Self::Misbehaviour,
+    ) -> Result<Self::ClientState, Box<dyn std::error::Error>> {
+        let (header1, header2) = (&misbehaviour.header1, &misbehaviour.header2);
+
+        for header in &[header1, header2] {
+            let chain_id = header.signed_header.header.chain_id.as_str();
+            if chain_id != client_state.chain_id.as_str() {
+                return Err(Kind::MisbehaviourChainIdMismatch(
+                    chain_id.to_string(),
+                    client_state.chain_id.to_string(),
+                )
+                .into());
+            }
+        }
+
+        if header1.height() < header2.height() {
+            return Err(
+                Kind::InvalidMisbehaviourHeaderHeights(header1.height(), header2.height()).into(),
+            );
+        }
+
+        // The headers constitute evidence of misbehaviour only if they are a fork (same height,
+        // different blocks) or a BFT time violation.
+        if header1.compatible_with(header2) {
+            return Err(Kind::MisbehaviourHeadersNotConflicting.into());
+        }
+
+        let now = ctx
+            .host_timestamp()
+            .as_datetime()
+            .ok_or(Kind::MissingHostTimestamp)?;
+
+        // Both headers must have been signed by the validator set that this client trusts,
+        // as of a consensus state which is still within the trusting period.
+        for header in &[header1, header2] {
+            let header_time: DateTime<Utc> = header.time().into();
+            let ahead = header_time.signed_duration_since(now).to_std();
+            if matches!(ahead, Ok(ahead) if ahead > client_state.max_clock_drift) {
+                return Err(Kind::HeaderFromFuture(header.height()).into());
+            }
+
+            let consensus_state = match ctx.consensus_state(&client_id, header.trusted_height) {
+                Some(AnyConsensusState::Tendermint(cs)) => cs,
+                _ => return Err(Kind::MissingTrustedConsensusState(header.trusted_height).into()),
+            };
+
+            let trusted_time: DateTime<Utc> = consensus_state.timestamp.into();
+            let elapsed = now.signed_duration_since(trusted_time).to_std();
+            if matches!(elapsed, Ok(elapsed) if elapsed >= client_state.trusting_period) {
+                return Err(Kind::TrustedConsensusStateExpired(header.trusted_height).into());
+            }
+
+            if header.trusted_validator_set.hash() != consensus_state.next_validators_hash {
+                return Err(Kind::TrustedValidatorSetMismatch(header.trusted_height).into());
+            }
+
+            verify_header_commit(header, &client_state.trust_level)?;
+        }
+
+        Ok(client_state.with_frozen_height(header1.height()))
+    }
+
+    fn verify_upgrade_and_update_state(
+        &self,
+        client_state: &Self::ClientState,
+        consensus_state: &Self::ConsensusState,
+        upgraded_client_state: Self::ClientState,
+        upgraded_consensus_state: Self::ConsensusState,
+        proof_upgrade_client: &RawMerkleProof,
+        proof_upgrade_consensus_state: &RawMerkleProof,
+    ) -> Result<(Self::ClientState, Self::ConsensusState), Box<dyn std::error::Error>> {
+        if upgraded_client_state.latest_height() <= client_state.latest_height() {
+            return Err(Kind::LowUpgradeHeight(
+                upgraded_client_state.latest_height(),
+                client_state.latest_height(),
+            )
+            .into());
+        }
+
+        // The upgraded states are committed by the counterparty chain under the key of its last
+        // (pre-upgrade) height, which is the latest height of this client.
+        let last_height = client_state.latest_height().revision_height;
+
+        // Only the chain-specific fields of the upgraded client state are committed; the
+        // client-chosen fields are expected to be zeroed out.
+        let client_path = upgrade_merkle_path(
+            &client_state.upgrade_path,
+            ClientUpgradePath::UpgradedClientState(last_height),
+        )?;
+        let client_value = AnyClientState::Tendermint(ClientState::zero_custom_fields(
+            upgraded_client_state.clone(),
+        ))
+        .encode_vec()?;
+
+        MerkleProof::try_from(proof_upgrade_client.clone())?
+            .verify_membership(
+                &ProofSpecs::cosmos(),
+                &consensus_state.root,
+                client_path,
+                client_value,
+                0,
+            )
+            .map_err(|e| Error::from(Kind::InvalidUpgradeClientProof.context(e)))?;
+
+        let consensus_path = upgrade_merkle_path(
+            &client_state.upgrade_path,
+            ClientUpgradePath::UpgradedClientConsensusState(last_height),
+        )?;
+        let consensus_value =
+            AnyConsensusState::Tendermint(upgraded_consensus_state.clone()).encode_vec()?;
+
+        MerkleProof::try_from(proof_upgrade_consensus_state.clone())?
+            .verify_membership(
+                &ProofSpecs::cosmos(),
+                &consensus_state.root,
+                consensus_path,
+                consensus_value,
+                0,
+            )
+            .map_err(|e| Error::from(Kind::InvalidUpgradeConsensusStateProof.context(e)))?;
+
+        // The new client keeps the parameters chosen by the relayer that created it, while
+        // taking the chain-specific parameters from the upgraded client state.
+        let new_client_state = ClientState {
+            trust_level: client_state.trust_level,
+            trusting_period: client_state.trusting_period,
+            max_clock_drift: client_state.max_clock_drift,
+            allow_update: client_state.allow_update,
+            frozen_height: Height::zero(),
+            ..upgraded_client_state
+        };
+
+        // The new client can only be used for proof verification once it has been updated past
+        // the upgrade height.
+        let new_consensus_state = ConsensusState::new(
+            CommitmentRoot::from_bytes(SENTINEL_ROOT),
+            upgraded_consensus_state.timestamp,
+            upgraded_consensus_state.next_validators_hash,
+        );
+
+        Ok((new_client_state, new_consensus_state))
+    }
+
     fn verify_client_consensus_state(
         &self,
-        _client_state: &Self::ClientState,
-        _height: Height,
-        _prefix: &CommitmentPrefix,
-        _proof: &CommitmentProofBytes,
-        _client_id: &ClientId,
-        _consensus_height: Height,
-        _expected_consensus_state: &AnyConsensusState,
+        _ctx: &dyn ConnectionReader,
+        client_state: &Self::ClientState,
+        height: Height,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProofBytes,
+        root: &CommitmentRoot,
+        client_id: &ClientId,
+        consensus_height: Height,
+        expected_consensus_state: &AnyConsensusState,
     ) -> Result<(), Box<dyn std::error::Error>> {
-        todo!()
+        client_state.verify_height(height)?;
+
+        let path = Path::ClientConsensusState {
+            client_id: client_id.clone(),
+            epoch: consensus_height.revision_number,
+            height: consensus_height.revision_height,
+        };
+        let value = expected_consensus_state.encode_vec()?;
+
+        verify_membership(prefix, proof, root, path, value)
     }
 
     fn verify_connection_state(
         &self,
-        _client_state: &Self::ClientState,
-        _height: Height,
-        _prefix: &CommitmentPrefix,
-        _proof: &CommitmentProofBytes,
-        _connection_id: Option<&ConnectionId>,
-        _expected_connection_end: &ConnectionEnd,
+        _ctx: &dyn ConnectionReader,
+        client_state: &Self::ClientState,
+        height: Height,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProofBytes,
+        root: &CommitmentRoot,
+        connection_id: Option<&ConnectionId>,
+        expected_connection_end: &ConnectionEnd,
     ) -> Result<(), Box<dyn std::error::Error>> {
-        todo!()
+        client_state.verify_height(height)?;
+
+        let connection_id = connection_id.ok_or(Kind::MissingConnectionId)?;
+        let path = Path::Connections(connection_id.clone());
+        let value = expected_connection_end.encode_vec()?;
+
+        verify_membership(prefix, proof, root, path, value)
     }
 
     fn verify_channel_state(
         &self,
-        _client_state: &Self::ClientState,
-        _height: Height,
-        _prefix: &CommitmentPrefix,
-        _proof: &CommitmentProofBytes,
-        _port_id: &PortId,
-        _channel_id: &ChannelId,
-        _expected_channel_end: &ChannelEnd,
+        _ctx: &dyn ChannelReader,
+        client_state: &Self::ClientState,
+        height: Height,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProofBytes,
+        root: &CommitmentRoot,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        expected_channel_end: &ChannelEnd,
     ) -> Result<(), Box<dyn std::error::Error>> {
-        todo!()
+        client_state.verify_height(height)?;
+
+        let path = Path::ChannelEnds(port_id.clone(), channel_id.clone());
+        let value = expected_channel_end.encode_vec()?;
+
+        verify_membership(prefix, proof, root, path, value)
     }
 
     fn verify_client_full_state(
         &self,
-        _client_state: &Self::ClientState,
-        _height: Height,
-        _root: &CommitmentRoot,
-        _prefix: &CommitmentPrefix,
-        _client_id: &ClientId,
-        _proof: &CommitmentProofBytes,
-        _expected_client_state: &AnyClientState,
+        _ctx: &dyn ConnectionReader,
+        client_state: &Self::ClientState,
+        height: Height,
+        root: &CommitmentRoot,
+        prefix: &CommitmentPrefix,
+        client_id: &ClientId,
+        proof: &CommitmentProofBytes,
+        expected_client_state: &AnyClientState,
     ) -> Result<(), Box<dyn std::error::Error>> {
-        unimplemented!()
+        client_state.verify_height(height)?;
+
+        let path = Path::ClientState(client_id.clone());
+        let value = expected_client_state.encode_vec()?;
+
+        verify_membership(prefix, proof, root, path, value)
     }
 
     fn verify_packet_data(
         &self,
-        _client_state: &Self::ClientState,
-        _height: Height,
-        _proof: &CommitmentProofBytes,
-        _port_id: &PortId,
-        _channel_id: &ChannelId,
-        _seq: &Sequence,
-        _data: String,
+        _ctx: &dyn ChannelReader,
+        client_state: &Self::ClientState,
+        height: Height,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProofBytes,
+        root: &CommitmentRoot,
+        packet: &Packet,
     ) -> Result<(), Box<dyn std::error::Error>> {
-        todo!()
+        client_state.verify_height(height)?;
+
+        let path = Path::Commitments {
+            port_id: packet.source_port.clone(),
+            channel_id: packet.source_channel.clone(),
+            sequence: packet.sequence,
+        };
+        let value = compute_packet_commitment(
+            &packet.data,
+            &packet.timeout_height,
+            &packet.timeout_timestamp,
+        );
+
+        verify_membership(prefix, proof, root, path, value)
     }
 
     fn verify_packet_acknowledgement(
         &self,
-        _client_state: &Self::ClientState,
-        _height: Height,
-        _proof: &CommitmentProofBytes,
-        _port_id: &PortId,
-        _channel_id: &ChannelId,
-        _seq: &Sequence,
-        _data: Vec<u8>,
+        _ctx: &dyn ChannelReader,
+        client_state: &Self::ClientState,
+        height: Height,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProofBytes,
+        root: &CommitmentRoot,
+        packet: &Packet,
+        ack: Vec<u8>,
     ) -> Result<(), Box<dyn std::error::Error>> {
-        todo!()
+        client_state.verify_height(height)?;
+
+        // The acknowledgement is written by the receiving chain, under the destination port and
+        // channel of the packet.
+        let path = Path::Acks {
+            port_id: packet.destination_port.clone(),
+            channel_id: packet.destination_channel.clone(),
+            sequence: packet.sequence,
+        };
+
+        verify_membership(prefix, proof, root, path, compute_ack_commitment(&ack))
     }
 
     fn verify_next_sequence_recv(
         &self,
-        _client_state: &Self::ClientState,
-        _height: Height,
-        _proof: &CommitmentProofBytes,
-        _port_id: &PortId,
-        _channel_id: &ChannelId,
-        _seq: &Sequence,
+        _ctx: &dyn ChannelReader,
+        client_state: &Self::ClientState,
+        height: Height,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProofBytes,
+        root: &CommitmentRoot,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        seq: &Sequence,
     ) -> Result<(), Box<dyn std::error::Error>> {
-        todo!()
+        client_state.verify_height(height)?;
+
+        let path = Path::SeqRecvs(port_id.clone(), channel_id.clone());
+        // The sequence is stored as a big-endian encoded `u64`.
+        let value = u64::from(*seq).to_be_bytes().to_vec();
+
+        verify_membership(prefix, proof, root, path, value)
     }
 
     fn verify_packet_receipt_absence(
         &self,
-        _client_state: &Self::ClientState,
-        _height: Height,
-        _proof: &CommitmentProofBytes,
-        _port_id: &PortId,
-        _channel_id: &ChannelId,
-        _seq: &Sequence,
+        _ctx: &dyn ChannelReader,
+        client_state: &Self::ClientState,
+        height: Height,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProofBytes,
+        root: &CommitmentRoot,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        seq: &Sequence,
     ) -> Result<(), Box<dyn std::error::Error>> {
-        todo!()
+        client_state.verify_height(height)?;
+
+        let path = Path::Receipts {
+            port_id: port_id.clone(),
+            channel_id: channel_id.clone(),
+            sequence: *seq,
+        };
+
+        verify_non_membership(prefix, proof, root, path)
+    }
+}
+
+/// Verifies that the commit of `header` was signed by more than two thirds of the voting power of
+/// its validator set, and by at least `trust_level` of the voting power of its trusted validator
+/// set, as the light client does when it skips to a header.
+fn verify_header_commit(header: &Header, trust_level: &TrustThreshold) -> Result<(), Error> {
+    let signed_header = &header.signed_header;
+    let invalid = || Kind::InvalidHeaderCommit(header.height());
+
+    if header.validator_set.hash() != signed_header.header.validators_hash {
+        return Err(invalid()
+            .context("the validator set does not match the header")
+            .into());
+    }
+
+    if signed_header.header.hash() != signed_header.commit.block_id.hash {
+        return Err(invalid().context("the commit is not for the header").into());
+    }
+
+    let predicates = ProdPredicates::default();
+    let calculator = ProdVotingPowerCalculator::default();
+
+    predicates
+        .has_sufficient_validators_overlap(
+            signed_header,
+            &header.trusted_validator_set,
+            trust_level,
+            &calculator,
+        )
+        .map_err(|e| invalid().context(e))?;
+
+    predicates
+        .has_sufficient_signers_overlap(signed_header, &header.validator_set, &calculator)
+        .map_err(|e| invalid().context(e))?;
+
+    Ok(())
+}
+
+/// Builds the Merkle path of the key `upgrade_key` in the upgrade sub-store, as defined by the
+/// client `upgrade_path`. The last element of the upgrade path is the key of the upgraded IBC state.
+fn upgrade_merkle_path(
+    upgrade_path: &[String],
+    upgrade_key: ClientUpgradePath,
+) -> Result<MerklePath, Kind> {
+    let (upgraded_ibc_state, store_keys) =
+        upgrade_path.split_last().ok_or(Kind::EmptyUpgradePath)?;
+
+    let mut key_path = store_keys.to_vec();
+    key_path.push(upgrade_key.key(upgraded_ibc_state));
+
+    Ok(MerklePath { key_path })
+}
+
+/// Verifies that `value` is stored under `path` (prefixed with `prefix`) in the Cosmos-SDK store
+/// committed to by `root`.
+fn verify_membership(
+    prefix: &CommitmentPrefix,
+    proof: &CommitmentProofBytes,
+    root: &CommitmentRoot,
+    path: Path,
+    value: Vec<u8>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let merkle_path = apply_prefix(prefix, vec![path.to_string()])?;
+    let merkle_proof = MerkleProof::try_from(proof.clone())?;
+
+    merkle_proof.verify_membership(&ProofSpecs::cosmos(), root, merkle_path, value, 0)?;
+
+    Ok(())
+}
+
+/// Verifies that nothing is stored under `path` (prefixed with `prefix`) in the Cosmos-SDK store
+/// committed to by `root`.
+fn verify_non_membership(
+    prefix: &CommitmentPrefix,
+    proof: &CommitmentProofBytes,
+    root: &CommitmentRoot,
+    path: Path,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let merkle_path = apply_prefix(prefix, vec![path.to_string()])?;
+    let merkle_proof = MerkleProof::try_from(proof.clone())?;
+
+    merkle_proof.verify_non_membership(&ProofSpecs::cosmos(), root, merkle_path)?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use chrono::{DateTime, Utc};
+    use tendermint::Hash;
+    use tendermint_testgen::{Generator, LightBlock as TestgenLightBlock};
+
+    use crate::ics02_client::client_def::ClientDef;
+    use crate::ics02_client::client_state::AnyClientState;
+    use crate::ics02_client::client_type::ClientType;
+    use crate::ics02_client::context::ClientReader;
+    use crate::ics07_tendermint::error::{Error, Kind};
+    use crate::ics07_tendermint::header::Header;
+    use crate::ics07_tendermint::misbehaviour::Misbehaviour;
+    use crate::ics24_host::identifier::{ChainId, ClientId};
+    use crate::mock::context::MockContext;
+    use crate::mock::host::HostType;
+    use crate::timestamp::Timestamp;
+    use crate::Height;
+
+    use super::{verify_header_commit, TendermintClient};
+
+    /// A header at height 10 of the testgen chain, trusting the validators of height 5.
+    fn header() -> Header {
+        let block = TestgenLightBlock::new_default(10).generate().unwrap();
+        Header {
+            signed_header: block.signed_header,
+            validator_set: block.validators.clone(),
+            trusted_height: Height::new(0, 5),
+            trusted_validator_set: block.validators,
+        }
+    }
+
+    /// A misbehaviour made of `header()` and a conflicting header, whose commit claims the
+    /// signatures of the original block.
+    fn forged_misbehaviour(client_id: &ClientId) -> Misbehaviour {
+        let header1 = header();
+
+        let mut header2 = header1.clone();
+        header2.signed_header.header.consensus_hash = Hash::Sha256([0xab; 32]);
+        header2.signed_header.commit.block_id.hash = header2.signed_header.header.hash();
+
+        Misbehaviour {
+            client_id: client_id.clone(),
+            header1,
+            header2,
+        }
+    }
+
+    /// Submits the misbehaviour to a Tendermint client trusting the height 5 of the testgen
+    /// chain, on a host whose time is `host_time_offset` seconds after the time of `header()`.
+    fn check_misbehaviour(misbehaviour: Misbehaviour, host_time_offset: i64) -> Error {
+        let header_time: DateTime<Utc> = misbehaviour.header1.time().into();
+        let host_time = header_time + chrono::Duration::seconds(host_time_offset);
+        let chain_id = ChainId::from(misbehaviour.header1.signed_header.header.chain_id.clone());
+
+        let client_id = misbehaviour.client_id.clone();
+        let ctx = MockContext::new(chain_id, HostType::Mock, 5, Height::new(0, 20))
+            .with_client_parametrized(
+                &client_id,
+                Height::new(0, 5),
+                Some(ClientType::Tendermint),
+                None,
+            )
+            .with_timestamp(Timestamp::from_datetime(host_time));
+        let client_state = match ctx.client_state(&client_id) {
+            Some(AnyClientState::Tendermint(client_state)) => client_state,
+            _ => panic!("missing Tendermint client state"),
+        };
+
+        // The commit of the original header is valid.
+        assert!(verify_header_commit(&misbehaviour.header1, &client_state.trust_level).is_ok());
+
+        let err = TendermintClient
+            .check_misbehaviour_and_update_state(&ctx, client_id, client_state, misbehaviour)
+            .unwrap_err();
+
+        match err.downcast::<Error>() {
+            Ok(err) => *err,
+            Err(err) => match err.downcast::<Kind>() {
+                Ok(kind) => (*kind).into(),
+                Err(err) => panic!("unexpected error: {}", err),
+            },
+        }
+    }
+
+    #[test]
+    fn misbehaviour_with_forged_commit_is_rejected() {
+        let client_id = ClientId::from_str("07-tendermint-0").unwrap();
+
+        let err = check_misbehaviour(forged_misbehaviour(&client_id), 0);
+        assert!(matches!(err.kind(), Kind::InvalidHeaderCommit(h) if *h == Height::new(0, 10)));
+    }
+
+    #[test]
+    fn misbehaviour_with_expired_trusted_consensus_state_is_rejected() {
+        let client_id = ClientId::from_str("07-tendermint-0").unwrap();
+
+        // The trusting period of the dummy client state is 64000 seconds.
+        let err = check_misbehaviour(forged_misbehaviour(&client_id), 64001);
+        assert!(
+            matches!(err.kind(), Kind::TrustedConsensusStateExpired(h) if *h == Height::new(0, 5))
+        );
+    }
+
+    #[test]
+    fn misbehaviour_with_header_from_future_is_rejected() {
+        let client_id = ClientId::from_str("07-tendermint-0").unwrap();
+
+        // The maximum clock drift of the dummy client state is 3 seconds.
+        let err = check_misbehaviour(forged_misbehaviour(&client_id), -4);
+        assert!(matches!(err.kind(), Kind::HeaderFromFuture(h) if *h == Height::new(0, 10)));
     }
 }
@@ -2,6 +2,7 @@ use anomaly::{BoxError, Context};
 use thiserror::Error;
 
 use crate::ics24_host::error::ValidationKind;
+use crate::Height;
 
 pub type Error = anomaly::Error<Kind>;
 
@@ -39,6 +40,54 @@ pub enum Kind {
 
     #[error("invalid raw misbehaviour")]
     InvalidRawMisbehaviour,
+
+    #[error("the client's latest height {0} is lower than the verification height {1}")]
+    InsufficientHeight(Height, Height),
+
+    #[error("the client is frozen at height {0}, cannot verify at height {1}")]
+    ClientFrozen(Height, Height),
+
+    #[error("misbehaviour header for chain {0} does not match the client chain {1}")]
+    MisbehaviourChainIdMismatch(String, String),
+
+    #[error("misbehaviour header1 height {0} is lower than header2 height {1}")]
+    InvalidMisbehaviourHeaderHeights(Height, Height),
+
+    #[error("misbehaviour headers do not conflict with each other")]
+    MisbehaviourHeadersNotConflicting,
+
+    #[error("no trusted consensus state found at height {0}")]
+    MissingTrustedConsensusState(Height),
+
+    #[error("the trusting period of the consensus state at height {0} has expired")]
+    TrustedConsensusStateExpired(Height),
+
+    #[error("the timestamp of the header at height {0} exceeds the host time plus the maximum clock drift")]
+    HeaderFromFuture(Height),
+
+    #[error("the timestamp of the host chain is not set")]
+    MissingHostTimestamp,
+
+    #[error("the trusted validator set of the header at height {0} does not match the trusted consensus state")]
+    TrustedValidatorSetMismatch(Height),
+
+    #[error("the commit of the header at height {0} is not valid")]
+    InvalidHeaderCommit(Height),
+
+    #[error("missing counterparty connection identifier")]
+    MissingConnectionId,
+
+    #[error("the upgraded client height {0} must be greater than the current client height {1}")]
+    LowUpgradeHeight(Height, Height),
+
+    #[error("the client does not define an upgrade path")]
+    EmptyUpgradePath,
+
+    #[error("invalid proof for the upgraded client state")]
+    InvalidUpgradeClientProof,
+
+    #[error("invalid proof for the upgraded consensus state")]
+    InvalidUpgradeConsensusStateProof,
 }
 
 impl Kind {
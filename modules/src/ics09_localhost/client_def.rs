@@ -0,0 +1,335 @@
+use ibc_proto::ibc::core::commitment::v1::MerkleProof;
+
+use crate::ics02_client::client_consensus::AnyConsensusState;
+use crate::ics02_client::client_def::ClientDef;
+use crate::ics02_client::client_state::AnyClientState;
+use crate::ics02_client::context::ClientReader;
+use crate::ics02_client::header::AnyHeader;
+use crate::ics02_client::misbehaviour::AnyMisbehaviour;
+use crate::ics03_connection::connection::ConnectionEnd;
+use crate::ics03_connection::context::ConnectionReader;
+use crate::ics04_channel::channel::ChannelEnd;
+use crate::ics04_channel::context::ChannelReader;
+use crate::ics04_channel::packet::{Packet, Sequence};
+use crate::ics09_localhost::client_state::ClientState;
+use crate::ics09_localhost::error::Kind;
+use crate::ics23_commitment::commitment::{CommitmentPrefix, CommitmentProofBytes, CommitmentRoot};
+use crate::ics24_host::identifier::{ChannelId, ClientId, ConnectionId, PortId};
+use crate::Height;
+
+/// The loopback client. Instead of checking proofs against a commitment root, this client verifies
+/// the state of the host chain by reading it directly through the context of the handlers. Its
+/// consensus states are the consensus states of the host chain itself.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LocalhostClient;
+
+impl ClientDef for LocalhostClient {
+    type Header = AnyHeader;
+    type ClientState = ClientState;
+    type ConsensusState = AnyConsensusState;
+    type Misbehaviour = AnyMisbehaviour;
+
+    /// Updates the client to the current height of the host chain. The content of the `header` is
+    /// ignored, as the host chain is the source of truth for its own state.
+    fn check_header_and_update_state(
+        &self,
+        ctx: &dyn ClientReader,
+        client_state: Self::ClientState,
+        _header: Self::Header,
+    ) -> Result<(Self::ClientState, Self::ConsensusState), Box<dyn std::error::Error>> {
+        let host_height = ctx.host_height();
+        if host_height <= client_state.latest_height() {
+            return Err(Kind::LowHostHeight(host_height, client_state.latest_height()).into());
+        }
+
+        let consensus_state = ctx
+            .host_consensus_state(host_height)
+            .ok_or(Kind::MissingHostConsensusState(host_height))?;
+
+        Ok((client_state.with_height(host_height), consensus_state))
+    }
+
+    fn check_misbehaviour_and_update_state(
+        &self,
+        _ctx: &dyn ClientReader,
+        _client_id: ClientId,
+        _client_state: Self::ClientState,
+        _misbehaviour: Self::Misbehaviour,
+    ) -> Result<Self::ClientState, Box<dyn std::error::Error>> {
+        Err(Kind::MisbehaviourNotSupported.into())
+    }
+
+    fn verify_upgrade_and_update_state(
+        &self,
+        _client_state: &Self::ClientState,
+        _consensus_state: &Self::ConsensusState,
+        _upgraded_client_state: Self::ClientState,
+        _upgraded_consensus_state: Self::ConsensusState,
+        _proof_upgrade_client: &MerkleProof,
+        _proof_upgrade_consensus_state: &MerkleProof,
+    ) -> Result<(Self::ClientState, Self::ConsensusState), Box<dyn std::error::Error>> {
+        Err(Kind::UpgradeNotSupported.into())
+    }
+
+    fn verify_client_consensus_state(
+        &self,
+        ctx: &dyn ConnectionReader,
+        client_state: &Self::ClientState,
+        height: Height,
+        _prefix: &CommitmentPrefix,
+        _proof: &CommitmentProofBytes,
+        _root: &CommitmentRoot,
+        client_id: &ClientId,
+        consensus_height: Height,
+        expected_consensus_state: &AnyConsensusState,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        verify_height(client_state, height)?;
+
+        match ctx.client_consensus_state(client_id, consensus_height) {
+            Some(consensus_state) if &consensus_state == expected_consensus_state => Ok(()),
+            _ => Err(Kind::ConsensusStateMismatch(client_id.clone(), consensus_height).into()),
+        }
+    }
+
+    fn verify_connection_state(
+        &self,
+        ctx: &dyn ConnectionReader,
+        client_state: &Self::ClientState,
+        height: Height,
+        _prefix: &CommitmentPrefix,
+        _proof: &CommitmentProofBytes,
+        _root: &CommitmentRoot,
+        connection_id: Option<&ConnectionId>,
+        expected_connection_end: &ConnectionEnd,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        verify_height(client_state, height)?;
+
+        let connection_id = connection_id.ok_or(Kind::MissingConnectionId)?;
+        match ctx.connection_end(connection_id) {
+            Some(connection_end) if &connection_end == expected_connection_end => Ok(()),
+            _ => Err(Kind::ConnectionEndMismatch(connection_id.clone()).into()),
+        }
+    }
+
+    fn verify_channel_state(
+        &self,
+        ctx: &dyn ChannelReader,
+        client_state: &Self::ClientState,
+        height: Height,
+        _prefix: &CommitmentPrefix,
+        _proof: &CommitmentProofBytes,
+        _root: &CommitmentRoot,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        expected_channel_end: &ChannelEnd,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        verify_height(client_state, height)?;
+
+        match ctx.channel_end(&(port_id.clone(), channel_id.clone())) {
+            Some(channel_end) if &channel_end == expected_channel_end => Ok(()),
+            _ => Err(Kind::ChannelEndMismatch(port_id.clone(), channel_id.clone()).into()),
+        }
+    }
+
+    fn verify_client_full_state(
+        &self,
+        ctx: &dyn ConnectionReader,
+        client_state: &Self::ClientState,
+        height: Height,
+        _root: &CommitmentRoot,
+        _prefix: &CommitmentPrefix,
+        client_id: &ClientId,
+        _proof: &CommitmentProofBytes,
+        expected_client_state: &AnyClientState,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        verify_height(client_state, height)?;
+
+        match ctx.client_state(client_id) {
+            Some(stored) if &stored == expected_client_state => Ok(()),
+            _ => Err(Kind::ClientStateMismatch(client_id.clone()).into()),
+        }
+    }
+
+    fn verify_packet_data(
+        &self,
+        ctx: &dyn ChannelReader,
+        client_state: &Self::ClientState,
+        height: Height,
+        _prefix: &CommitmentPrefix,
+        _proof: &CommitmentProofBytes,
+        _root: &CommitmentRoot,
+        packet: &Packet,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        verify_height(client_state, height)?;
+
+        // The host stores the hash of the packet fields, see `ChannelKeeper`.
+        let expected = ctx.hash(format!(
+            "{:?},{:?},{:?}",
+            packet.timeout_timestamp, packet.timeout_height, packet.data
+        ));
+        let key = (
+            packet.source_port.clone(),
+            packet.source_channel.clone(),
+            packet.sequence,
+        );
+        match ctx.get_packet_commitment(&key) {
+            Some(stored) if stored == expected => Ok(()),
+            _ => Err(Kind::PacketCommitmentMismatch(packet.sequence).into()),
+        }
+    }
+
+    fn verify_packet_acknowledgement(
+        &self,
+        ctx: &dyn ChannelReader,
+        client_state: &Self::ClientState,
+        height: Height,
+        _prefix: &CommitmentPrefix,
+        _proof: &CommitmentProofBytes,
+        _root: &CommitmentRoot,
+        packet: &Packet,
+        ack: Vec<u8>,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        verify_height(client_state, height)?;
+
+        // The host stores the hash of the acknowledgement, see `ChannelKeeper`.
+        let expected = ctx.hash(format!("{:?}", ack));
+        let key = (
+            packet.source_port.clone(),
+            packet.source_channel.clone(),
+            packet.sequence,
+        );
+        match ctx.get_packet_acknowledgement(&key) {
+            Some(stored) if stored == expected => Ok(()),
+            _ => Err(Kind::PacketAcknowledgementMismatch(packet.sequence).into()),
+        }
+    }
+
+    fn verify_next_sequence_recv(
+        &self,
+        ctx: &dyn ChannelReader,
+        client_state: &Self::ClientState,
+        height: Height,
+        _prefix: &CommitmentPrefix,
+        _proof: &CommitmentProofBytes,
+        _root: &CommitmentRoot,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        seq: &Sequence,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        verify_height(client_state, height)?;
+
+        match ctx.get_next_sequence_recv(&(port_id.clone(), channel_id.clone())) {
+            Some(next_seq) if next_seq == *seq => Ok(()),
+            _ => Err(Kind::NextSequenceRecvMismatch(*seq).into()),
+        }
+    }
+
+    fn verify_packet_receipt_absence(
+        &self,
+        ctx: &dyn ChannelReader,
+        client_state: &Self::ClientState,
+        height: Height,
+        _prefix: &CommitmentPrefix,
+        _proof: &CommitmentProofBytes,
+        _root: &CommitmentRoot,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        seq: &Sequence,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        verify_height(client_state, height)?;
+
+        match ctx.get_packet_receipt(&(port_id.clone(), channel_id.clone(), *seq)) {
+            None => Ok(()),
+            Some(_) => Err(Kind::PacketReceiptExists(*seq).into()),
+        }
+    }
+}
+
+/// The client can only attest to the state of the host up to the height it was last updated to.
+fn verify_height(client_state: &ClientState, height: Height) -> Result<(), Kind> {
+    if height > client_state.latest_height() {
+        return Err(Kind::InvalidVerificationHeight(
+            height,
+            client_state.latest_height(),
+        ));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ics02_client::client_def::ClientDef;
+    use crate::ics02_client::client_type::ClientType;
+    use crate::ics02_client::context::ClientReader;
+    use crate::ics02_client::header::AnyHeader;
+    use crate::ics03_connection::connection::ConnectionEnd;
+    use crate::ics09_localhost::client_state::ClientState;
+    use crate::ics23_commitment::commitment::{CommitmentPrefix, CommitmentRoot};
+    use crate::ics24_host::identifier::{ChainId, ClientId, ConnectionId};
+    use crate::mock::context::MockContext;
+    use crate::mock::header::MockHeader;
+    use crate::mock::host::HostType;
+    use crate::Height;
+
+    use super::LocalhostClient;
+
+    #[test]
+    fn update_tracks_host_height() {
+        let chain_id = ChainId::new("mockgaia".to_string(), 1);
+        let ctx = MockContext::new(chain_id.clone(), HostType::Mock, 5, Height::new(1, 5));
+        let client_state = ClientState::new(chain_id, Height::new(1, 2));
+
+        // The header is ignored, the client is updated to the latest height of the host.
+        let header = AnyHeader::Mock(MockHeader::new(Height::new(1, 3)));
+        let (new_client_state, new_consensus_state) = LocalhostClient
+            .check_header_and_update_state(&ctx, client_state.clone(), header.clone())
+            .unwrap();
+
+        assert_eq!(new_client_state.latest_height(), Height::new(1, 5));
+        assert_eq!(
+            Some(new_consensus_state),
+            ctx.host_consensus_state(Height::new(1, 5))
+        );
+
+        // The client cannot be updated if the host did not advance.
+        let result = LocalhostClient.check_header_and_update_state(&ctx, new_client_state, header);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn verify_connection_state_reads_host() {
+        let client_id = ClientId::new(ClientType::Localhost, 0).unwrap();
+        let connection_id = ConnectionId::new(0);
+        let connection_end = ConnectionEnd::default();
+
+        let ctx = MockContext::default()
+            .with_client_parametrized(
+                &client_id,
+                Height::new(0, 5),
+                Some(ClientType::Localhost),
+                None,
+            )
+            .with_connection(connection_id.clone(), connection_end.clone());
+        let client_state = ClientState::new(ChainId::default(), Height::new(0, 5));
+
+        let verify = |connection_id: &ConnectionId, height: Height| {
+            LocalhostClient.verify_connection_state(
+                &ctx,
+                &client_state,
+                height,
+                &CommitmentPrefix::from(vec![]),
+                &vec![].into(),
+                &CommitmentRoot::from_bytes(&[]),
+                Some(connection_id),
+                &connection_end,
+            )
+        };
+
+        assert!(verify(&connection_id, Height::new(0, 5)).is_ok());
+        // The connection does not exist on the host.
+        assert!(verify(&ConnectionId::new(1), Height::new(0, 5)).is_err());
+        // The client has not been updated to the verification height.
+        assert!(verify(&connection_id, Height::new(0, 6)).is_err());
+    }
+}
@@ -0,0 +1,102 @@
+use std::convert::{TryFrom, TryInto};
+use std::str::FromStr;
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+use tendermint_proto::Protobuf;
+
+use ibc_proto::ibc::lightclients::localhost::v1::ClientState as RawClientState;
+
+use crate::ics02_client::client_state::AnyClientState;
+use crate::ics02_client::client_type::ClientType;
+use crate::ics09_localhost::error::{Error, Kind};
+use crate::ics24_host::identifier::ChainId;
+use crate::Height;
+
+/// The state of a loopback client, i.e., a client of the host chain stored on the host chain
+/// itself. The client only tracks the latest height of the host, whose state it reads directly.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ClientState {
+    pub chain_id: ChainId,
+    pub height: Height,
+}
+
+impl ClientState {
+    pub fn new(chain_id: ChainId, height: Height) -> ClientState {
+        Self { chain_id, height }
+    }
+
+    pub fn latest_height(&self) -> Height {
+        self.height
+    }
+
+    /// Returns this client state, updated to the host height `height`.
+    pub fn with_height(self, height: Height) -> Self {
+        ClientState { height, ..self }
+    }
+
+    /// Localhost clients are updated with the host chain, hence need no refresh.
+    pub fn refresh_time(&self) -> Option<Duration> {
+        None
+    }
+
+    pub fn expired(&self, _elapsed: Duration) -> bool {
+        false
+    }
+}
+
+impl crate::ics02_client::client_state::ClientState for ClientState {
+    fn chain_id(&self) -> ChainId {
+        self.chain_id.clone()
+    }
+
+    fn client_type(&self) -> ClientType {
+        ClientType::Localhost
+    }
+
+    fn latest_height(&self) -> Height {
+        self.height
+    }
+
+    fn is_frozen(&self) -> bool {
+        // A localhost client cannot misbehave, hence is never frozen.
+        false
+    }
+
+    fn wrap_any(self) -> AnyClientState {
+        AnyClientState::Localhost(self)
+    }
+}
+
+impl From<ClientState> for AnyClientState {
+    fn from(cs: ClientState) -> Self {
+        AnyClientState::Localhost(cs)
+    }
+}
+
+impl Protobuf<RawClientState> for ClientState {}
+
+impl TryFrom<RawClientState> for ClientState {
+    type Error = Error;
+
+    fn try_from(raw: RawClientState) -> Result<Self, Self::Error> {
+        Ok(Self {
+            chain_id: ChainId::from_str(raw.chain_id.as_str())
+                .map_err(|_| Kind::InvalidRawClientState.context("invalid chain identifier"))?,
+            height: raw
+                .height
+                .ok_or_else(|| Kind::InvalidRawClientState.context("missing height"))?
+                .try_into()
+                .map_err(|e| Kind::InvalidRawClientState.context(e))?,
+        })
+    }
+}
+
+impl From<ClientState> for RawClientState {
+    fn from(value: ClientState) -> Self {
+        RawClientState {
+            chain_id: value.chain_id.to_string(),
+            height: Some(value.height.into()),
+        }
+    }
+}
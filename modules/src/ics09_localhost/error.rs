@@ -0,0 +1,62 @@
+use anomaly::{BoxError, Context};
+use thiserror::Error;
+
+use crate::ics04_channel::packet::Sequence;
+use crate::ics24_host::identifier::{ChannelId, ClientId, ConnectionId, PortId};
+use crate::Height;
+
+pub type Error = anomaly::Error<Kind>;
+
+#[derive(Clone, Debug, Error)]
+pub enum Kind {
+    #[error("invalid raw client state")]
+    InvalidRawClientState,
+
+    #[error("the host height {0} is not higher than the client latest height {1}")]
+    LowHostHeight(Height, Height),
+
+    #[error("the host has no consensus state for height {0}")]
+    MissingHostConsensusState(Height),
+
+    #[error("the verification height {0} is higher than the client latest height {1}")]
+    InvalidVerificationHeight(Height, Height),
+
+    #[error("consensus state of client {0} at height {1} does not match the expected one")]
+    ConsensusStateMismatch(ClientId, Height),
+
+    #[error("missing counterparty connection identifier")]
+    MissingConnectionId,
+
+    #[error("connection end {0} does not match the expected one")]
+    ConnectionEndMismatch(ConnectionId),
+
+    #[error("client state of client {0} does not match the expected one")]
+    ClientStateMismatch(ClientId),
+
+    #[error("channel end ({0}, {1}) does not match the expected one")]
+    ChannelEndMismatch(PortId, ChannelId),
+
+    #[error("packet commitment for sequence {0} does not match the expected one")]
+    PacketCommitmentMismatch(Sequence),
+
+    #[error("packet acknowledgement for sequence {0} does not match the expected one")]
+    PacketAcknowledgementMismatch(Sequence),
+
+    #[error("next sequence receive {0} does not match the expected one")]
+    NextSequenceRecvMismatch(Sequence),
+
+    #[error("a receipt exists for packet with sequence {0}")]
+    PacketReceiptExists(Sequence),
+
+    #[error("localhost clients cannot misbehave")]
+    MisbehaviourNotSupported,
+
+    #[error("localhost clients cannot be upgraded")]
+    UpgradeNotSupported,
+}
+
+impl Kind {
+    pub fn context(self, source: impl Into<BoxError>) -> Context<Self> {
+        Context::new(self, Some(source.into()))
+    }
+}
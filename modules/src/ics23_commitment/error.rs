@@ -8,4 +8,28 @@ pub enum Error {
 
     #[error("failed to decode commitment proof")]
     CommitmentProofDecodingFailed(DecodeError),
+
+    #[error("empty commitment prefix")]
+    EmptyCommitmentPrefix,
+
+    #[error("empty merkle proof")]
+    EmptyMerkleProof,
+
+    #[error("empty merkle root")]
+    EmptyMerkleRoot,
+
+    #[error("empty verified value")]
+    EmptyVerifiedValue,
+
+    #[error("mismatch between the number of proofs with that of specs")]
+    NumberOfSpecsMismatch,
+
+    #[error("mismatch between the number of proofs with that of keys")]
+    NumberOfKeysMismatch,
+
+    #[error("invalid merkle proof")]
+    InvalidMerkleProof,
+
+    #[error("proof verification failed")]
+    VerificationFailure,
 }
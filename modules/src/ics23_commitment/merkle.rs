@@ -1,17 +1,23 @@
-use tendermint::merkle::proof::Proof;
+use std::convert::TryFrom;
+
+use ics23::commitment_proof::Proof;
+use ics23::{
+    calculate_existence_root, verify_membership, verify_non_membership, CommitmentProof,
+    NonExistenceProof,
+};
+use tendermint::merkle::proof::Proof as TendermintProof;
 
 use ibc_proto::ibc::core::commitment::v1::MerklePath;
 use ibc_proto::ibc::core::commitment::v1::MerkleProof as RawMerkleProof;
+use ibc_proto::ics23::CommitmentProof as RawCommitmentProof;
 
-use crate::ics23_commitment::commitment::{CommitmentPrefix, CommitmentProofBytes};
+use crate::ics23_commitment::commitment::{CommitmentPrefix, CommitmentProofBytes, CommitmentRoot};
 use crate::ics23_commitment::error::Error;
+use crate::ics23_commitment::specs::ProofSpecs;
 
-pub fn apply_prefix(
-    prefix: &CommitmentPrefix,
-    mut path: Vec<String>,
-) -> Result<MerklePath, Box<dyn std::error::Error>> {
+pub fn apply_prefix(prefix: &CommitmentPrefix, mut path: Vec<String>) -> Result<MerklePath, Error> {
     if prefix.is_empty() {
-        return Err("empty prefix".into());
+        return Err(Error::EmptyCommitmentPrefix);
     }
 
     let mut result: Vec<String> = vec![format!("{:?}", prefix)];
@@ -20,9 +26,132 @@ pub fn apply_prefix(
     Ok(MerklePath { key_path: result })
 }
 
+/// Domain type for a Merkle proof, i.e., a chain of ICS-23 commitment proofs, one for each
+/// sub-tree that must be traversed from the value up to the root (lowest sub-tree first).
 #[derive(Clone, Debug, PartialEq)]
 pub struct MerkleProof {
-    pub proof: Vec<CommitmentProofBytes>,
+    pub proofs: Vec<CommitmentProof>,
+}
+
+impl MerkleProof {
+    /// Verifies that `value` is stored under the key path `keys` in the tree committed to by
+    /// `root`. The proofs are checked starting with the one at `start_index`; the keys in `keys`
+    /// are ordered from root to leaf, as produced by `apply_prefix`.
+    pub fn verify_membership(
+        &self,
+        specs: &ProofSpecs,
+        root: &CommitmentRoot,
+        keys: MerklePath,
+        value: Vec<u8>,
+        start_index: usize,
+    ) -> Result<(), Error> {
+        // Validate the arguments.
+        if self.proofs.is_empty() {
+            return Err(Error::EmptyMerkleProof);
+        }
+        if root.as_bytes().is_empty() {
+            return Err(Error::EmptyMerkleRoot);
+        }
+        let num = self.proofs.len();
+        if specs.len() != num {
+            return Err(Error::NumberOfSpecsMismatch);
+        }
+        if keys.key_path.len() != num {
+            return Err(Error::NumberOfKeysMismatch);
+        }
+        if value.is_empty() {
+            return Err(Error::EmptyVerifiedValue);
+        }
+
+        let mut subroot = value.clone();
+        let mut value = value;
+
+        // The keys are ordered from root to leaf, while the proofs go from leaf to root.
+        for ((proof, spec), key) in self
+            .proofs
+            .iter()
+            .zip(specs.iter())
+            .zip(keys.key_path.iter().rev())
+            .skip(start_index)
+        {
+            match &proof.proof {
+                Some(Proof::Exist(existence_proof)) => {
+                    subroot = calculate_existence_root(existence_proof)
+                        .map_err(|_| Error::InvalidMerkleProof)?;
+
+                    if !verify_membership(proof, spec, &subroot, key.as_bytes(), &value) {
+                        return Err(Error::VerificationFailure);
+                    }
+
+                    value = subroot.clone();
+                }
+                _ => return Err(Error::InvalidMerkleProof),
+            }
+        }
+
+        if root.as_bytes() != subroot.as_slice() {
+            return Err(Error::VerificationFailure);
+        }
+
+        Ok(())
+    }
+
+    /// Verifies that nothing is stored under the key path `keys` in the tree committed to by
+    /// `root`. The first proof must be a non-existence proof for the lowest sub-tree, while the
+    /// remaining ones prove the existence of that sub-tree's root up to `root`.
+    pub fn verify_non_membership(
+        &self,
+        specs: &ProofSpecs,
+        root: &CommitmentRoot,
+        keys: MerklePath,
+    ) -> Result<(), Error> {
+        // Validate the arguments.
+        if self.proofs.is_empty() {
+            return Err(Error::EmptyMerkleProof);
+        }
+        if root.as_bytes().is_empty() {
+            return Err(Error::EmptyMerkleRoot);
+        }
+        let num = self.proofs.len();
+        if specs.len() != num {
+            return Err(Error::NumberOfSpecsMismatch);
+        }
+        if keys.key_path.len() != num {
+            return Err(Error::NumberOfKeysMismatch);
+        }
+
+        // Verify the absence of the key in the lowest sub-tree.
+        let proof = &self.proofs[0];
+        let spec = specs.iter().next().ok_or(Error::InvalidMerkleProof)?;
+        // The keys are ordered from root to leaf.
+        let key = &keys.key_path[num - 1];
+
+        match &proof.proof {
+            Some(Proof::Nonexist(non_existence_proof)) => {
+                let subroot = calculate_non_existence_root(non_existence_proof)?;
+
+                if !verify_non_membership(proof, spec, &subroot, key.as_bytes()) {
+                    return Err(Error::VerificationFailure);
+                }
+
+                // Verify the membership proofs starting from index 1, with value = subroot.
+                self.verify_membership(specs, root, keys, subroot, 1)
+            }
+            _ => Err(Error::InvalidMerkleProof),
+        }
+    }
+}
+
+/// Computes the root of the tree in which a `NonExistenceProof` was created, using whichever
+/// of its neighbouring existence proofs is present.
+fn calculate_non_existence_root(proof: &NonExistenceProof) -> Result<Vec<u8>, Error> {
+    let neighbour = proof
+        .left
+        .as_ref()
+        .or_else(|| proof.right.as_ref())
+        .ok_or(Error::InvalidMerkleProof)?;
+
+    calculate_existence_root(neighbour).map_err(|_| Error::InvalidMerkleProof)
 }
 
 // Merkle Proof serialization notes:
@@ -39,18 +168,15 @@ pub struct MerkleProof {
 //  - Vec<u8>: RawMerkleProof is not explicitly used but, serialized as Vec<u8>, it is
 //       included in all handshake messages that require proofs (i.e. all except the two `OpenInit`),
 //       and also in all queries that require proofs
-//  - MerkleProof: Domain type for RawMerkleProof, currently not used and identical to RawMerkleProof.
-//       This will change with verification implementation.
+//  - MerkleProof: Domain type for RawMerkleProof, holding the `ics23` commitment proofs that the
+//       verification functions operate on.
 //  - CommitmentProof: Defined in ibc-rs as Vec<u8> and currently used in all its messages
 //
 // Here are a couple of flows that illustrate the different conversions:
 // IBC Messages and Handlers: sink happens in the handle verification
-//    Vec<u8> -> CommitmentProof -> RawMerkleProof -> MerkleProof
+//    Vec<u8> -> CommitmentProof -> RawMerkleProof -> MerkleProof -> verify()
 //
 // Relayer: from the proof in the  query response to the proof being included in a message
-//    TmProof -> RawProofOps => RawMerkleProof -> MerkleProof -> verify()
-//      -> MerkleProof -> RawMerkleProof -> CommitmentProof -> Vec<u8>
-// Note: current implementation for ^ is simplified since verification is not yet implemented:
 //    TmProof -> RawProofOps => RawMerkleProof -> CommitmentProof -> Vec<u8>
 //
 // Implementations of (de)serializers and conversions:
@@ -64,24 +190,60 @@ pub struct MerkleProof {
 //  - cosmos.rs:abci_query() converts from query proof to Merkle proof:
 //      RawProofOps => RawMerkleProof
 //
-// impl TryFrom<RawMerkleProof> for MerkleProof {
-//     type Error = Error;
-//     fn try_from(value: RawMerkleProof) -> Result<Self, Self::Error> {
-//         Ok(MerkleProof { proof: value.proofs.into_iter().map(|v| v.into()).collect() })
-//     }
-// }
-//
-// impl From<MerkleProof> for RawMerkleProof {
-//     fn from(value: MerkleProof) -> Self {
-//         RawMerkleProof { proof: value.proof }
-//     }
-// }
 
-pub fn convert_tm_to_ics_merkle_proof(tm_proof: &Proof) -> Result<RawMerkleProof, Error> {
+impl TryFrom<RawMerkleProof> for MerkleProof {
+    type Error = Error;
+
+    fn try_from(raw: RawMerkleProof) -> Result<Self, Self::Error> {
+        // The `ics23` crate and `ibc_proto` define distinct (but identical) types for commitment
+        // proofs. Convert by encoding, then decoding into the destination type.
+        let proofs = raw
+            .proofs
+            .iter()
+            .map(|p| {
+                let mut encoded = Vec::new();
+                prost::Message::encode(p, &mut encoded).unwrap();
+                <CommitmentProof as prost::Message>::decode(&*encoded)
+                    .map_err(Error::CommitmentProofDecodingFailed)
+            })
+            .collect::<Result<Vec<CommitmentProof>, Error>>()?;
+
+        Ok(MerkleProof { proofs })
+    }
+}
+
+impl From<MerkleProof> for RawMerkleProof {
+    fn from(proof: MerkleProof) -> Self {
+        // Safety note: the source and target data structures are identical, hence the
+        // encode/decode conversion here should be infallible.
+        let proofs = proof
+            .proofs
+            .iter()
+            .map(|p| {
+                let mut encoded = Vec::new();
+                prost::Message::encode(p, &mut encoded).unwrap();
+                <RawCommitmentProof as prost::Message>::decode(&*encoded).unwrap()
+            })
+            .collect();
+
+        RawMerkleProof { proofs }
+    }
+}
+
+impl TryFrom<CommitmentProofBytes> for MerkleProof {
+    type Error = Error;
+
+    fn try_from(bytes: CommitmentProofBytes) -> Result<Self, Self::Error> {
+        let raw = RawMerkleProof::try_from(bytes)?;
+        MerkleProof::try_from(raw)
+    }
+}
+
+pub fn convert_tm_to_ics_merkle_proof(tm_proof: &TendermintProof) -> Result<RawMerkleProof, Error> {
     let mut proofs = vec![];
 
     for op in &tm_proof.ops {
-        let mut parsed = ibc_proto::ics23::CommitmentProof { proof: None };
+        let mut parsed = RawCommitmentProof { proof: None };
         prost::Message::merge(&mut parsed, op.data.as_slice())
             .map_err(Error::CommitmentProofDecodingFailed)?;
 
@@ -90,3 +252,149 @@ pub fn convert_tm_to_ics_merkle_proof(tm_proof: &Proof) -> Result<RawMerkleProof
 
     Ok(RawMerkleProof { proofs })
 }
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+
+    use ics23::commitment_proof::Proof;
+    use ics23::{calculate_existence_root, CommitmentProof, ExistenceProof, ProofSpec};
+
+    use ibc_proto::ibc::core::commitment::v1::MerkleProof as RawMerkleProof;
+
+    use crate::ics23_commitment::commitment::{CommitmentPrefix, CommitmentRoot};
+    use crate::ics23_commitment::error::Error;
+    use crate::ics23_commitment::merkle::{apply_prefix, MerkleProof};
+    use crate::ics23_commitment::specs::ProofSpecs;
+
+    /// Builds an existence proof for a tree holding a single leaf (`key`, `value`), with the leaf
+    /// encoded as mandated by `spec`.
+    fn single_leaf_proof(
+        spec: &ProofSpec,
+        leaf_prefix: Vec<u8>,
+        key: &[u8],
+        value: &[u8],
+    ) -> ExistenceProof {
+        let mut leaf = spec.leaf_spec.clone().unwrap();
+        leaf.prefix = leaf_prefix;
+
+        ExistenceProof {
+            key: key.to_vec(),
+            value: value.to_vec(),
+            leaf: Some(leaf),
+            path: vec![],
+        }
+    }
+
+    /// Returns a Cosmos-SDK style proof (an IAVL sub-store nested in the Tendermint multi-store)
+    /// for `value` stored at `path` under `prefix`, together with the root it commits to.
+    fn dummy_proof(prefix: &str, path: &str, value: &[u8]) -> (MerkleProof, CommitmentRoot) {
+        // An IAVL leaf prefix encodes the height (0), size (1) and version (1) as varints.
+        let iavl = single_leaf_proof(&ics23::iavl_spec(), vec![0, 2, 2], path.as_bytes(), value);
+        let subroot = calculate_existence_root(&iavl).unwrap();
+
+        let tm = single_leaf_proof(
+            &ics23::tendermint_spec(),
+            vec![0],
+            prefix.as_bytes(),
+            &subroot,
+        );
+        let root = calculate_existence_root(&tm).unwrap();
+
+        let proof = MerkleProof {
+            proofs: vec![
+                CommitmentProof {
+                    proof: Some(Proof::Exist(iavl)),
+                },
+                CommitmentProof {
+                    proof: Some(Proof::Exist(tm)),
+                },
+            ],
+        };
+
+        (proof, root.into())
+    }
+
+    #[test]
+    fn verify_membership() {
+        let prefix = CommitmentPrefix::from_bytes(b"ibc");
+        let path = "connections/connection-0".to_string();
+        let value = b"connection end".to_vec();
+        let specs = ProofSpecs::cosmos();
+
+        let (proof, root) = dummy_proof("ibc", &path, &value);
+        let keys = apply_prefix(&prefix, vec![path.clone()]).unwrap();
+
+        assert_eq!(
+            proof.verify_membership(&specs, &root, keys.clone(), value.clone(), 0),
+            Ok(())
+        );
+
+        // A different value must not verify.
+        assert_eq!(
+            proof.verify_membership(&specs, &root, keys.clone(), b"other".to_vec(), 0),
+            Err(Error::VerificationFailure)
+        );
+
+        // Neither must a different root.
+        let other_root = CommitmentRoot::from_bytes(&[1; 32]);
+        assert_eq!(
+            proof.verify_membership(&specs, &other_root, keys.clone(), value.clone(), 0),
+            Err(Error::VerificationFailure)
+        );
+
+        // Nor a key path that does not match the number of proofs.
+        let long_keys = apply_prefix(&prefix, vec![path, "extra".to_string()]).unwrap();
+        assert_eq!(
+            proof.verify_membership(&specs, &root, long_keys, value, 0),
+            Err(Error::NumberOfKeysMismatch)
+        );
+
+        // An existence proof cannot prove the absence of a key.
+        assert_eq!(
+            proof.verify_non_membership(&specs, &root, keys),
+            Err(Error::InvalidMerkleProof)
+        );
+    }
+
+    #[test]
+    fn verify_empty_arguments() {
+        let prefix = CommitmentPrefix::from_bytes(b"ibc");
+        let path = "connections/connection-0".to_string();
+        let value = b"connection end".to_vec();
+        let specs = ProofSpecs::cosmos();
+
+        let (proof, root) = dummy_proof("ibc", &path, &value);
+        let keys = apply_prefix(&prefix, vec![path.clone()]).unwrap();
+
+        let empty_proof = MerkleProof { proofs: vec![] };
+        assert_eq!(
+            empty_proof.verify_membership(&specs, &root, keys.clone(), value.clone(), 0),
+            Err(Error::EmptyMerkleProof)
+        );
+
+        let empty_root = CommitmentRoot::from_bytes(&[]);
+        assert_eq!(
+            proof.verify_membership(&specs, &empty_root, keys.clone(), value, 0),
+            Err(Error::EmptyMerkleRoot)
+        );
+
+        assert_eq!(
+            proof.verify_membership(&specs, &root, keys, vec![], 0),
+            Err(Error::EmptyVerifiedValue)
+        );
+
+        assert_eq!(
+            apply_prefix(&CommitmentPrefix::default(), vec![path]),
+            Err(Error::EmptyCommitmentPrefix)
+        );
+    }
+
+    #[test]
+    fn raw_merkle_proof_roundtrip() {
+        let (proof, _) = dummy_proof("ibc", "connections/connection-0", b"connection end");
+
+        let raw = RawMerkleProof::from(proof.clone());
+        assert_eq!(MerkleProof::try_from(raw), Ok(proof));
+    }
+}
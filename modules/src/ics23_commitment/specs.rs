@@ -23,6 +23,21 @@ impl ProofSpecs {
             ],
         }
     }
+
+    /// Returns the number of proof specifications, i.e., the number of sub-trees a proof must
+    /// traverse to reach the root.
+    pub fn len(&self) -> usize {
+        self.specs.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.specs.is_empty()
+    }
+
+    /// Iterates over the specifications, ordered from the lowest sub-tree to the root.
+    pub fn iter(&self) -> impl Iterator<Item = &ProofSpec> {
+        self.specs.iter()
+    }
 }
 
 /// Converts from the domain type (which is represented as a vector of `ics23::ProofSpec`
@@ -64,6 +64,23 @@ pub enum ClientUpgradePath {
     UpgradedClientConsensusState(u64),
 }
 
+impl ClientUpgradePath {
+    /// Returns the key of this path within the upgrade sub-store, given the key under which the
+    /// upgraded IBC state is stored (by default, `upgradedIBCState`).
+    pub fn key(&self, upgraded_ibc_state: &str) -> String {
+        match self {
+            ClientUpgradePath::UpgradedClientState(height) => format!(
+                "{}/{}/{}",
+                upgraded_ibc_state, height, UPGRADED_CLIENT_STATE
+            ),
+            ClientUpgradePath::UpgradedClientConsensusState(height) => format!(
+                "{}/{}/{}",
+                upgraded_ibc_state, height, UPGRADED_CLIENT_CONSENSUS_STATE
+            ),
+        }
+    }
+}
+
 impl Path {
     /// Indication if the path is provable.
     pub fn is_provable(&self) -> bool {
@@ -140,16 +157,7 @@ impl Display for Path {
                 "receipts/ports/{}/channels/{}/sequences/{}",
                 port_id, channel_id, sequence
             ),
-            Path::Upgrade(ClientUpgradePath::UpgradedClientState(height)) => write!(
-                f,
-                "{}/{}/{}",
-                UPGRADED_IBC_STATE, height, UPGRADED_CLIENT_STATE
-            ),
-            Path::Upgrade(ClientUpgradePath::UpgradedClientConsensusState(height)) => write!(
-                f,
-                "{}/{}/{}",
-                UPGRADED_IBC_STATE, height, UPGRADED_CLIENT_CONSENSUS_STATE
-            ),
+            Path::Upgrade(upgrade_path) => write!(f, "{}", upgrade_path.key(UPGRADED_IBC_STATE)),
         }
     }
 }
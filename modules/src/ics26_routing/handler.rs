@@ -2,15 +2,24 @@ use prost_types::Any;
 use tendermint_proto::Protobuf;
 
 use crate::application::ics20_fungible_token_transfer::msgs::transfer;
+use crate::application::ics20_fungible_token_transfer::relay_application_logic::on_ack_packet::on_acknowledgement_packet;
+use crate::application::ics20_fungible_token_transfer::relay_application_logic::on_recv_packet::on_recv_packet;
+use crate::application::ics20_fungible_token_transfer::relay_application_logic::on_timeout_packet::on_timeout_packet;
 use crate::application::ics20_fungible_token_transfer::relay_application_logic::send_transfer::send_transfer as ics20_msg_dispatcher;
+use crate::application::ics20_fungible_token_transfer::PORT_ID as ICS20_PORT_ID;
+use crate::ics02_client::client_state::AnyClientState;
 use crate::ics02_client::handler::dispatch as ics2_msg_dispatcher;
-use crate::ics02_client::msgs::{create_client, update_client, ClientMsg};
+use crate::ics02_client::msgs::{
+    create_client, misbehavior, update_client, upgrade_client, ClientMsg,
+};
 use crate::ics03_connection::handler::dispatch as ics3_msg_dispatcher;
 use crate::ics03_connection::msgs::{
     conn_open_ack, conn_open_confirm, conn_open_init, conn_open_try, ConnectionMsg,
 };
 use crate::ics04_channel::handler::channel_dispatch as ics4_msg_dispatcher;
 use crate::ics04_channel::handler::packet_dispatch as ics04_packet_msg_dispatcher;
+use crate::ics04_channel::handler::write_acknowledgement;
+use crate::ics24_host::identifier::ClientId;
 use crate::{events::IbcEvent, handler::HandlerOutput};
 
 use crate::ics04_channel::msgs::{
@@ -52,6 +61,16 @@ where
                     .map_err(|e| Kind::MalformedMessageBytes.context(e))?;
                 Ok(Ics2Msg(ClientMsg::UpdateClient(domain_msg)))
             }
+            upgrade_client::TYPE_URL => {
+                let domain_msg = upgrade_client::MsgUpgradeAnyClient::decode_vec(&any_msg.value)
+                    .map_err(|e| Kind::MalformedMessageBytes.context(e))?;
+                Ok(Ics2Msg(ClientMsg::UpgradeClient(domain_msg)))
+            }
+            misbehavior::TYPE_URL => {
+                let domain_msg = misbehavior::MsgSubmitAnyMisbehaviour::decode_vec(&any_msg.value)
+                    .map_err(|e| Kind::MalformedMessageBytes.context(e))?;
+                Ok(Ics2Msg(ClientMsg::Misbehaviour(domain_msg)))
+            }
 
             // ICS03
             conn_open_init::TYPE_URL => {
@@ -182,6 +201,7 @@ where
                 ics3_msg_dispatcher(ctx, msg).map_err(|e| Kind::HandlerRaisedError.context(e))?;
 
             // Apply any results to the host chain store.
+            store_verifying_client_state(ctx, handler_output.result.client_state.clone())?;
             ctx.store_connection_result(handler_output.result)
                 .map_err(|e| Kind::KeeperRaisedError.context(e))?;
 
@@ -196,6 +216,7 @@ where
                 ics4_msg_dispatcher(ctx, msg).map_err(|e| Kind::HandlerRaisedError.context(e))?;
 
             // Apply any results to the host chain store.
+            store_verifying_client_state(ctx, handler_output.result.client_state.clone())?;
             ctx.store_channel_result(handler_output.result)
                 .map_err(|e| Kind::KeeperRaisedError.context(e))?;
 
@@ -220,16 +241,22 @@ where
         }
 
         Ics4PacketMsg(msg) => {
-            let handler_output = ics04_packet_msg_dispatcher(ctx, msg)
+            let handler_output = ics04_packet_msg_dispatcher(ctx, msg.clone())
                 .map_err(|e| Kind::HandlerRaisedError.context(e))?;
 
             // Apply any results to the host chain store.
+            store_verifying_client_state(ctx, handler_output.result.client_state())?;
             ctx.store_packet_result(handler_output.result)
                 .map_err(|e| Kind::KeeperRaisedError.context(e))?;
 
+            // Let the application bound to the packet's port act upon the packet.
+            let app_output = ics20_packet_callbacks(ctx, &msg)?;
+
             HandlerOutput::builder()
                 .with_log(handler_output.log)
                 .with_events(handler_output.events)
+                .with_log(app_output.log)
+                .with_events(app_output.events)
                 .with_result(())
         }
     };
@@ -237,10 +264,70 @@ where
     Ok(output)
 }
 
+/// Stores the new state of the client which verified the proofs of a message, for clients whose
+/// state changes on every verification (e.g., solo machines).
+fn store_verifying_client_state<Ctx>(
+    ctx: &mut Ctx,
+    client_state: Option<(ClientId, AnyClientState)>,
+) -> Result<(), Error>
+where
+    Ctx: Ics26Context,
+{
+    if let Some((client_id, client_state)) = client_state {
+        ctx.store_client_state(client_id, client_state)
+            .map_err(|e| Kind::KeeperRaisedError.context(e))?;
+    }
+
+    Ok(())
+}
+
+/// Invokes the ICS20 application callbacks for packets that belong to the transfer port. For a
+/// received packet, this also writes the acknowledgement produced by the application.
+fn ics20_packet_callbacks<Ctx>(ctx: &mut Ctx, msg: &PacketMsg) -> Result<HandlerOutput<()>, Error>
+where
+    Ctx: Ics26Context,
+{
+    let mut output = HandlerOutput::builder();
+
+    match msg {
+        PacketMsg::RecvPacket(msg) if msg.packet.destination_port.as_str() == ICS20_PORT_ID => {
+            let ack = on_recv_packet(ctx, &msg.packet);
+
+            let ack_output =
+                write_acknowledgement::process(&*ctx, msg.packet.clone(), ack.to_bytes())
+                    .map_err(|e| Kind::HandlerRaisedError.context(e))?;
+
+            ctx.store_packet_result(ack_output.result)
+                .map_err(|e| Kind::KeeperRaisedError.context(e))?;
+
+            output = output
+                .with_log(ack_output.log)
+                .with_events(ack_output.events);
+        }
+        PacketMsg::AckPacket(msg) if msg.packet.source_port.as_str() == ICS20_PORT_ID => {
+            on_acknowledgement_packet(ctx, &msg.packet, &msg.acknowledgement)
+                .map_err(|e| Kind::HandlerRaisedError.context(e))?;
+            output.log("success: ICS20 acknowledgement processed");
+        }
+        PacketMsg::ToPacket(msg) if msg.packet.source_port.as_str() == ICS20_PORT_ID => {
+            on_timeout_packet(ctx, &msg.packet).map_err(|e| Kind::HandlerRaisedError.context(e))?;
+            output.log("success: ICS20 tokens refunded");
+        }
+        PacketMsg::ToClosePacket(msg) if msg.packet.source_port.as_str() == ICS20_PORT_ID => {
+            on_timeout_packet(ctx, &msg.packet).map_err(|e| Kind::HandlerRaisedError.context(e))?;
+            output.log("success: ICS20 tokens refunded");
+        }
+        _ => {}
+    }
+
+    Ok(output.with_result(()))
+}
+
 #[cfg(test)]
 mod tests {
     use std::convert::TryFrom;
 
+    use crate::application::ics20_fungible_token_transfer::coin::Coin;
     use crate::application::ics20_fungible_token_transfer::msgs::transfer::test_util::get_dummy_msg_transfer;
     use crate::events::IbcEvent;
     use crate::ics02_client::client_consensus::AnyConsensusState;
@@ -298,11 +385,15 @@ mod tests {
         let update_client_height_after_second_send = Height::new(0, 36);
 
         // We reuse this same context across all tests. Nothing in particular needs parametrizing.
-        let mut ctx = MockContext::default();
+        // The signer is funded so that it can pay for the two ICS20 transfers.
+        let mut ctx =
+            MockContext::default().with_balance(default_signer.clone(), Coin::new("uatom", 100));
 
         let create_client_msg = MsgCreateAnyClient::new(
-            AnyClientState::from(MockClientState(MockHeader::new(start_client_height))),
-            AnyConsensusState::from(MockConsensusState(MockHeader::new(start_client_height))),
+            AnyClientState::from(MockClientState::new(MockHeader::new(start_client_height))),
+            AnyConsensusState::from(MockConsensusState::new(MockHeader::new(
+                start_client_height,
+            ))),
             default_signer.clone(),
         )
         .unwrap();
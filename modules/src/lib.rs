@@ -17,7 +17,9 @@
 //! - ICS 03: Connection
 //! - ICS 04: Channel
 //! - ICS 05: Port
+//! - ICS 06: Solo Machine Client
 //! - ICS 07: Tendermint Client
+//! - ICS 09: Loopback Client
 //! - ICS 18: Basic relayer functions
 //! - ICS 23: Vector Commitment Scheme
 //! - ICS 24: Host Requirements
@@ -40,7 +42,9 @@ pub mod ics02_client;
 pub mod ics03_connection;
 pub mod ics04_channel;
 pub mod ics05_port;
+pub mod ics06_solomachine;
 pub mod ics07_tendermint;
+pub mod ics09_localhost;
 pub mod ics18_relayer;
 pub mod ics23_commitment;
 pub mod ics24_host;
@@ -1,15 +1,21 @@
+use ibc_proto::ibc::core::commitment::v1::MerkleProof;
+
 use crate::ics02_client::client_consensus::AnyConsensusState;
 use crate::ics02_client::client_def::ClientDef;
 use crate::ics02_client::client_state::AnyClientState;
+use crate::ics02_client::context::ClientReader;
 use crate::ics03_connection::connection::ConnectionEnd;
+use crate::ics03_connection::context::ConnectionReader;
 use crate::ics04_channel::channel::ChannelEnd;
-use crate::ics04_channel::packet::Sequence;
+use crate::ics04_channel::context::ChannelReader;
+use crate::ics04_channel::packet::{Packet, Sequence};
 use crate::ics23_commitment::commitment::{CommitmentPrefix, CommitmentProofBytes, CommitmentRoot};
 use crate::ics23_commitment::merkle::apply_prefix;
 use crate::ics24_host::identifier::{ChannelId, ClientId, ConnectionId, PortId};
 use crate::ics24_host::Path;
 use crate::mock::client_state::{MockClientState, MockConsensusState};
 use crate::mock::header::MockHeader;
+use crate::mock::misbehaviour::Misbehaviour;
 use crate::Height;
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -19,9 +25,11 @@ impl ClientDef for MockClient {
     type Header = MockHeader;
     type ClientState = MockClientState;
     type ConsensusState = MockConsensusState;
+    type Misbehaviour = Misbehaviour;
 
     fn check_header_and_update_state(
         &self,
+        _ctx: &dyn ClientReader,
         client_state: Self::ClientState,
         header: Self::Header,
     ) -> Result<(Self::ClientState, Self::ConsensusState), Box<dyn std::error::Error>> {
@@ -30,15 +38,56 @@ impl ClientDef for MockClient {
                 "received header height is lower than (or equal to) client latest height".into(),
             );
         }
-        Ok((MockClientState(header), MockConsensusState(header)))
+        Ok((
+            MockClientState::new(header),
+            MockConsensusState::new(header),
+        ))
+    }
+
+    fn check_misbehaviour_and_update_state(
+        &self,
+        _ctx: &dyn ClientReader,
+        _client_id: ClientId,
+        client_state: Self::ClientState,
+        misbehaviour: Self::Misbehaviour,
+    ) -> Result<Self::ClientState, Box<dyn std::error::Error>> {
+        // Two distinct headers for the same height are considered evidence of misbehaviour.
+        if misbehaviour.header1.height() != misbehaviour.header2.height() {
+            return Err("misbehaviour headers have different heights".into());
+        }
+        if misbehaviour.header1 == misbehaviour.header2 {
+            return Err("misbehaviour headers are identical".into());
+        }
+
+        Ok(client_state.with_frozen_height(misbehaviour.header1.height()))
+    }
+
+    fn verify_upgrade_and_update_state(
+        &self,
+        client_state: &Self::ClientState,
+        _consensus_state: &Self::ConsensusState,
+        upgraded_client_state: Self::ClientState,
+        upgraded_consensus_state: Self::ConsensusState,
+        _proof_upgrade_client: &MerkleProof,
+        _proof_upgrade_consensus_state: &MerkleProof,
+    ) -> Result<(Self::ClientState, Self::ConsensusState), Box<dyn std::error::Error>> {
+        if client_state.latest_height() >= upgraded_client_state.latest_height() {
+            return Err(
+                "upgraded client height is lower than (or equal to) client latest height".into(),
+            );
+        }
+
+        Ok((upgraded_client_state, upgraded_consensus_state))
     }
 
     fn verify_client_consensus_state(
         &self,
+        _ctx: &dyn ConnectionReader,
         _client_state: &Self::ClientState,
         height: Height,
         prefix: &CommitmentPrefix,
         _proof: &CommitmentProofBytes,
+        _root: &CommitmentRoot,
         client_id: &ClientId,
         _consensus_height: Height,
         _expected_consensus_state: &AnyConsensusState,
@@ -62,10 +111,12 @@ impl ClientDef for MockClient {
 
     fn verify_connection_state(
         &self,
+        _ctx: &dyn ConnectionReader,
         _client_state: &Self::ClientState,
         _height: Height,
         _prefix: &CommitmentPrefix,
         _proof: &CommitmentProofBytes,
+        _root: &CommitmentRoot,
         _connection_id: Option<&ConnectionId>,
         _expected_connection_end: &ConnectionEnd,
     ) -> Result<(), Box<dyn std::error::Error>> {
@@ -74,10 +125,12 @@ impl ClientDef for MockClient {
 
     fn verify_channel_state(
         &self,
+        _ctx: &dyn ChannelReader,
         _client_state: &Self::ClientState,
         _height: Height,
         _prefix: &CommitmentPrefix,
         _proof: &CommitmentProofBytes,
+        _root: &CommitmentRoot,
         _port_id: &PortId,
         _channel_id: &ChannelId,
         _expected_channel_end: &ChannelEnd,
@@ -87,6 +140,7 @@ impl ClientDef for MockClient {
 
     fn verify_client_full_state(
         &self,
+        _ctx: &dyn ConnectionReader,
         _client_state: &Self::ClientState,
         _height: Height,
         _root: &CommitmentRoot,
@@ -100,25 +154,26 @@ impl ClientDef for MockClient {
 
     fn verify_packet_data(
         &self,
+        _ctx: &dyn ChannelReader,
         _client_state: &Self::ClientState,
         _height: Height,
+        _prefix: &CommitmentPrefix,
         _proof: &CommitmentProofBytes,
-        _port_id: &PortId,
-        _channel_id: &ChannelId,
-        _seq: &Sequence,
-        _data: String,
+        _root: &CommitmentRoot,
+        _packet: &Packet,
     ) -> Result<(), Box<dyn std::error::Error>> {
         Ok(())
     }
 
     fn verify_packet_acknowledgement(
         &self,
+        _ctx: &dyn ChannelReader,
         _client_state: &Self::ClientState,
         _height: Height,
+        _prefix: &CommitmentPrefix,
         _proof: &CommitmentProofBytes,
-        _port_id: &PortId,
-        _channel_id: &ChannelId,
-        _seq: &Sequence,
+        _root: &CommitmentRoot,
+        _packet: &Packet,
         _data: Vec<u8>,
     ) -> Result<(), Box<dyn std::error::Error>> {
         Ok(())
@@ -126,9 +181,12 @@ impl ClientDef for MockClient {
 
     fn verify_next_sequence_recv(
         &self,
+        _ctx: &dyn ChannelReader,
         _client_state: &Self::ClientState,
         _height: Height,
+        _prefix: &CommitmentPrefix,
         _proof: &CommitmentProofBytes,
+        _root: &CommitmentRoot,
         _port_id: &PortId,
         _channel_id: &ChannelId,
         _seq: &Sequence,
@@ -138,9 +196,12 @@ impl ClientDef for MockClient {
 
     fn verify_packet_receipt_absence(
         &self,
+        _ctx: &dyn ChannelReader,
         _client_state: &Self::ClientState,
         _height: Height,
+        _prefix: &CommitmentPrefix,
         _proof: &CommitmentProofBytes,
+        _root: &CommitmentRoot,
         _port_id: &PortId,
         _channel_id: &ChannelId,
         _seq: &Sequence,
@@ -7,10 +7,15 @@ use std::error::Error;
 use prost_types::Any;
 use sha2::Digest;
 
+use crate::application::ics20_fungible_token_transfer::coin::Coin;
 use crate::application::ics20_fungible_token_transfer::context::Ics20Context;
+use crate::application::ics20_fungible_token_transfer::denom::DenomTrace;
+use crate::application::ics20_fungible_token_transfer::error::{
+    Error as Ics20Error, Kind as Ics20Kind,
+};
 use crate::events::IbcEvent;
 use crate::ics02_client::client_consensus::{AnyConsensusState, AnyConsensusStateWithHeight};
-use crate::ics02_client::client_state::AnyClientState;
+use crate::ics02_client::client_state::{AnyClientState, IdentifiedAnyClientState};
 use crate::ics02_client::client_type::ClientType;
 use crate::ics02_client::context::{ClientKeeper, ClientReader};
 use crate::ics02_client::error::Error as Ics02Error;
@@ -18,13 +23,15 @@ use crate::ics02_client::header::AnyHeader;
 use crate::ics03_connection::connection::ConnectionEnd;
 use crate::ics03_connection::context::{ConnectionKeeper, ConnectionReader};
 use crate::ics03_connection::error::Error as Ics3Error;
-use crate::ics04_channel::channel::ChannelEnd;
+use crate::ics04_channel::channel::{ChannelEnd, IdentifiedChannelEnd};
 use crate::ics04_channel::context::{ChannelKeeper, ChannelReader};
 use crate::ics04_channel::error::{Error as Ics4Error, Kind as Ics4Kind};
 use crate::ics04_channel::packet::{Receipt, Sequence};
 use crate::ics05_port::capabilities::Capability;
 use crate::ics05_port::context::PortReader;
+use crate::ics06_solomachine::client_state::test_util::get_dummy_solomachine_client_state;
 use crate::ics07_tendermint::client_state::test_util::get_dummy_tendermint_client_state;
+use crate::ics09_localhost::client_state::ClientState as LocalhostClientState;
 use crate::ics18_relayer::context::Ics18Context;
 use crate::ics18_relayer::error::{Error as Ics18Error, Kind as Ics18ErrorKind};
 use crate::ics23_commitment::commitment::CommitmentPrefix;
@@ -105,6 +112,12 @@ pub struct MockContext {
 
     // Used by unordered channel
     packet_receipt: HashMap<(PortId, ChannelId, Sequence), Receipt>,
+
+    /// Bank balances of the host chain, indexed by account and denomination.
+    balances: HashMap<(Signer, String), u64>,
+
+    /// The ICS20 denomination traces, indexed by their hash.
+    denom_traces: HashMap<String, DenomTrace>,
 }
 
 /// Returns a MockContext with bare minimum initialization: no clients, no connections and no channels are
@@ -177,6 +190,8 @@ impl MockContext {
             packet_commitment: Default::default(),
             packet_receipt: Default::default(),
             packet_acknowledgement: Default::default(),
+            balances: Default::default(),
+            denom_traces: Default::default(),
             connection_ids_counter: 0,
             channel_ids_counter: 0,
         }
@@ -208,8 +223,8 @@ impl MockContext {
         let (client_state, consensus_state) = match client_type {
             // If it's a mock client, create the corresponding mock states.
             ClientType::Mock => (
-                Some(MockClientState(MockHeader::new(client_state_height)).into()),
-                MockConsensusState(MockHeader::new(cs_height)).into(),
+                Some(MockClientState::new(MockHeader::new(client_state_height)).into()),
+                MockConsensusState::new(MockHeader::new(cs_height)).into(),
             ),
             // If it's a Tendermint client, we need TM states.
             ClientType::Tendermint => {
@@ -224,6 +239,30 @@ impl MockContext {
                 // Return the tuple.
                 (Some(client_state), consensus_state)
             }
+            // A localhost client tracks the host chain itself, hence stores its consensus states.
+            ClientType::Localhost => {
+                let client_state =
+                    LocalhostClientState::new(self.host_chain_id.clone(), client_state_height);
+                let consensus_state = HostBlock::generate_block(
+                    self.host_chain_id.clone(),
+                    self.host_chain_type,
+                    cs_height.revision_height,
+                )
+                .into();
+
+                (Some(client_state.into()), consensus_state)
+            }
+            // A solo machine client signs with a dummy key, its sequence is the client height.
+            ClientType::SoloMachine => {
+                let client_state =
+                    get_dummy_solomachine_client_state(client_state_height.revision_height);
+                let consensus_state = client_state.consensus_state.clone();
+
+                (
+                    Some(AnyClientState::SoloMachine(client_state)),
+                    AnyConsensusState::SoloMachine(consensus_state),
+                )
+            }
         };
         let consensus_states = vec![(cs_height, consensus_state)].into_iter().collect();
 
@@ -344,6 +383,21 @@ impl MockContext {
         }
     }
 
+    /// Credits `amount` to the bank balance of `account`.
+    pub fn with_balance(self, account: Signer, amount: Coin) -> Self {
+        let mut balances = self.balances.clone();
+        *balances.entry((account, amount.denom)).or_insert(0) += amount.amount;
+        Self { balances, ..self }
+    }
+
+    /// Returns the bank balance of `account` in the given denomination.
+    pub fn balance(&self, account: &Signer, denom: &str) -> u64 {
+        self.balances
+            .get(&(account.clone(), denom.to_string()))
+            .copied()
+            .unwrap_or(0)
+    }
+
     /// Accessor for a block of the local (host) chain from this context.
     /// Returns `None` if the block at the requested height does not exist.
     fn host_block(&self, target_height: Height) -> Option<&HostBlock> {
@@ -430,11 +484,125 @@ impl MockContext {
             })
             .collect()
     }
+
+    /// Returns the states of all the clients in this context, with their identifiers.
+    pub fn client_states(&self) -> Vec<IdentifiedAnyClientState> {
+        let mut client_states: Vec<IdentifiedAnyClientState> = self
+            .clients
+            .iter()
+            .filter_map(|(client_id, record)| {
+                record
+                    .client_state
+                    .clone()
+                    .map(|cs| IdentifiedAnyClientState::new(client_id.clone(), cs))
+            })
+            .collect();
+        client_states.sort_by(|a, b| a.client_id.cmp(&b.client_id));
+        client_states
+    }
+
+    /// Returns the identifier of the connection associated with the given client, if any.
+    pub fn client_connection(&self, client_id: &ClientId) -> Option<ConnectionId> {
+        self.client_connections.get(client_id).cloned()
+    }
+
+    /// Returns the identifiers of all the connections in this context.
+    pub fn connection_ids(&self) -> Vec<ConnectionId> {
+        let mut connection_ids: Vec<ConnectionId> = self.connections.keys().cloned().collect();
+        connection_ids.sort();
+        connection_ids
+    }
+
+    /// Returns all the channel ends in this context, with their port and channel identifiers.
+    pub fn channels(&self) -> Vec<IdentifiedChannelEnd> {
+        let mut channels: Vec<IdentifiedChannelEnd> = self
+            .channels
+            .iter()
+            .map(|((port_id, channel_id), channel_end)| {
+                IdentifiedChannelEnd::new(port_id.clone(), channel_id.clone(), channel_end.clone())
+            })
+            .collect();
+        channels.sort_by(|a, b| (&a.port_id, &a.channel_id).cmp(&(&b.port_id, &b.channel_id)));
+        channels
+    }
+
+    /// Returns the commitments of the packets sent on the given channel which were neither
+    /// acknowledged nor timed out yet, ordered by sequence.
+    pub fn packet_commitments(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Vec<(Sequence, String)> {
+        packets_on_channel(&self.packet_commitment, port_id, channel_id)
+    }
+
+    /// Returns the acknowledgements written for the packets received on the given channel,
+    /// ordered by sequence.
+    pub fn packet_acknowledgements(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Vec<(Sequence, String)> {
+        packets_on_channel(&self.packet_acknowledgement, port_id, channel_id)
+    }
+}
+
+fn packets_on_channel(
+    packets: &HashMap<(PortId, ChannelId, Sequence), String>,
+    port_id: &PortId,
+    channel_id: &ChannelId,
+) -> Vec<(Sequence, String)> {
+    let mut result: Vec<(Sequence, String)> = packets
+        .iter()
+        .filter(|((p, c, _), _)| p == port_id && c == channel_id)
+        .map(|((_, _, sequence), value)| (*sequence, value.clone()))
+        .collect();
+    result.sort_by_key(|(sequence, _)| *sequence);
+    result
 }
 
 impl Ics26Context for MockContext {}
 
-impl Ics20Context for MockContext {}
+impl Ics20Context for MockContext {
+    fn get_channel_escrow_address(&self, port_id: &PortId, channel_id: &ChannelId) -> Signer {
+        Signer::new(format!("escrow-{}-{}", port_id, channel_id))
+    }
+
+    fn get_denom_trace(&self, denom_hash: &str) -> Option<DenomTrace> {
+        self.denom_traces.get(denom_hash).cloned()
+    }
+
+    fn store_denom_trace(&mut self, denom_trace: DenomTrace) -> Result<(), Ics20Error> {
+        self.denom_traces.insert(denom_trace.hash(), denom_trace);
+        Ok(())
+    }
+
+    fn send_coins(&mut self, from: &Signer, to: &Signer, amount: &Coin) -> Result<(), Ics20Error> {
+        self.burn_coins(from, amount)?;
+        self.mint_coins(to, amount)
+    }
+
+    fn mint_coins(&mut self, account: &Signer, amount: &Coin) -> Result<(), Ics20Error> {
+        let balance = self
+            .balances
+            .entry((account.clone(), amount.denom.clone()))
+            .or_insert(0);
+        *balance = balance
+            .checked_add(amount.amount)
+            .ok_or_else(|| Ics20Kind::InvalidTokenAmount(amount.amount.to_string()))?;
+        Ok(())
+    }
+
+    fn burn_coins(&mut self, account: &Signer, amount: &Coin) -> Result<(), Ics20Error> {
+        let key = (account.clone(), amount.denom.clone());
+        let balance = self.balances.get(&key).copied().unwrap_or(0);
+        if balance < amount.amount {
+            return Err(Ics20Kind::InsufficientFunds(account.clone(), amount.to_string()).into());
+        }
+        self.balances.insert(key, balance - amount.amount);
+        Ok(())
+    }
+}
 
 impl PortReader for MockContext {
     fn lookup_module_by_port(&self, port_id: &PortId) -> Option<Capability> {
@@ -723,6 +891,18 @@ impl ClientReader for MockContext {
         }
     }
 
+    fn host_height(&self) -> Height {
+        self.latest_height
+    }
+
+    fn host_timestamp(&self) -> Timestamp {
+        self.timestamp
+    }
+
+    fn host_consensus_state(&self, height: Height) -> Option<AnyConsensusState> {
+        ConnectionReader::host_consensus_state(self, height)
+    }
+
     fn client_counter(&self) -> u64 {
         self.client_ids_counter
     }
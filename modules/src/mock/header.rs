@@ -68,16 +68,16 @@ impl Header for MockHeader {
     }
 
     fn height(&self) -> Height {
-        todo!()
+        self.height
     }
 
     fn wrap_any(self) -> AnyHeader {
-        todo!()
+        AnyHeader::Mock(self)
     }
 }
 
 impl From<MockHeader> for AnyConsensusState {
     fn from(h: MockHeader) -> Self {
-        AnyConsensusState::Mock(MockConsensusState(h))
+        AnyConsensusState::Mock(MockConsensusState::new(h))
     }
 }
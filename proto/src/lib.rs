@@ -90,6 +90,11 @@ pub mod cosmos {
             include!("prost/cosmos.gov.v1beta1.rs");
         }
     }
+    pub mod feegrant {
+        pub mod v1beta1 {
+            include!("prost/cosmos.feegrant.v1beta1.rs");
+        }
+    }
 }
 
 pub mod ibc {
@@ -3,8 +3,9 @@ use tokio::runtime::Runtime as TokioRuntime;
 
 use ibc::ics24_host::identifier::ChainId;
 use ibc_relayer::{
-    chain::{handle::ChainHandle, runtime::ChainRuntime, CosmosSdkChain},
-    config::Config,
+    chain::handle::ChainHandle,
+    config::{ChainConfig, Config},
+    keyring, registry,
 };
 
 use crate::error::{Error, Kind};
@@ -45,11 +46,33 @@ pub fn spawn_chain_runtime(
         .ok_or_else(|| format!("missing chain for id ({}) in configuration file", chain_id))
         .map_err(|e| Kind::Config.context(e))?;
 
+    unlock_key_store(&chain_config)?;
+
     let rt = Arc::new(TokioRuntime::new().unwrap());
-    let chain_res = ChainRuntime::<CosmosSdkChain>::spawn(chain_config, rt)
-        .map_err(|e| Kind::Runtime.context(e));
 
-    let handle = chain_res.map(|(handle, _)| handle)?;
+    let handle = registry::spawn_chain_runtime_with_config(chain_config, rt)
+        .map_err(|e| Kind::Runtime.context(e))?;
+
+    Ok(handle)
+}
+
+/// Spawns a chain runtime from the configuration of the chain, without a light client nor an
+/// event monitor, for the commands which only query the chain or send it messages.
+/// Returns the corresponding handle if successful.
+pub fn spawn_minimal_chain_runtime_with_config(
+    chain_config: &ChainConfig,
+    rt: Arc<TokioRuntime>,
+) -> Result<Box<dyn ChainHandle>, Error> {
+    unlock_key_store(chain_config)?;
+
+    let handle = registry::spawn_minimal_chain_runtime_with_config(chain_config.clone(), rt)
+        .map_err(|e| Kind::Runtime.context(e))?;
 
     Ok(handle)
 }
+
+/// Reads the passphrase of the key store of the chain if it is encrypted, before the chain
+/// runtime is spawned, so that the runtime never prompts for it.
+pub fn unlock_key_store(chain_config: &ChainConfig) -> Result<(), Error> {
+    keyring::unlock(chain_config).map_err(|e| Kind::Keys.context(e).into())
+}
@@ -9,7 +9,7 @@ use anomaly::BoxError;
 use ibc::ics24_host::identifier::ChainId;
 use ibc_relayer::{
     config::{ChainConfig, Config},
-    keyring::{KeyEntry, KeyRing, Store},
+    keyring::{self, KeyEntry, KeyRing},
 };
 
 use crate::application::app_config;
@@ -69,10 +69,12 @@ impl Runnable for KeysAddCmd {
 }
 
 pub fn add_key(config: ChainConfig, file: &Path) -> Result<KeyEntry, BoxError> {
-    let mut keyring = KeyRing::new(Store::Test, config)?;
+    let key_type = config.key_type;
+    keyring::unlock(&config)?;
+    let mut keyring = KeyRing::new(config.key_store_type, config)?;
 
     let key_contents = fs::read_to_string(file).map_err(|_| "error reading the key file")?;
-    let key = keyring.key_from_seed_file(&key_contents)?;
+    let key = keyring.key_from_seed_file(&key_contents, key_type)?;
 
     keyring.add_key(key.clone())?;
 
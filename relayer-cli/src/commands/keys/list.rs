@@ -4,7 +4,7 @@ use anomaly::BoxError;
 use ibc::ics24_host::identifier::ChainId;
 use ibc_relayer::{
     config::{ChainConfig, Config},
-    keyring::{KeyEntry, KeyRing, Store},
+    keyring::{self, KeyEntry, KeyRing},
 };
 
 use crate::application::app_config;
@@ -57,7 +57,8 @@ pub struct KeysListOptions {
 }
 
 pub fn list_keys(config: ChainConfig) -> Result<KeyEntry, BoxError> {
-    let keyring = KeyRing::new(Store::Test, config)?;
+    keyring::unlock(&config)?;
+    let keyring = KeyRing::new(config.key_store_type, config)?;
     let key_entry = keyring.get_key()?;
     Ok(key_entry)
 }
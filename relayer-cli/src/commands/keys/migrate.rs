@@ -0,0 +1,93 @@
+use abscissa_core::{Command, Options, Runnable};
+use anomaly::BoxError;
+
+use ibc::ics24_host::identifier::ChainId;
+use ibc_relayer::{
+    config::{ChainConfig, Config},
+    keyring::{self, KeyEntry, KeyRing, Store},
+};
+
+use crate::application::app_config;
+use crate::conclude::Output;
+
+#[derive(Clone, Command, Debug, Options)]
+pub struct KeysMigrateCmd {
+    #[options(free, required, help = "identifier of the chain")]
+    chain_id: ChainId,
+
+    #[options(
+        short = "d",
+        help = "delete the key from the test key store once it is migrated"
+    )]
+    delete: bool,
+}
+
+impl KeysMigrateCmd {
+    fn options(&self, config: &Config) -> Result<KeysMigrateOptions, String> {
+        let chain_config = config
+            .find_chain(&self.chain_id)
+            .ok_or_else(|| format!("chain '{}' not found in configuration file", self.chain_id))?;
+
+        if chain_config.key_store_type == Store::Test {
+            return Err(format!(
+                "chain '{}' is configured with the test key store, set `key_store_type` to the store to migrate the key to",
+                self.chain_id
+            ));
+        }
+
+        Ok(KeysMigrateOptions {
+            config: chain_config.clone(),
+            delete: self.delete,
+        })
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct KeysMigrateOptions {
+    pub config: ChainConfig,
+    pub delete: bool,
+}
+
+impl Runnable for KeysMigrateCmd {
+    fn run(&self) {
+        let config = app_config();
+
+        let opts = match self.options(&config) {
+            Err(err) => return Output::error(err).exit(),
+            Ok(result) => result,
+        };
+
+        let key_name = opts.config.key_name.clone();
+        let chain_id = opts.config.id.clone();
+        let store = opts.config.key_store_type;
+        let key = migrate_key(opts.config, opts.delete);
+
+        match key {
+            Ok(key) => Output::success_msg(format!(
+                "Migrated key '{}' ({}) on chain {} from the test key store to the {:?} key store",
+                key_name, key.account, chain_id, store
+            ))
+            .exit(),
+            Err(e) => Output::error(format!("{}", e)).exit(),
+        }
+    }
+}
+
+/// Copies the key of the chain from the test key store to the configured key store,
+/// optionally deleting it from the test key store afterwards.
+pub fn migrate_key(config: ChainConfig, delete: bool) -> Result<KeyEntry, BoxError> {
+    let test_keyring = KeyRing::new(Store::Test, config.clone())?;
+    let key_entry = test_keyring.get_key()?;
+
+    keyring::unlock(&config)?;
+    let mut keyring = KeyRing::new(config.key_store_type, config)?;
+    keyring.add_key(key_entry.clone())?;
+
+    if delete {
+        if let KeyRing::Test(test_store) = test_keyring {
+            test_store.delete_key()?;
+        }
+    }
+
+    Ok(key_entry)
+}
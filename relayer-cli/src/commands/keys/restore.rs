@@ -4,7 +4,7 @@ use anomaly::BoxError;
 use ibc::ics24_host::identifier::ChainId;
 use ibc_relayer::{
     config::{ChainConfig, Config},
-    keyring::{CoinType, KeyEntry, KeyRing, Store},
+    keyring::{self, CoinType, KeyEntry, KeyRing},
 };
 
 use crate::application::app_config;
@@ -20,10 +20,9 @@ pub struct KeyRestoreCmd {
 
     #[options(
         short = "t",
-        help = "coin type of the key to restore, default: 118 (Atom)",
-        default_expr = "CoinType::ATOM"
+        help = "coin type of the key to restore, default: 118 (Atom), or 60 (Ether) for eth_secp256k1 keys"
     )]
-    coin_type: CoinType,
+    coin_type: Option<CoinType>,
 }
 
 #[derive(Clone, Debug)]
@@ -42,7 +41,9 @@ impl KeyRestoreCmd {
         Ok(KeysRestoreOptions {
             mnemonic: self.mnemonic.clone(),
             config: chain_config.clone(),
-            coin_type: self.coin_type,
+            coin_type: self
+                .coin_type
+                .unwrap_or_else(|| chain_config.key_type.default_coin_type()),
         })
     }
 }
@@ -76,8 +77,10 @@ pub fn restore_key(
     coin_type: CoinType,
     config: ChainConfig,
 ) -> Result<KeyEntry, BoxError> {
-    let mut keyring = KeyRing::new(Store::Test, config)?;
-    let key_entry = keyring.key_from_mnemonic(mnemonic, coin_type)?;
+    let key_type = config.key_type;
+    keyring::unlock(&config)?;
+    let mut keyring = KeyRing::new(config.key_store_type, config)?;
+    let key_entry = keyring.key_from_mnemonic(mnemonic, coin_type, key_type)?;
     keyring.add_key(key_entry.clone())?;
 
     Ok(key_entry)
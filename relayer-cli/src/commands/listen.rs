@@ -78,7 +78,11 @@ fn subscribe(
 ) -> Result<(EventMonitor, EventReceiver), BoxError> {
     let (mut event_monitor, rx) = EventMonitor::new(
         chain_config.id.clone(),
-        chain_config.websocket_addr.clone(),
+        chain_config
+            .endpoints()
+            .into_iter()
+            .map(|endpoints| endpoints.websocket_addr)
+            .collect(),
         rt,
     )
     .map_err(|e| format!("could not initialize event monitor: {}", e))?;
@@ -5,9 +5,9 @@ use tokio::runtime::Runtime as TokioRuntime;
 
 use ibc::ics24_host::identifier::ChainId;
 use ibc::ics24_host::identifier::{ChannelId, PortId};
-use ibc_relayer::chain::{Chain, CosmosSdkChain};
 
-use crate::conclude::Output;
+use crate::cli_utils::spawn_minimal_chain_runtime_with_config;
+use crate::conclude::{exit_with_unrecoverable_error, Output};
 use crate::prelude::*;
 
 #[derive(Clone, Command, Debug, Options)]
@@ -43,7 +43,8 @@ impl Runnable for QueryChannelEndCmd {
         debug!("Options: {:?}", self);
 
         let rt = Arc::new(TokioRuntime::new().unwrap());
-        let chain = CosmosSdkChain::bootstrap(chain_config.clone(), rt).unwrap();
+        let chain = spawn_minimal_chain_runtime_with_config(chain_config, rt)
+            .unwrap_or_else(exit_with_unrecoverable_error);
 
         let height = ibc::Height::new(chain.id().version(), self.height.unwrap_or(0_u64));
         let res = chain.query_channel(&self.port_id, &self.channel_id, height);
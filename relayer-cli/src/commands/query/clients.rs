@@ -5,9 +5,9 @@ use tokio::runtime::Runtime as TokioRuntime;
 
 use ibc::ics24_host::identifier::{ChainId, ClientId};
 use ibc_proto::ibc::core::client::v1::QueryClientStatesRequest;
-use ibc_relayer::chain::{Chain, CosmosSdkChain};
 
-use crate::conclude::Output;
+use crate::cli_utils::spawn_minimal_chain_runtime_with_config;
+use crate::conclude::{exit_with_unrecoverable_error, Output};
 use crate::error::{Error, Kind};
 use crate::prelude::*;
 
@@ -38,7 +38,8 @@ impl Runnable for QueryAllClientsCmd {
         debug!("Options: {:?}", self);
 
         let rt = Arc::new(TokioRuntime::new().unwrap());
-        let chain = CosmosSdkChain::bootstrap(chain_config.clone(), rt).unwrap();
+        let chain = spawn_minimal_chain_runtime_with_config(chain_config, rt)
+            .unwrap_or_else(exit_with_unrecoverable_error);
 
         let req = QueryClientStatesRequest {
             pagination: ibc_proto::cosmos::base::query::pagination::all(),
@@ -7,9 +7,9 @@ use ibc::ics03_connection::connection::ConnectionEnd;
 use ibc::ics24_host::identifier::ChainId;
 use ibc::ics24_host::identifier::ConnectionId;
 use ibc_proto::ibc::core::channel::v1::QueryConnectionChannelsRequest;
-use ibc_relayer::chain::{Chain, CosmosSdkChain};
 
-use crate::conclude::Output;
+use crate::cli_utils::spawn_minimal_chain_runtime_with_config;
+use crate::conclude::{exit_with_unrecoverable_error, Output};
 use crate::error::{Error, Kind};
 use crate::prelude::*;
 
@@ -44,7 +44,8 @@ impl Runnable for QueryConnectionEndCmd {
         debug!("Options: {:?}", self);
 
         let rt = Arc::new(TokioRuntime::new().unwrap());
-        let chain = CosmosSdkChain::bootstrap(chain_config.clone(), rt).unwrap();
+        let chain = spawn_minimal_chain_runtime_with_config(chain_config, rt)
+            .unwrap_or_else(exit_with_unrecoverable_error);
 
         let height = ibc::Height::new(chain.id().version(), self.height.unwrap_or(0_u64));
 
@@ -89,7 +90,8 @@ impl Runnable for QueryConnectionChannelsCmd {
         debug!("Options: {:?}", self);
 
         let rt = Arc::new(TokioRuntime::new().unwrap());
-        let chain = CosmosSdkChain::bootstrap(chain_config.clone(), rt).unwrap();
+        let chain = spawn_minimal_chain_runtime_with_config(chain_config, rt)
+            .unwrap_or_else(exit_with_unrecoverable_error);
 
         let req = QueryConnectionChannelsRequest {
             connection: self.connection_id.to_string(),
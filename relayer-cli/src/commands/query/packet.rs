@@ -14,9 +14,8 @@ use ibc_proto::ibc::core::channel::v1::{
     QueryUnreceivedAcksRequest, QueryUnreceivedPacketsRequest,
 };
 
-use ibc_relayer::chain::{runtime::ChainRuntime, CosmosSdkChain};
-
-use crate::conclude::Output;
+use crate::cli_utils::spawn_minimal_chain_runtime_with_config;
+use crate::conclude::{exit_with_unrecoverable_error, Output};
 use crate::error::{Error, Kind};
 use crate::prelude::*;
 use ibc::ics02_client::client_state::ClientState;
@@ -56,7 +55,8 @@ impl Runnable for QueryPacketCommitmentsCmd {
         };
 
         let rt = Arc::new(TokioRuntime::new().unwrap());
-        let (chain, _) = ChainRuntime::<CosmosSdkChain>::spawn(chain_config.clone(), rt).unwrap();
+        let chain = spawn_minimal_chain_runtime_with_config(chain_config, rt)
+            .unwrap_or_else(exit_with_unrecoverable_error);
 
         let grpc_request = QueryPacketCommitmentsRequest {
             port_id: self.port_id.to_string(),
@@ -116,7 +116,8 @@ impl Runnable for QueryPacketCommitmentCmd {
 
         // cargo run --bin hermes -- query packet commitment ibc-0 transfer ibconexfer 3 --height 3
         let rt = Arc::new(TokioRuntime::new().unwrap());
-        let (chain, _) = ChainRuntime::<CosmosSdkChain>::spawn(chain_config.clone(), rt).unwrap();
+        let chain = spawn_minimal_chain_runtime_with_config(chain_config, rt)
+            .unwrap_or_else(exit_with_unrecoverable_error);
 
         let res = chain.build_packet_proofs(
             PacketMsgType::Recv,
@@ -175,8 +176,8 @@ impl Runnable for QueryUnreceivedPacketsCmd {
         };
 
         let rt = Arc::new(TokioRuntime::new().unwrap());
-        let (chain, _) =
-            ChainRuntime::<CosmosSdkChain>::spawn(chain_config.clone(), rt.clone()).unwrap();
+        let chain = spawn_minimal_chain_runtime_with_config(chain_config, rt.clone())
+            .unwrap_or_else(exit_with_unrecoverable_error);
 
         let channel_connection_client =
             match channel_connection_client(chain.as_ref(), &self.port_id, &self.channel_id) {
@@ -210,8 +211,9 @@ impl Runnable for QueryUnreceivedPacketsCmd {
             Some(chain_config) => chain_config,
         };
 
-        let (counterparty_chain, _) =
-            ChainRuntime::<CosmosSdkChain>::spawn(counterparty_chain_config.clone(), rt).unwrap();
+        let counterparty_chain =
+            spawn_minimal_chain_runtime_with_config(counterparty_chain_config, rt)
+                .unwrap_or_else(exit_with_unrecoverable_error);
 
         // get the packet commitments on the counterparty/ source chain
         let commitments_request = QueryPacketCommitmentsRequest {
@@ -289,7 +291,8 @@ impl Runnable for QueryPacketAcknowledgementsCmd {
         };
 
         let rt = Arc::new(TokioRuntime::new().unwrap());
-        let (chain, _) = ChainRuntime::<CosmosSdkChain>::spawn(chain_config.clone(), rt).unwrap();
+        let chain = spawn_minimal_chain_runtime_with_config(chain_config, rt)
+            .unwrap_or_else(exit_with_unrecoverable_error);
         let grpc_request = QueryPacketAcknowledgementsRequest {
             port_id: self.port_id.to_string(),
             channel_id: self.channel_id.to_string(),
@@ -348,7 +351,8 @@ impl Runnable for QueryPacketAcknowledgmentCmd {
 
         // cargo run --bin hermes -- query packet acknowledgment ibc-0 transfer ibconexfer --height 3
         let rt = Arc::new(TokioRuntime::new().unwrap());
-        let (chain, _) = ChainRuntime::<CosmosSdkChain>::spawn(chain_config.clone(), rt).unwrap();
+        let chain = spawn_minimal_chain_runtime_with_config(chain_config, rt)
+            .unwrap_or_else(exit_with_unrecoverable_error);
 
         let res = chain.build_packet_proofs(
             PacketMsgType::Ack,
@@ -405,8 +409,8 @@ impl Runnable for QueryUnreceivedAcknowledgementCmd {
             Some(chain_config) => chain_config,
         };
         let rt = Arc::new(TokioRuntime::new().unwrap());
-        let (chain, _) =
-            ChainRuntime::<CosmosSdkChain>::spawn(chain_config.clone(), rt.clone()).unwrap();
+        let chain = spawn_minimal_chain_runtime_with_config(chain_config, rt.clone())
+            .unwrap_or_else(exit_with_unrecoverable_error);
 
         let channel_connection_client =
             match channel_connection_client(chain.as_ref(), &self.port_id, &self.channel_id) {
@@ -440,8 +444,9 @@ impl Runnable for QueryUnreceivedAcknowledgementCmd {
             Some(chain_config) => chain_config,
         };
 
-        let (counterparty_chain, _) =
-            ChainRuntime::<CosmosSdkChain>::spawn(counterparty_chain_config.clone(), rt).unwrap();
+        let counterparty_chain =
+            spawn_minimal_chain_runtime_with_config(counterparty_chain_config, rt)
+                .unwrap_or_else(exit_with_unrecoverable_error);
 
         // get the packet acknowledgments on counterparty chain
         let acks_request = QueryPacketAcknowledgementsRequest {
@@ -3,6 +3,7 @@ use abscissa_core::{Command, Options, Runnable};
 use ibc::ics24_host::identifier::{ChainId, ChannelId, PortId};
 use ibc_relayer::link::LinkParameters;
 use ibc_relayer::relay::{channel_relay, relay_on_new_link};
+use ibc_relayer::telemetry;
 
 use crate::cli_utils::ChainHandlePair;
 use crate::conclude::Output;
@@ -27,6 +28,12 @@ impl Runnable for StartCmd {
     fn run(&self) {
         let config = app_config();
 
+        if config.global.telemetry.enabled {
+            if let Err(e) = telemetry::spawn(&config.global.telemetry) {
+                return Output::error(format!("failed to start telemetry service: {}", e)).exit();
+            }
+        }
+
         let chains = match ChainHandlePair::spawn(&config, &self.src_chain_id, &self.dst_chain_id) {
             Ok(chains) => chains,
             Err(e) => return Output::error(format!("{}", e)).exit(),
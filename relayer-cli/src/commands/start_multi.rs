@@ -1,8 +1,13 @@
 use abscissa_core::{Command, Options, Runnable};
 
+use ibc_relayer::admin;
 use ibc_relayer::supervisor::Supervisor;
+use ibc_relayer::telemetry;
 
+use crate::cli_utils::unlock_key_store;
+use crate::commands::default_config_file;
 use crate::conclude::Output;
+use crate::config::config_path;
 use crate::prelude::*;
 
 #[derive(Clone, Command, Debug, Options)]
@@ -11,7 +16,32 @@ pub struct StartMultiCmd {}
 impl Runnable for StartMultiCmd {
     fn run(&self) {
         let config = app_config();
-        let supervisor = Supervisor::spawn(config.clone()).expect("failed to spawn supervisor");
+
+        if config.global.telemetry.enabled {
+            if let Err(e) = telemetry::spawn(&config.global.telemetry) {
+                return Output::error(format!("failed to start telemetry service: {}", e)).exit();
+            }
+        }
+
+        for chain_config in &config.chains {
+            if let Err(e) = unlock_key_store(chain_config) {
+                return Output::error(format!("{}", e)).exit();
+            }
+        }
+
+        let mut supervisor = Supervisor::spawn(config.clone()).expect("failed to spawn supervisor");
+
+        // Apply the modifications of the configuration file without restarting
+        if let Some(config_path) = config_path().ok().or_else(default_config_file) {
+            supervisor.watch_config(config_path);
+        }
+
+        if config.global.admin.enabled {
+            if let Err(e) = admin::spawn(&config.global.admin, supervisor.admin_channel()) {
+                return Output::error(format!("failed to start admin service: {}", e)).exit();
+            }
+        }
+
         match supervisor.run() {
             Ok(()) => Output::success_msg("done").exit(),
             Err(e) => Output::error(e).exit(),
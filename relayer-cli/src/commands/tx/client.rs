@@ -119,6 +119,12 @@ pub struct TxUpgradeClientCmd {
 
     #[options(free, required, help = "identifier of the client to be upgraded")]
     client_id: ClientId,
+
+    #[options(
+        help = "height of the upgrade of the source chain, defaults to the height of its current upgrade plan",
+        short = "u"
+    )]
+    upgrade_height: Option<u64>,
 }
 
 impl Runnable for TxUpgradeClientCmd {
@@ -147,10 +153,32 @@ impl Runnable for TxUpgradeClientCmd {
             Err(e) => return Output::error(format!("{}", e)).exit(),
         };
 
+        // The chain reports its upgrade plan only until the upgrade is applied.
+        let upgrade_height = match self.upgrade_height {
+            Some(height) => ibc::Height::new(src_chain_id.version(), height),
+            None => match src_chain.query_upgrade_plan() {
+                Ok(Some(plan)) => plan.height,
+                Ok(None) => {
+                    return Output::error(format!(
+                        "chain '{}' has no upgrade plan, the upgrade height must be specified",
+                        src_chain_id
+                    ))
+                    .exit()
+                }
+                Err(e) => {
+                    return Output::error(format!(
+                        "Query of the upgrade plan of chain '{}' failed with error: {}",
+                        src_chain_id, e
+                    ))
+                    .exit()
+                }
+            },
+        };
+
         let client = ForeignClient::find(src_chain, dst_chain, &self.client_id)
             .unwrap_or_else(exit_with_unrecoverable_error);
 
-        let outcome = client.upgrade();
+        let outcome = client.upgrade(upgrade_height);
 
         match outcome {
             Ok(receipt) => Output::success(receipt).exit(),
@@ -59,16 +59,8 @@ impl Runnable for TxRawConnInitCmd {
             |chains: ChainHandlePair| {
                 Connection {
                     delay_period: Duration::from_secs(0),
-                    a_side: ConnectionSide::new(
-                        chains.src,
-                        self.src_client_id.clone(),
-                        ConnectionId::default(),
-                    ),
-                    b_side: ConnectionSide::new(
-                        chains.dst,
-                        self.dst_client_id.clone(),
-                        ConnectionId::default(),
-                    ),
+                    a_side: ConnectionSide::new(chains.src, self.src_client_id.clone(), None),
+                    b_side: ConnectionSide::new(chains.dst, self.dst_client_id.clone(), None),
                 }
             }
         );
@@ -110,13 +102,9 @@ impl Runnable for TxRawConnTryCmd {
                     a_side: ConnectionSide::new(
                         chains.src,
                         self.src_client_id.clone(),
-                        self.src_conn_id.clone(),
-                    ),
-                    b_side: ConnectionSide::new(
-                        chains.dst,
-                        self.dst_client_id.clone(),
-                        ConnectionId::default(),
+                        Some(self.src_conn_id.clone()),
                     ),
+                    b_side: ConnectionSide::new(chains.dst, self.dst_client_id.clone(), None),
                 }
             }
         );
@@ -166,12 +154,12 @@ impl Runnable for TxRawConnAckCmd {
                     a_side: ConnectionSide::new(
                         chains.src,
                         self.src_client_id.clone(),
-                        self.src_conn_id.clone(),
+                        Some(self.src_conn_id.clone()),
                     ),
                     b_side: ConnectionSide::new(
                         chains.dst,
                         self.dst_client_id.clone(),
-                        self.dst_conn_id.clone(),
+                        Some(self.dst_conn_id.clone()),
                     ),
                 }
             }
@@ -222,12 +210,12 @@ impl Runnable for TxRawConnConfirmCmd {
                     a_side: ConnectionSide::new(
                         chains.src,
                         self.src_client_id.clone(),
-                        self.src_conn_id.clone(),
+                        Some(self.src_conn_id.clone()),
                     ),
                     b_side: ConnectionSide::new(
                         chains.dst,
                         self.dst_client_id.clone(),
-                        self.dst_conn_id.clone(),
+                        Some(self.dst_conn_id.clone()),
                     ),
                 }
             }
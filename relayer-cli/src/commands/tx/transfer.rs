@@ -5,14 +5,15 @@ use anomaly::BoxError;
 use tokio::runtime::Runtime as TokioRuntime;
 
 use ibc::events::IbcEvent;
+use ibc::ics02_client::client_state::ClientState;
 use ibc::ics02_client::height::Height;
 use ibc::ics24_host::identifier::{ChainId, ChannelId, PortId};
 use ibc_relayer::{
-    chain::{Chain, CosmosSdkChain},
     config::Config,
     transfer::{build_and_send_transfer_messages, TransferOptions},
 };
 
+use crate::cli_utils::spawn_minimal_chain_runtime_with_config;
 use crate::conclude::{exit_with_unrecoverable_error, Output};
 use crate::error::{Error, Kind};
 use crate::prelude::*;
@@ -105,16 +106,15 @@ impl Runnable for TxIcs20MsgTransferCmd {
         let rt = Arc::new(TokioRuntime::new().unwrap());
 
         let src_chain_res =
-            CosmosSdkChain::bootstrap(opts.packet_src_chain_config.clone(), rt.clone())
-                .map_err(|e| Kind::Runtime.context(e));
+            spawn_minimal_chain_runtime_with_config(&opts.packet_src_chain_config, rt.clone());
 
         let src_chain = match src_chain_res {
             Ok(chain) => chain,
             Err(e) => return Output::error(format!("{}", e)).exit(),
         };
 
-        let dst_chain_res = CosmosSdkChain::bootstrap(opts.packet_dst_chain_config.clone(), rt)
-            .map_err(|e| Kind::Runtime.context(e));
+        let dst_chain_res =
+            spawn_minimal_chain_runtime_with_config(&opts.packet_dst_chain_config, rt);
 
         let dst_chain = match dst_chain_res {
             Ok(chain) => chain,
@@ -154,13 +154,13 @@ impl Runnable for TxIcs20MsgTransferCmd {
             "client state underlying the channel: {:?}",
             src_chain_client_state
         );
-        if src_chain_client_state.chain_id != self.dst_chain_id {
+        if src_chain_client_state.chain_id() != self.dst_chain_id {
             return Output::error(
                 format!("the requested port/channel ({}/{}) provides a path from chain '{}' to \
                  chain '{}' (not to the destination chain '{}'). Bailing due to mismatching arguments.",
                         opts.packet_src_port_id, opts.packet_src_channel_id,
                         self.src_chain_id,
-                        src_chain_client_state.chain_id, self.dst_chain_id)).exit();
+                        src_chain_client_state.chain_id(), self.dst_chain_id)).exit();
         }
 
         // Checks pass, build and send the tx
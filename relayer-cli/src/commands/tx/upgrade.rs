@@ -5,12 +5,10 @@ use tokio::runtime::Runtime as TokioRuntime;
 
 use ibc::events::IbcEvent;
 use ibc::ics24_host::identifier::{ChainId, ClientId};
+use ibc_relayer::config::Config;
 use ibc_relayer::upgrade_chain::{build_and_send_upgrade_chain_message, UpdatePlanOptions};
-use ibc_relayer::{
-    chain::{Chain, CosmosSdkChain},
-    config::Config,
-};
 
+use crate::cli_utils::spawn_minimal_chain_runtime_with_config;
 use crate::conclude::Output;
 use crate::error::{Error, Kind};
 use crate::prelude::*;
@@ -75,15 +73,14 @@ impl Runnable for TxUpgradeChainCmd {
 
         let rt = Arc::new(TokioRuntime::new().unwrap());
 
-        let src_chain_res = CosmosSdkChain::bootstrap(opts.src_chain_config.clone(), rt.clone())
-            .map_err(|e| Kind::Runtime.context(e));
+        let src_chain_res =
+            spawn_minimal_chain_runtime_with_config(&opts.src_chain_config, rt.clone());
         let src_chain = match src_chain_res {
             Ok(chain) => chain,
             Err(e) => return Output::error(format!("{}", e)).exit(),
         };
 
-        let dst_chain_res = CosmosSdkChain::bootstrap(opts.dst_chain_config.clone(), rt)
-            .map_err(|e| Kind::Runtime.context(e));
+        let dst_chain_res = spawn_minimal_chain_runtime_with_config(&opts.dst_chain_config, rt);
         let dst_chain = match dst_chain_res {
             Ok(chain) => chain,
             Err(e) => return Output::error(format!("{}", e)).exit(),
@@ -0,0 +1,66 @@
+//! Local HTTP/JSON API to inspect and steer a running [`Supervisor`].
+//!
+//! The [`server`] forwards the requests it receives as [`AdminRequest`]s to the supervisor,
+//! which handles them between two batches of events. It is enabled in the [`GlobalConfig`],
+//! and only listens on `127.0.0.1`. The requests modifying the state of the supervisor must
+//! carry the admin token.
+//!
+//! [`Supervisor`]: crate::supervisor::Supervisor
+//! [`GlobalConfig`]: crate::config::GlobalConfig
+
+use crossbeam_channel::Sender;
+use serde::Serialize;
+
+use ibc::ics24_host::identifier::{ChainId, ChannelId, ClientId, PortId};
+use ibc::Height;
+
+use crate::supervisor::Object;
+
+pub mod server;
+
+pub use server::spawn;
+
+/// A request sent by the admin server to the supervisor.
+#[derive(Debug)]
+pub enum AdminRequest {
+    /// List the workers of the supervisor.
+    Workers { reply_to: Sender<Vec<WorkerStatus>> },
+
+    /// Show the status of the runtime of each configured chain.
+    Chains { reply_to: Sender<Vec<ChainStatus>> },
+
+    /// Clear the pending packets sent on the given channel.
+    ClearPackets {
+        chain_id: ChainId,
+        port_id: PortId,
+        channel_id: ChannelId,
+        reply_to: Sender<Result<(), String>>,
+    },
+
+    /// Refresh the given client, hosted by the given chain.
+    RefreshClient {
+        chain_id: ChainId,
+        client_id: ClientId,
+        reply_to: Sender<Result<(), String>>,
+    },
+}
+
+/// A worker of the supervisor.
+#[derive(Clone, Debug, Serialize)]
+pub struct WorkerStatus {
+    pub worker_type: &'static str,
+    pub object: Object,
+}
+
+/// The status of the runtime of a chain.
+#[derive(Clone, Debug, Serialize)]
+pub struct ChainStatus {
+    pub id: ChainId,
+    /// Whether the runtime of the chain was spawned by the supervisor.
+    pub spawned: bool,
+    /// The height of the latest batch of events received from the chain, if any.
+    pub latest_height: Option<Height>,
+    /// The error last reported by the event monitor of the chain, unless events were
+    /// received from it since.
+    pub error: Option<String>,
+}
@@ -0,0 +1,315 @@
+use std::str::FromStr;
+use std::thread;
+use std::time::Duration;
+
+use crossbeam_channel::{self as channel, Sender};
+use serde::Serialize;
+use tracing::{error, info, warn};
+
+use ibc::ics24_host::identifier::{ChainId, ChannelId, ClientId, PortId};
+
+use crate::config::AdminConfig;
+use crate::error::{self, Error};
+
+use super::AdminRequest;
+
+/// The address on which the admin server listens.
+const ADMIN_HOST: &str = "127.0.0.1";
+
+/// How long the server waits for the supervisor to handle a request.
+const REPLY_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Environment variable from which the token authorizing the `POST` requests is read.
+pub const ADMIN_TOKEN_ENV_VAR: &str = "HERMES_ADMIN_TOKEN";
+
+type Response = tiny_http::Response<std::io::Cursor<Vec<u8>>>;
+
+/// Spawns a thread serving the admin API on `127.0.0.1` at the port configured in `config`,
+/// forwarding the requests to the supervisor through `requests`:
+///
+/// - `GET /workers` lists the workers of the supervisor
+/// - `GET /chains` shows the status of the runtime of each chain
+/// - `POST /clear_packets/<chain_id>/<port_id>/<channel_id>` clears the pending packets
+///   sent on a channel
+/// - `POST /refresh_client/<chain_id>/<client_id>` refreshes a client hosted by a chain
+///
+/// The `POST` requests must carry the admin token in an `Authorization: Bearer <token>`
+/// header, and are rejected if no token is configured.
+pub fn spawn(
+    config: &AdminConfig,
+    requests: Sender<AdminRequest>,
+) -> Result<thread::JoinHandle<()>, Error> {
+    let token = read_token(config)?;
+    if token.is_none() {
+        warn!("no admin token is configured, the POST requests of the admin service are disabled");
+    }
+
+    let server = tiny_http::Server::http((ADMIN_HOST, config.port))
+        .map_err(|e| error::Kind::Admin.context(e))?;
+
+    info!(
+        "admin service running at http://{}:{}",
+        ADMIN_HOST, config.port
+    );
+
+    let handle = thread::spawn(move || {
+        for request in server.incoming_requests() {
+            let authorization = request
+                .headers()
+                .iter()
+                .find(|header| header.field.equiv("Authorization"))
+                .map(|header| header.value.as_str());
+
+            let response = handle_request(
+                &requests,
+                token.as_deref(),
+                request.method(),
+                request.url(),
+                authorization,
+            );
+
+            if let Err(e) = request.respond(response) {
+                error!("failed to respond to admin request: {}", e);
+            }
+        }
+    });
+
+    Ok(handle)
+}
+
+/// Reads the token authorizing the `POST` requests from, in order: the `HERMES_ADMIN_TOKEN`
+/// environment variable, or the `token_file` of the admin configuration.
+fn read_token(config: &AdminConfig) -> Result<Option<String>, Error> {
+    if let Ok(token) = std::env::var(ADMIN_TOKEN_ENV_VAR) {
+        return Ok(Some(token));
+    }
+
+    match &config.token_file {
+        Some(path) => {
+            let token = std::fs::read_to_string(path).map_err(|e| {
+                error::Kind::Admin.context(format!("cannot read {:?}: {}", path, e))
+            })?;
+
+            Ok(Some(token.trim_end_matches(&['\r', '\n'][..]).to_string()))
+        }
+        None => Ok(None),
+    }
+}
+
+/// Handles a request with the given `method` and `url`, whose `Authorization` header
+/// must hold the admin `token` for `POST` requests.
+fn handle_request(
+    requests: &Sender<AdminRequest>,
+    token: Option<&str>,
+    method: &tiny_http::Method,
+    url: &str,
+    authorization: Option<&str>,
+) -> Response {
+    use tiny_http::Method::{Get, Post};
+
+    if *method == Post {
+        match token {
+            None => return error_response(403, "no admin token is configured"),
+            Some(token) if authorization != Some(format!("Bearer {}", token).as_str()) => {
+                return error_response(401, "missing or invalid admin token")
+            }
+            Some(_) => (),
+        }
+    }
+
+    let segments: Vec<&str> = url.trim_matches('/').split('/').collect();
+
+    match (method, segments.as_slice()) {
+        (Get, ["workers"]) => forward(requests, |reply_to| AdminRequest::Workers { reply_to })
+            .map_or_else(|e| e, |workers| json_response(&workers)),
+        (Get, ["chains"]) => forward(requests, |reply_to| AdminRequest::Chains { reply_to })
+            .map_or_else(|e| e, |chains| json_response(&chains)),
+        (Post, ["clear_packets", chain_id, port_id, channel_id]) => {
+            let ids = (
+                ChainId::from_str(chain_id),
+                PortId::from_str(port_id),
+                ChannelId::from_str(channel_id),
+            );
+
+            match ids {
+                (Ok(chain_id), Ok(port_id), Ok(channel_id)) => {
+                    forward(requests, |reply_to| AdminRequest::ClearPackets {
+                        chain_id,
+                        port_id,
+                        channel_id,
+                        reply_to,
+                    })
+                    .map_or_else(|e| e, action_response)
+                }
+                _ => error_response(400, "invalid chain, port or channel identifier"),
+            }
+        }
+        (Post, ["refresh_client", chain_id, client_id]) => {
+            match (ChainId::from_str(chain_id), ClientId::from_str(client_id)) {
+                (Ok(chain_id), Ok(client_id)) => {
+                    forward(requests, |reply_to| AdminRequest::RefreshClient {
+                        chain_id,
+                        client_id,
+                        reply_to,
+                    })
+                    .map_or_else(|e| e, action_response)
+                }
+                _ => error_response(400, "invalid chain or client identifier"),
+            }
+        }
+        _ => error_response(404, "not found"),
+    }
+}
+
+/// Sends a request to the supervisor and waits for its reply.
+fn forward<T>(
+    requests: &Sender<AdminRequest>,
+    request: impl FnOnce(Sender<T>) -> AdminRequest,
+) -> Result<T, Response> {
+    let (reply_to, reply) = channel::bounded(1);
+
+    requests
+        .send(request(reply_to))
+        .map_err(|_| error_response(503, "the supervisor is not running"))?;
+
+    reply
+        .recv_timeout(REPLY_TIMEOUT)
+        .map_err(|_| error_response(504, "the supervisor did not reply in time"))
+}
+
+fn action_response(result: Result<(), String>) -> Response {
+    match result {
+        Ok(()) => json_response(&serde_json::json!({ "status": "triggered" })),
+        Err(e) => error_response(404, &e),
+    }
+}
+
+fn json_response(value: &impl Serialize) -> Response {
+    match serde_json::to_vec_pretty(value) {
+        Ok(body) => {
+            let content_type =
+                tiny_http::Header::from_bytes(&b"Content-Type"[..], &b"application/json"[..])
+                    .unwrap();
+
+            tiny_http::Response::from_data(body).with_header(content_type)
+        }
+        Err(e) => error_response(500, &format!("failed to encode the response: {}", e)),
+    }
+}
+
+fn error_response(status: u16, message: &str) -> Response {
+    let body = serde_json::json!({ "error": message }).to_string();
+
+    tiny_http::Response::from_string(body).with_status_code(tiny_http::StatusCode(status))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+
+    use crossbeam_channel as channel;
+    use tiny_http::Method;
+
+    use ibc::ics24_host::identifier::{ChainId, ClientId};
+
+    use super::handle_request;
+    use crate::admin::AdminRequest;
+
+    const TOKEN: &str = "secret";
+
+    /// Handles the requests forwarded to a supervisor which knows a single client,
+    /// `07-tendermint-0` hosted by `chain_a-0`.
+    fn spawn_supervisor() -> channel::Sender<AdminRequest> {
+        let (requests, supervisor) = channel::unbounded();
+
+        thread::spawn(move || {
+            for request in supervisor {
+                match request {
+                    AdminRequest::Workers { reply_to } => {
+                        let _ = reply_to.send(vec![]);
+                    }
+                    AdminRequest::RefreshClient {
+                        chain_id,
+                        client_id,
+                        reply_to,
+                    } => {
+                        let known = chain_id == ChainId::new("chain_a".to_string(), 0)
+                            && client_id == ClientId::default();
+
+                        let _ = reply_to.send(if known {
+                            Ok(())
+                        } else {
+                            Err("no worker".to_string())
+                        });
+                    }
+                    _ => (),
+                }
+            }
+        });
+
+        requests
+    }
+
+    fn status(
+        requests: &channel::Sender<AdminRequest>,
+        method: Method,
+        url: &str,
+        authorization: Option<&str>,
+    ) -> u16 {
+        handle_request(requests, Some(TOKEN), &method, url, authorization)
+            .status_code()
+            .0
+    }
+
+    #[test]
+    fn get_requests_are_forwarded() {
+        let requests = spawn_supervisor();
+
+        assert_eq!(status(&requests, Method::Get, "/workers", None), 200);
+        assert_eq!(status(&requests, Method::Get, "/unknown", None), 404);
+    }
+
+    #[test]
+    fn post_requests_require_the_token() {
+        let requests = spawn_supervisor();
+        let url = "/refresh_client/chain_a-0/07-tendermint-0";
+        let bearer = format!("Bearer {}", TOKEN);
+
+        assert_eq!(status(&requests, Method::Post, url, None), 401);
+        assert_eq!(
+            status(&requests, Method::Post, url, Some("Bearer wrong")),
+            401
+        );
+        assert_eq!(status(&requests, Method::Post, url, Some(&bearer)), 200);
+        assert_eq!(
+            status(
+                &requests,
+                Method::Post,
+                "/refresh_client/chain_a-0/07-tendermint-1",
+                Some(&bearer)
+            ),
+            404
+        );
+        assert_eq!(
+            status(
+                &requests,
+                Method::Post,
+                "/refresh_client/chain_a-0/invalid id",
+                Some(&bearer)
+            ),
+            400
+        );
+
+        // Without a configured token, the POST requests are rejected.
+        let response = handle_request(&requests, None, &Method::Post, url, Some(&bearer));
+        assert_eq!(response.status_code().0, 403);
+    }
+
+    #[test]
+    fn stopped_supervisor_is_reported() {
+        let (requests, supervisor) = channel::unbounded();
+        drop(supervisor);
+
+        assert_eq!(status(&requests, Method::Get, "/chains", None), 503);
+    }
+}
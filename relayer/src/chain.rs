@@ -56,6 +56,33 @@ pub struct QueryResponse {
     pub height: Height,
 }
 
+/// Status of a transaction submitted to a chain
+#[derive(Clone, Debug)]
+pub enum TxStatus {
+    /// The transaction passed `CheckTx`, and is awaiting its inclusion in a block.
+    /// Its `outcome` receives the events of the transaction once committed, or a `ChainError`
+    /// if it is rejected or not committed in time.
+    Pending {
+        hash: tendermint::abci::transaction::Hash,
+        outcome: crossbeam_channel::Receiver<Vec<IbcEvent>>,
+    },
+    /// The transaction was committed with the given events, or was rejected,
+    /// in which case the events consist of a `ChainError`.
+    Completed { events: Vec<IbcEvent> },
+}
+
+/// An upgrade of a chain scheduled by its governance, after which the clients of the chain
+/// must be upgraded to the client and consensus states the chain committed to.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UpgradePlan {
+    pub name: String,
+    /// The height at which the upgraded chain produces its first block.
+    pub height: ICSHeight,
+    /// Whether the chain commits to the state of the clients of the upgraded chain,
+    /// without which the clients cannot be upgraded.
+    pub has_upgraded_client_state: bool,
+}
+
 /// Packet query options
 #[derive(Debug)]
 pub struct QueryPacketOptions {
@@ -94,6 +121,9 @@ pub trait Chain: Sized {
     /// Returns the chain's identifier
     fn id(&self) -> &ChainId;
 
+    /// Returns the configuration of the chain
+    fn config(&self) -> &ChainConfig;
+
     /// Returns the chain's keybase
     fn keybase(&self) -> &KeyRing;
 
@@ -103,6 +133,13 @@ pub trait Chain: Sized {
     /// Sends one or more transactions with `msgs` to chain.
     fn send_msgs(&mut self, proto_msgs: Vec<Any>) -> Result<Vec<IbcEvent>, Error>;
 
+    /// Submits one or more transactions with `msgs` to chain, without waiting for their
+    /// inclusion in a block if the chain supports it.
+    fn submit_msgs(&mut self, proto_msgs: Vec<Any>) -> Result<Vec<TxStatus>, Error> {
+        let events = self.send_msgs(proto_msgs)?;
+        Ok(vec![TxStatus::Completed { events }])
+    }
+
     fn get_signer(&mut self) -> Result<Signer, Error>;
 
     fn get_key(&mut self) -> Result<KeyEntry, Error>;
@@ -111,9 +148,15 @@ pub trait Chain: Sized {
 
     fn query_commitment_prefix(&self) -> Result<CommitmentPrefix, Error>;
 
+    /// Returns the versions supported by the connection module of the chain, as configured in
+    /// its `connection_versions`, or the default compatible versions otherwise.
     fn query_compatible_versions(&self) -> Result<Vec<Version>, Error> {
-        // TODO - do a real chain query
-        Ok(get_compatible_versions())
+        let versions = &self.config().connection_versions;
+        if versions.is_empty() {
+            Ok(get_compatible_versions())
+        } else {
+            Ok(versions.clone())
+        }
     }
 
     /// Query the latest height the chain is at
@@ -125,11 +168,13 @@ pub trait Chain: Sized {
         request: QueryClientStatesRequest,
     ) -> Result<Vec<IdentifiedAnyClientState>, Error>;
 
+    /// Performs a query to retrieve the state of a client that the chain hosts. The client may be
+    /// of any type, e.g., a localhost client of the chain itself.
     fn query_client_state(
         &self,
         client_id: &ClientId,
         height: ICSHeight,
-    ) -> Result<Self::ClientState, Error>;
+    ) -> Result<AnyClientState, Error>;
 
     fn query_consensus_states(
         &self,
@@ -155,6 +200,12 @@ pub trait Chain: Sized {
         height: ICSHeight,
     ) -> Result<(Self::ConsensusState, MerkleProof), Error>;
 
+    /// Performs a query to retrieve the upgrade of the chain currently scheduled, if any.
+    /// Chains which cannot be upgraded never have any.
+    fn query_upgrade_plan(&self) -> Result<Option<UpgradePlan>, Error> {
+        Ok(None)
+    }
+
     /// Performs a query to retrieve the identifiers of all connections.
     fn query_connections(
         &self,
@@ -193,15 +244,19 @@ pub trait Chain: Sized {
     ) -> Result<ChannelEnd, Error>;
 
     // TODO: Introduce a newtype for the module version string
-    fn query_module_version(&self, port_id: &PortId) -> String {
-        // TODO - query the chain, currently hardcoded
-        if port_id.as_str() == "transfer" {
-            "ics20-1".to_string()
-        } else if port_id.as_str() == "ibcaccount" {
-            "ics27-1".to_string()
-        } else {
-            "".to_string()
-        }
+    /// Returns the version of the application module bound to the given port, as configured
+    /// in the `port_versions` of the chain. Only `ChanOpenInit` relies on it, the following
+    /// handshake steps take the version of the counterparty channel end.
+    fn query_module_version(&self, port_id: &PortId) -> Result<String, Error> {
+        self.config()
+            .port_versions
+            .get(port_id)
+            .cloned()
+            .ok_or_else(|| {
+                Kind::Query(format!("module version of port {}", port_id))
+                    .context("no version is configured for the port in `port_versions`")
+                    .into()
+            })
     }
 
     fn query_packet_commitments(
@@ -236,7 +291,7 @@ pub trait Chain: Sized {
         &self,
         client_id: &ClientId,
         height: ICSHeight,
-    ) -> Result<(Self::ClientState, MerkleProof), Error>;
+    ) -> Result<(AnyClientState, MerkleProof), Error>;
 
     fn proven_connection(
         &self,
@@ -289,7 +344,7 @@ pub trait Chain: Sized {
         connection_id: &ConnectionId,
         client_id: &ClientId,
         height: ICSHeight,
-    ) -> Result<(Option<Self::ClientState>, Proofs), Error> {
+    ) -> Result<(Option<AnyClientState>, Proofs), Error> {
         let (connection_end, connection_proof) = self.proven_connection(&connection_id, height)?;
 
         // Check that the connection state is compatible with the message
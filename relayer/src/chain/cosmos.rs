@@ -1,6 +1,6 @@
 use std::{
-    convert::TryFrom, convert::TryInto, future::Future, str::FromStr, sync::Arc, thread,
-    time::Duration,
+    convert::TryFrom, convert::TryInto, future::Future, str::FromStr, sync::Arc, sync::Mutex,
+    thread, time::Duration, time::Instant,
 };
 
 use anomaly::fail;
@@ -8,19 +8,22 @@ use bech32::{ToBase32, Variant};
 use bitcoin::hashes::hex::ToHex;
 use prost::Message;
 use prost_types::Any;
+use tendermint::abci::transaction::Hash as TxHash;
 use tendermint::abci::Path as TendermintABCIPath;
 use tendermint::account::Id as AccountId;
 use tendermint::block::Height;
 use tendermint::consensus::Params;
 use tendermint_light_client::types::LightBlock as TMLightBlock;
 use tendermint_proto::Protobuf;
+use tendermint_rpc::endpoint::broadcast::tx_sync::Response as SyncResponse;
 use tendermint_rpc::query::Query;
 use tendermint_rpc::{endpoint::broadcast::tx_commit::Response, Client, HttpClient, Order};
 use tokio::runtime::Runtime as TokioRuntime;
 use tonic::codegen::http::Uri;
+use tracing::{debug, error, trace, warn};
 
 use ibc::downcast;
-use ibc::events::{from_tx_response_event, IbcEvent};
+use ibc::events::{from_tx_response_event, IbcEvent, VecIbcEvents};
 use ibc::ics02_client::client_consensus::{
     AnyConsensusState, AnyConsensusStateWithHeight, QueryClientEventRequest,
 };
@@ -45,11 +48,12 @@ use ibc::Height as ICSHeight;
 // Support for GRPC
 use ibc_proto::cosmos::auth::v1beta1::{BaseAccount, QueryAccountRequest};
 use ibc_proto::cosmos::base::v1beta1::Coin;
+use ibc_proto::cosmos::feegrant::v1beta1::{Grant, QueryAllowanceRequest};
 use ibc_proto::cosmos::tx::v1beta1::mode_info::{Single, Sum};
-use ibc_proto::cosmos::tx::v1beta1::{AuthInfo, Fee, ModeInfo, SignDoc, SignerInfo, TxBody, TxRaw};
-use ibc_proto::cosmos::upgrade::v1beta1::{
-    QueryCurrentPlanRequest, QueryUpgradedConsensusStateRequest,
+use ibc_proto::cosmos::tx::v1beta1::{
+    AuthInfo, Fee, ModeInfo, SignDoc, SignerInfo, SimulateRequest, Tx, TxBody, TxRaw,
 };
+use ibc_proto::cosmos::upgrade::v1beta1::QueryCurrentPlanRequest;
 use ibc_proto::ibc::core::channel::v1::{
     PacketState, QueryChannelsRequest, QueryConnectionChannelsRequest,
     QueryNextSequenceReceiveRequest, QueryPacketAcknowledgementsRequest,
@@ -61,29 +65,66 @@ use ibc_proto::ibc::core::connection::v1::{
     QueryClientConnectionsRequest, QueryConnectionsRequest,
 };
 
-use crate::chain::QueryResponse;
-use crate::config::ChainConfig;
+use crate::chain::{QueryResponse, TxStatus, UpgradePlan};
+use crate::config::{BroadcastMode, ChainConfig, Endpoints};
 use crate::error::{Error, Kind};
 use crate::event::monitor::{EventMonitor, EventReceiver};
-use crate::keyring::{KeyEntry, KeyRing, Store};
+use crate::keyring::{KeyEntry, KeyRing};
 use crate::light_client::tendermint::LightClient as TmLightClient;
 use crate::light_client::LightClient;
+use crate::telemetry;
+use crate::util::failover::Failover;
 
 use super::Chain;
 use tendermint_rpc::endpoint::tx_search::ResultTx;
 
+mod batch;
+mod gas;
+mod sequence;
+
 // TODO size this properly
 const DEFAULT_MAX_GAS: u64 = 300000;
 const DEFAULT_MAX_MSG_NUM: usize = 30;
 const DEFAULT_MAX_TX_SIZE: usize = 2 * 1048576; // 2 MBytes
 const DEFAULT_GAS_FEE_AMOUNT: u64 = 1000;
+const DEFAULT_GAS_ADJUSTMENT: f64 = 1.1;
+
+/// Interval between two queries for the inclusion of a transaction broadcast with
+/// `broadcast_tx_sync`.
+const TX_POLL_INTERVAL: Duration = Duration::from_secs(1);
 
 pub struct CosmosSdkChain {
     config: ChainConfig,
-    rpc_client: HttpClient,
-    grpc_addr: Uri,
+    /// The nodes of the chain, and the one in use
+    nodes: Mutex<Failover<Node>>,
     rt: Arc<TokioRuntime>,
     keybase: KeyRing,
+    /// The account of the relayer, with the sequence of its next transaction
+    account: Option<BaseAccount>,
+}
+
+/// The RPC and gRPC clients of a node of the chain.
+#[derive(Clone)]
+struct Node {
+    rpc_addr: tendermint_rpc::Url,
+    rpc_client: HttpClient,
+    grpc_addr: Uri,
+}
+
+impl Node {
+    fn new(endpoints: Endpoints) -> Result<Self, Error> {
+        let rpc_client = HttpClient::new(endpoints.rpc_addr.clone())
+            .map_err(|e| Kind::Rpc(endpoints.rpc_addr.clone()).context(e))?;
+
+        let grpc_addr =
+            Uri::from_str(&endpoints.grpc_addr.to_string()).map_err(|e| Kind::Grpc.context(e))?;
+
+        Ok(Self {
+            rpc_addr: endpoints.rpc_addr,
+            rpc_client,
+            grpc_addr,
+        })
+    }
 }
 
 impl CosmosSdkChain {
@@ -94,10 +135,10 @@ impl CosmosSdkChain {
         let mut client = self
             .block_on(
                 ibc_proto::cosmos::staking::v1beta1::query_client::QueryClient::connect(
-                    self.grpc_addr.clone(),
+                    self.grpc_addr(),
                 ),
             )
-            .map_err(|e| Kind::Grpc.context(e))?;
+            .map_err(|e| self.failover(Kind::Grpc.context(e)))?;
 
         let request =
             tonic::Request::new(ibc_proto::cosmos::staking::v1beta1::QueryParamsRequest {});
@@ -116,12 +157,41 @@ impl CosmosSdkChain {
         Ok(Duration::from_secs(res.seconds as u64))
     }
 
-    fn rpc_client(&self) -> &HttpClient {
-        &self.rpc_client
+    /// The node of the chain in use.
+    fn node(&self) -> Node {
+        self.nodes.lock().unwrap().current().clone()
     }
 
-    pub fn config(&self) -> &ChainConfig {
-        &self.config
+    fn rpc_client(&self) -> HttpClient {
+        self.node().rpc_client
+    }
+
+    fn rpc_addr(&self) -> tendermint_rpc::Url {
+        self.node().rpc_addr
+    }
+
+    fn grpc_addr(&self) -> Uri {
+        self.node().grpc_addr
+    }
+
+    /// Fails over to the next healthy node of the chain, after the node in use
+    /// failed with the given error, which is returned.
+    fn failover<E>(&self, e: E) -> E {
+        let mut nodes = self.nodes.lock().unwrap();
+
+        let failed = nodes.current().rpc_addr.clone();
+        let next = nodes.failed();
+
+        if next.rpc_addr != failed {
+            warn!(
+                "[{}] node {} failed, failing over to node {}",
+                self.id(),
+                failed,
+                next.rpc_addr
+            );
+        }
+
+        e
     }
 
     /// Query the consensus parameters via an RPC query
@@ -131,7 +201,7 @@ impl CosmosSdkChain {
 
         Ok(self
             .block_on(self.rpc_client().genesis())
-            .map_err(|e| Kind::Rpc(self.config.rpc_addr.clone()).context(e))?
+            .map_err(|e| self.failover(Kind::Rpc(self.rpc_addr()).context(e)))?
             .consensus_params)
     }
 
@@ -141,27 +211,226 @@ impl CosmosSdkChain {
         self.rt.block_on(f)
     }
 
-    fn send_tx(&self, proto_msgs: Vec<Any>) -> Result<Vec<IbcEvent>, Error> {
+    fn send_tx(&mut self, proto_msgs: Vec<Any>) -> Result<Vec<IbcEvent>, Error> {
         crate::time!("send_tx");
 
-        let key = self
-            .keybase()
-            .get_key()
-            .map_err(|e| Kind::KeyBase.context(e))?;
+        if self.config.broadcast_mode == BroadcastMode::Sync {
+            return match self.submit_tx(proto_msgs)? {
+                TxStatus::Pending { hash, outcome } => {
+                    // The tracker of the transaction gives up after `tx_confirmation_timeout`,
+                    // the RPC timeout leaves it the time to report it.
+                    let timeout = self.config.tx_confirmation_timeout + self.config.rpc_timeout;
+                    match outcome.recv_timeout(timeout) {
+                        Ok(events) => Ok(events),
+                        Err(crossbeam_channel::RecvTimeoutError::Timeout) => {
+                            Ok(vec![IbcEvent::ChainError(format!(
+                                "tx {} was not committed after {:?}",
+                                hash, timeout
+                            ))])
+                        }
+                        Err(e) => Err(Kind::Channel.context(e).into()),
+                    }
+                }
+                TxStatus::Completed { events } => Ok(events),
+            };
+        }
+
+        let key = self.get_key()?;
+        let body = tx_body(proto_msgs);
+
+        let account = self.account(&key)?;
+        let mut response = self.commit_tx(&key, &account, &body)?;
+
+        // Retry once with the expected sequence if the cached one is out of date,
+        // eg. because a transaction was sent with the same account by another client.
+        if let Some(sequence) = sequence::expected_sequence(&response.check_tx.log.to_string()) {
+            let account = self.expected_account(account, sequence);
+            response = self.commit_tx(&key, &account, &body)?;
+        }
+
+        let res = tx_result_to_event(&self.config.id, response)?;
+
+        if res.iter().any(|ev| matches!(ev, IbcEvent::ChainError(_))) {
+            telemetry::global().tx_failed(&self.config.id);
+        }
+
+        Ok(res)
+    }
+
+    /// Broadcasts a transaction with `broadcast_tx_sync`, returning once it passed `CheckTx`.
+    /// Its inclusion in a block is then tracked in the background.
+    fn submit_tx(&mut self, proto_msgs: Vec<Any>) -> Result<TxStatus, Error> {
+        crate::time!("submit_tx");
+
+        let key = self.get_key()?;
+        let body = tx_body(proto_msgs);
+
+        let account = self.account(&key)?;
+        let mut response = self.check_tx(&key, &account, &body)?;
+
+        // Retry once with the expected sequence if the cached one is out of date
+        if let Some(sequence) = sequence::expected_sequence(&response.log.to_string()) {
+            let account = self.expected_account(account, sequence);
+            response = self.check_tx(&key, &account, &body)?;
+        }
+
+        if response.code.is_err() {
+            telemetry::global().tx_failed(&self.config.id);
+
+            return Ok(TxStatus::Completed {
+                events: vec![IbcEvent::ChainError(format!(
+                    "check_tx reports error: log={:?}",
+                    response.log
+                ))],
+            });
+        }
+
+        let (sender, outcome) = crossbeam_channel::bounded(1);
+        self.rt.spawn(track_tx(
+            self.rpc_client(),
+            self.config.id.clone(),
+            response.hash,
+            self.config.tx_confirmation_timeout,
+            sender,
+        ));
+
+        Ok(TxStatus::Pending {
+            hash: response.hash,
+            outcome,
+        })
+    }
+
+    /// Returns the account of the relayer, which is only queried if it is not cached yet.
+    fn account(&mut self, key: &KeyEntry) -> Result<BaseAccount, Error> {
+        if let Some(account) = &self.account {
+            return Ok(account.clone());
+        }
+
+        let account = self
+            .block_on(query_account(self, key.account.clone()))
+            .map_err(|e| Kind::Grpc.context(e))?;
+
+        self.account = Some(account.clone());
+
+        Ok(account)
+    }
+
+    /// The `account` with the `sequence` expected by the chain, after a transaction
+    /// was rejected because of an account sequence mismatch.
+    fn expected_account(&self, account: BaseAccount, sequence: u64) -> BaseAccount {
+        warn!(
+            "[{}] account sequence mismatch, retrying with sequence {} instead of {}",
+            self.id(),
+            sequence,
+            account.sequence
+        );
+
+        BaseAccount {
+            sequence,
+            ..account
+        }
+    }
+
+    /// Signs the transaction with the given `body` using the number and sequence of `account`,
+    /// and broadcasts it with `broadcast_tx_commit`.
+    fn commit_tx(
+        &mut self,
+        key: &KeyEntry,
+        account: &BaseAccount,
+        body: &TxBody,
+    ) -> Result<Response, Error> {
+        let tx = self.encode_tx(key, account, body)?;
+
+        telemetry::global().tx_submitted(&self.config.id);
+
+        let response = self.block_on(broadcast_tx_commit(self, tx));
+
+        self.consume_sequence(
+            account,
+            matches!(&response, Ok(response) if response.check_tx.code.is_ok()),
+        );
+
+        response.map_err(|e| {
+            telemetry::global().tx_failed(&self.config.id);
+            Kind::Rpc(self.rpc_addr()).context(e).into()
+        })
+    }
+
+    /// Signs the transaction with the given `body` using the number and sequence of `account`,
+    /// and broadcasts it with `broadcast_tx_sync`, which only waits for its `CheckTx`.
+    fn check_tx(
+        &mut self,
+        key: &KeyEntry,
+        account: &BaseAccount,
+        body: &TxBody,
+    ) -> Result<SyncResponse, Error> {
+        let tx = self.encode_tx(key, account, body)?;
+
+        telemetry::global().tx_submitted(&self.config.id);
+
+        let response = self.block_on(broadcast_tx_sync(self, tx));
 
-        // Create TxBody
-        let body = TxBody {
-            messages: proto_msgs.to_vec(),
-            memo: "".to_string(),
-            timeout_height: 0_u64,
-            extension_options: Vec::<Any>::new(),
-            non_critical_extension_options: Vec::<Any>::new(),
+        self.consume_sequence(
+            account,
+            matches!(&response, Ok(response) if response.code.is_ok()),
+        );
+
+        response.map_err(|e| {
+            telemetry::global().tx_failed(&self.config.id);
+            e
+        })
+    }
+
+    /// If a transaction signed with the sequence of `account` passed `CheckTx`, its sequence
+    /// is consumed, and the next sequence of the account is cached. Otherwise, the cached
+    /// account is discarded, to be queried again before sending the next transaction.
+    fn consume_sequence(&mut self, account: &BaseAccount, passed_check: bool) {
+        self.account = if passed_check {
+            Some(BaseAccount {
+                sequence: account.sequence + 1,
+                ..account.clone()
+            })
+        } else {
+            None
         };
+    }
+
+    /// Signs the transaction with the given `body` using the number and sequence of `account`,
+    /// and returns its encoding.
+    fn encode_tx(
+        &self,
+        key: &KeyEntry,
+        account: &BaseAccount,
+        body: &TxBody,
+    ) -> Result<Vec<u8>, Error> {
+        let (gas, fee) = self.estimate_gas_and_fee(key, account, body)?;
+        let (auth_info, signed) = self.sign_tx(key, account, body, gas, fee)?;
 
         // A protobuf serialization of a TxBody
         let mut body_buf = Vec::new();
-        prost::Message::encode(&body, &mut body_buf).unwrap();
+        prost::Message::encode(body, &mut body_buf).unwrap();
+
+        // A protobuf serialization of a AuthInfo
+        let mut auth_buf = Vec::new();
+        prost::Message::encode(&auth_info, &mut auth_buf).unwrap();
+
+        let tx_raw = TxRaw {
+            body_bytes: body_buf,
+            auth_info_bytes: auth_buf,
+            signatures: vec![signed],
+        };
+
+        let mut txraw_buf = Vec::new();
+        prost::Message::encode(&tx_raw, &mut txraw_buf).unwrap();
+
+        crate::time!("TxRAW {:?}", hex::encode(txraw_buf.clone()));
 
+        Ok(txraw_buf)
+    }
+
+    /// Builds the `AuthInfo` of a transaction signed by `key` with the given `sequence`,
+    /// limited to `gas` and paying `fee`.
+    fn auth_info(&self, key: &KeyEntry, sequence: u64, gas: u64, fee: Coin) -> AuthInfo {
         let mut pk_buf = Vec::new();
         prost::Message::encode(&key.public_key.public_key.to_bytes(), &mut pk_buf).unwrap();
 
@@ -169,80 +438,216 @@ impl CosmosSdkChain {
 
         // Create a MsgSend proto Any message
         let pk_any = Any {
-            type_url: "/cosmos.crypto.secp256k1.PubKey".to_string(),
+            type_url: key.key_type.pubkey_type_url().to_string(),
             value: pk_buf,
         };
 
-        let acct_response = self
-            .block_on(query_account(self, key.account))
-            .map_err(|e| Kind::Grpc.context(e))?;
-
         let single = Single { mode: 1 };
         let sum_single = Some(Sum::Single(single));
         let mode = Some(ModeInfo { sum: sum_single });
         let signer_info = SignerInfo {
             public_key: Some(pk_any),
             mode_info: mode,
-            sequence: acct_response.sequence,
+            sequence,
         };
 
         let fee = Some(Fee {
-            amount: vec![self.fee()],
-            gas_limit: self.gas(),
-            payer: "".to_string(),
-            granter: "".to_string(),
+            amount: vec![fee],
+            gas_limit: gas,
+            // The fee payer must sign the transaction, so it is the relayer account,
+            // which `check_fee_payer` ensures the configured `fee_payer` matches.
+            payer: key.account.clone(),
+            granter: self.config.fee_granter.clone().unwrap_or_default(),
         });
 
-        let auth_info = AuthInfo {
+        AuthInfo {
             signer_infos: vec![signer_info],
             fee,
+        }
+    }
+
+    /// Returns an upper bound of the size of the encoding of a transaction signed by `key`,
+    /// excluding its messages, that is of its `AuthInfo`, its signature and the envelope of
+    /// its `TxBody`, up to a body of `max_tx_size` bytes.
+    fn tx_envelope_len(&self, key: &KeyEntry) -> usize {
+        let fee = Coin {
+            denom: self.config.fee_denom.clone(),
+            amount: u64::MAX.to_string(),
         };
+        let auth_info = self.auth_info(key, u64::MAX, u64::MAX, fee);
+
+        let mut auth_buf = Vec::new();
+        prost::Message::encode(&auth_info, &mut auth_buf).unwrap();
+
+        let tx_raw = TxRaw {
+            body_bytes: vec![],
+            auth_info_bytes: auth_buf,
+            // A secp256k1 signature, with its recovery byte for Ethermint keys.
+            signatures: vec![vec![0; 65]],
+        };
+
+        prost::Message::encoded_len(&tx_raw)
+            + prost::encoding::encoded_len_varint(self.max_tx_size() as u64)
+    }
+
+    /// Splits the messages into batches which fit the `max_msg_num` and `max_tx_size`
+    /// of the chain, once signed into transactions.
+    fn batch_messages(&self, proto_msgs: Vec<Any>) -> Result<Vec<Vec<Any>>, Error> {
+        let key = self
+            .keybase
+            .get_key()
+            .map_err(|e| Kind::KeyBase.context(e))?;
+
+        Ok(batch::batch_messages(
+            proto_msgs,
+            self.max_msg_num(),
+            self.max_tx_size(),
+            self.tx_envelope_len(&key),
+        ))
+    }
+
+    /// Builds the `AuthInfo` of a transaction with the given `body`, limited to `gas` and paying
+    /// `fee`, and signs the transaction with `key`. Returns the `AuthInfo` and the signature.
+    fn sign_tx(
+        &self,
+        key: &KeyEntry,
+        account: &BaseAccount,
+        body: &TxBody,
+        gas: u64,
+        fee: Coin,
+    ) -> Result<(AuthInfo, Vec<u8>), Error> {
+        // A protobuf serialization of a TxBody
+        let mut body_buf = Vec::new();
+        prost::Message::encode(body, &mut body_buf).unwrap();
+
+        let auth_info = self.auth_info(key, account.sequence, gas, fee);
 
         // A protobuf serialization of a AuthInfo
         let mut auth_buf = Vec::new();
         prost::Message::encode(&auth_info, &mut auth_buf).unwrap();
 
         let sign_doc = SignDoc {
-            body_bytes: body_buf.clone(),
-            auth_info_bytes: auth_buf.clone(),
+            body_bytes: body_buf,
+            auth_info_bytes: auth_buf,
             chain_id: self.config.clone().id.to_string(),
-            account_number: acct_response.account_number,
+            account_number: account.account_number,
         };
 
         // A protobuf serialization of a SignDoc
         let mut signdoc_buf = Vec::new();
         prost::Message::encode(&sign_doc, &mut signdoc_buf).unwrap();
 
-        // Sign doc and broadcast
+        // Sign doc
         let signed = self
             .keybase
             .sign_msg(signdoc_buf)
             .map_err(|e| Kind::KeyBase.context(e))?;
 
-        let tx_raw = TxRaw {
-            body_bytes: body_buf,
-            auth_info_bytes: auth_buf,
+        Ok((auth_info, signed))
+    }
+
+    /// Returns the gas limit and the fee of a transaction with the given `body`.
+    ///
+    /// If `simulate_tx` is enabled, the transaction is first simulated. The gas it uses is then
+    /// multiplied by the `gas_adjustment` and capped at the configured `gas`, and the fee is
+    /// computed from the `gas_price`, if any. If the simulation is disabled or fails, the static
+    /// `gas` and `fee_amount` are used instead.
+    fn estimate_gas_and_fee(
+        &self,
+        key: &KeyEntry,
+        account: &BaseAccount,
+        body: &TxBody,
+    ) -> Result<(u64, Coin), Error> {
+        if !self.config.simulate_tx {
+            return Ok((self.gas(), self.fee()));
+        }
+
+        // The transaction is simulated with the maximum gas and fee, so that it passes
+        // the checks of the ante handler.
+        let (auth_info, signed) = self.sign_tx(key, account, body, self.gas(), self.fee())?;
+        let tx = Tx {
+            body: Some(body.clone()),
+            auth_info: Some(auth_info),
             signatures: vec![signed],
         };
 
-        let mut txraw_buf = Vec::new();
-        prost::Message::encode(&tx_raw, &mut txraw_buf).unwrap();
+        match self.block_on(simulate(self, tx)) {
+            Ok(gas_used) => {
+                let gas = gas::adjusted_gas(gas_used, self.gas_adjustment(), self.gas());
+                Ok((gas, self.fee_for_gas(gas)))
+            }
+            Err(e) => {
+                warn!(
+                    "[{}] failed to simulate tx, falling back to the static gas and fee: {}",
+                    self.id(),
+                    e
+                );
+                Ok((self.gas(), self.fee()))
+            }
+        }
+    }
 
-        crate::time!("TxRAW {:?}", hex::encode(txraw_buf.clone()));
+    /// Checks that the `fee_payer`, if any, is the relayer account, as multiple signers
+    /// are not supported.
+    fn check_fee_payer(&self) -> Result<(), Error> {
+        let payer = match &self.config.fee_payer {
+            Some(payer) => payer,
+            None => return Ok(()),
+        };
 
-        let response = self
-            .block_on(broadcast_tx_commit(self, txraw_buf))
-            .map_err(|e| Kind::Rpc(self.config.rpc_addr.clone()).context(e))?;
+        let account = self
+            .keybase
+            .get_key()
+            .map_err(|e| Kind::KeyBase.context(e))?
+            .account;
+
+        if payer != &account {
+            return Err(Kind::Config
+                .context(format!(
+                    "the fee payer {} is not the relayer account {}, which is the only signer \
+                     of the transactions",
+                    payer, account
+                ))
+                .into());
+        }
 
-        let res = tx_result_to_event(&self.config.id, response)?;
+        Ok(())
+    }
 
-        Ok(res)
+    /// Checks that the `fee_granter`, if any, granted an allowance to the relayer account,
+    /// which pays the fees of the transactions.
+    fn check_fee_grant(&self) -> Result<(), Error> {
+        let granter = match &self.config.fee_granter {
+            Some(granter) => granter.clone(),
+            None => return Ok(()),
+        };
+
+        let grantee = self
+            .keybase
+            .get_key()
+            .map_err(|e| Kind::KeyBase.context(e))?
+            .account;
+
+        let grant = self.block_on(query_allowance(self, granter, grantee))?;
+
+        debug!(
+            "[{}] fees paid with the allowance granted by {} to {}",
+            self.id(),
+            grant.granter,
+            grant.grantee
+        );
+
+        Ok(())
     }
 
     fn gas(&self) -> u64 {
         self.config.gas.unwrap_or(DEFAULT_MAX_GAS)
     }
 
+    fn gas_adjustment(&self) -> f64 {
+        self.config.gas_adjustment.unwrap_or(DEFAULT_GAS_ADJUSTMENT)
+    }
+
     fn fee(&self) -> Coin {
         let amount = self
             .config
@@ -256,6 +661,18 @@ impl CosmosSdkChain {
         }
     }
 
+    /// The fee paid for `gas` units of gas at the configured `gas_price`,
+    /// or the static fee if no gas price is configured.
+    fn fee_for_gas(&self, gas: u64) -> Coin {
+        match self.config.gas_price {
+            Some(gas_price) => Coin {
+                denom: self.config.fee_denom.clone(),
+                amount: gas::fee_amount(gas, gas_price).to_string(),
+            },
+            None => self.fee(),
+        }
+    }
+
     fn max_msg_num(&self) -> usize {
         self.config.max_msg_num.unwrap_or(DEFAULT_MAX_MSG_NUM)
     }
@@ -286,12 +703,14 @@ impl CosmosSdkChain {
         Ok(response)
     }
 
-    // Perform an ABCI query against the client upgrade sub-store to fetch a proof.
-    fn query_client_upgrade_proof(
+    // Perform an ABCI query against the client upgrade sub-store to fetch an upgraded state,
+    // with its proof. The upgraded states of an upgrade at `height` are read at the previous
+    // height, the last one of the chain before the upgrade, as they are cleared afterwards.
+    fn query_client_upgrade_state(
         &self,
         data: ClientUpgradePath,
         height: Height,
-    ) -> Result<(MerkleProof, ICSHeight), Error> {
+    ) -> Result<(Any, MerkleProof), Error> {
         let prev_height =
             Height::try_from(height.value() - 1).map_err(|e| Kind::InvalidHeight.context(e))?;
 
@@ -306,12 +725,14 @@ impl CosmosSdkChain {
 
         let proof = response.proof.ok_or(Kind::EmptyResponseProof)?;
 
-        let height = ICSHeight::new(
-            self.config.id.version(),
-            response.height.increment().value(),
-        );
+        if response.value.is_empty() {
+            return Err(Kind::EmptyResponseValue.into());
+        }
+
+        let state = Any::decode(response.value.as_slice())
+            .map_err(|e| Kind::Query("upgraded state".into()).context(e))?;
 
-        Ok((proof, height))
+        Ok((state, proof))
     }
 }
 
@@ -322,37 +743,35 @@ impl Chain for CosmosSdkChain {
     type ClientState = ClientState;
 
     fn bootstrap(config: ChainConfig, rt: Arc<TokioRuntime>) -> Result<Self, Error> {
-        let rpc_client = HttpClient::new(config.rpc_addr.clone())
-            .map_err(|e| Kind::Rpc(config.rpc_addr.clone()).context(e))?;
+        let nodes = config
+            .endpoints()
+            .into_iter()
+            .map(Node::new)
+            .collect::<Result<Vec<_>, _>>()?;
 
         // Initialize key store and load key
-        let keybase =
-            KeyRing::new(Store::Test, config.clone()).map_err(|e| Kind::KeyBase.context(e))?;
-
-        let grpc_addr =
-            Uri::from_str(&config.grpc_addr.to_string()).map_err(|e| Kind::Grpc.context(e))?;
+        let keybase = KeyRing::new(config.key_store_type, config.clone())
+            .map_err(|e| Kind::KeyBase.context(e))?;
 
-        Ok(Self {
+        let chain = Self {
             config,
-            rpc_client,
-            grpc_addr,
+            nodes: Mutex::new(Failover::new(nodes)),
             rt,
             keybase,
-        })
+            account: None,
+        };
+
+        chain.check_fee_payer()?;
+        chain.check_fee_grant()?;
+
+        Ok(chain)
     }
 
     fn init_light_client(&self) -> Result<Box<dyn LightClient<Self>>, Error> {
-        use tendermint_light_client::types::PeerId;
-
         crate::time!("init_light_client");
 
-        let peer_id: PeerId = self
-            .rt
-            .block_on(self.rpc_client.status())
-            .map(|s| s.node_info.id)
-            .map_err(|e| Kind::Rpc(self.config.rpc_addr.clone()).context(e))?;
-
-        let light_client = TmLightClient::from_config(&self.config, peer_id)?;
+        // The light client fails over among the nodes of the chain on its own.
+        let light_client = TmLightClient::from_config(&self.config)?;
 
         Ok(Box::new(light_client))
     }
@@ -365,7 +784,11 @@ impl Chain for CosmosSdkChain {
 
         let (mut event_monitor, event_receiver) = EventMonitor::new(
             self.config.id.clone(),
-            self.config.websocket_addr.clone(),
+            self.config
+                .endpoints()
+                .into_iter()
+                .map(|endpoints| endpoints.websocket_addr)
+                .collect(),
             rt,
         )
         .map_err(Kind::EventMonitor)?;
@@ -381,6 +804,10 @@ impl Chain for CosmosSdkChain {
         &self.config().id
     }
 
+    fn config(&self) -> &ChainConfig {
+        &self.config
+    }
+
     fn keybase(&self) -> &KeyRing {
         &self.keybase
     }
@@ -389,33 +816,18 @@ impl Chain for CosmosSdkChain {
         &mut self.keybase
     }
 
-    /// Send one or more transactions that include all the specified messages
+    /// Send one or more transactions that include all the specified messages.
+    /// The messages are split into transactions according to the `max_msg_num` and
+    /// `max_tx_size` configured for this chain, see `batch::batch_messages`.
     fn send_msgs(&mut self, proto_msgs: Vec<Any>) -> Result<Vec<IbcEvent>, Error> {
         crate::time!("send_msgs");
 
         if proto_msgs.is_empty() {
             return Ok(vec![IbcEvent::Empty("No messages to send".to_string())]);
         }
-        let mut res = vec![];
 
-        let mut n = 0;
-        let mut size = 0;
-        let mut msg_batch = vec![];
-        for msg in proto_msgs.iter() {
-            msg_batch.append(&mut vec![msg.clone()]);
-            let mut buf = Vec::new();
-            prost::Message::encode(msg, &mut buf).unwrap();
-            n += 1;
-            size += buf.len();
-            if n >= self.max_msg_num() || size >= self.max_tx_size() {
-                let mut result = self.send_tx(msg_batch)?;
-                res.append(&mut result);
-                n = 0;
-                size = 0;
-                msg_batch = vec![];
-            }
-        }
-        if !msg_batch.is_empty() {
+        let mut res = vec![];
+        for msg_batch in self.batch_messages(proto_msgs)? {
             let mut result = self.send_tx(msg_batch)?;
             res.append(&mut result);
         }
@@ -423,6 +835,24 @@ impl Chain for CosmosSdkChain {
         Ok(res)
     }
 
+    /// In the `sync` broadcast mode, returns once the transactions passed `CheckTx`, and
+    /// tracks their inclusion in a block in the background.
+    fn submit_msgs(&mut self, proto_msgs: Vec<Any>) -> Result<Vec<TxStatus>, Error> {
+        crate::time!("submit_msgs");
+
+        if self.config.broadcast_mode == BroadcastMode::Commit || proto_msgs.is_empty() {
+            let events = self.send_msgs(proto_msgs)?;
+            return Ok(vec![TxStatus::Completed { events }]);
+        }
+
+        let mut statuses = vec![];
+        for msg_batch in self.batch_messages(proto_msgs)? {
+            statuses.push(self.submit_tx(msg_batch)?);
+        }
+
+        Ok(statuses)
+    }
+
     /// Get the account for the signer
     fn get_signer(&mut self) -> Result<Signer, Error> {
         crate::time!("get_signer");
@@ -465,13 +895,13 @@ impl Chain for CosmosSdkChain {
 
         let status = self
             .block_on(self.rpc_client().status())
-            .map_err(|e| Kind::Rpc(self.config.rpc_addr.clone()).context(e))?;
+            .map_err(|e| self.failover(Kind::Rpc(self.rpc_addr()).context(e)))?;
 
         if status.sync_info.catching_up {
             fail!(
-                Kind::LightClient(self.config.rpc_addr.to_string()),
+                Kind::LightClient(self.rpc_addr().to_string()),
                 "node at {} running chain {} not caught up",
-                self.config().rpc_addr,
+                self.rpc_addr(),
                 self.config().id,
             );
         }
@@ -491,10 +921,10 @@ impl Chain for CosmosSdkChain {
         let mut client = self
             .block_on(
                 ibc_proto::ibc::core::client::v1::query_client::QueryClient::connect(
-                    self.grpc_addr.clone(),
+                    self.grpc_addr(),
                 ),
             )
-            .map_err(|e| Kind::Grpc.context(e))?;
+            .map_err(|e| self.failover(Kind::Grpc.context(e)))?;
 
         let request = tonic::Request::new(request);
         let response = self
@@ -515,7 +945,7 @@ impl Chain for CosmosSdkChain {
         &self,
         client_id: &ClientId,
         height: ICSHeight,
-    ) -> Result<Self::ClientState, Error> {
+    ) -> Result<AnyClientState, Error> {
         crate::time!("query_client_state");
 
         let client_state = self
@@ -525,10 +955,6 @@ impl Chain for CosmosSdkChain {
                 AnyClientState::decode_vec(&v.value)
                     .map_err(|e| Kind::Query("client state".into()).context(e))
             })?;
-        let client_state =
-            downcast!(client_state => AnyClientState::Tendermint).ok_or_else(|| {
-                Kind::Query("client state".into()).context("unexpected client state type")
-            })?;
         Ok(client_state)
     }
 
@@ -538,27 +964,21 @@ impl Chain for CosmosSdkChain {
     ) -> Result<(Self::ClientState, MerkleProof), Error> {
         crate::time!("query_upgraded_client_state");
 
-        let mut client = self
-            .block_on(
-                ibc_proto::cosmos::upgrade::v1beta1::query_client::QueryClient::connect(
-                    self.grpc_addr.clone(),
-                ),
-            )
-            .map_err(|e| Kind::Grpc.context(e))?;
+        let tm_height =
+            Height::try_from(height.revision_height).map_err(|e| Kind::InvalidHeight.context(e))?;
 
-        let req = tonic::Request::new(QueryCurrentPlanRequest {});
-        let response = self
-            .block_on(client.current_plan(req))
-            .map_err(|e| Kind::Grpc.context(e))?;
+        let (upgraded_client_state_raw, proof) = self
+            .query_client_upgrade_state(
+                ClientUpgradePath::UpgradedClientState(height.revision_height),
+                tm_height,
+            )
+            .map_err(|e| match e.kind() {
+                Kind::EmptyResponseValue => Kind::EmptyUpgradedClientState.into(),
+                _ => e,
+            })?;
 
-        let upgraded_client_state_raw = response
-            .into_inner()
-            .plan
-            .ok_or(Kind::EmptyResponseValue)?
-            .upgraded_client_state
-            .ok_or(Kind::EmptyUpgradedClientState)?;
         let client_state = AnyClientState::try_from(upgraded_client_state_raw)
-            .map_err(|e| Kind::Grpc.context(e))?;
+            .map_err(|e| Kind::Query("upgraded client state".into()).context(e.to_string()))?;
 
         // TODO: Better error kinds here.
         let tm_client_state =
@@ -566,14 +986,6 @@ impl Chain for CosmosSdkChain {
                 Kind::Query("upgraded client state".into()).context("unexpected client state type")
             })?;
 
-        // Query for the proof.
-        let tm_height =
-            Height::try_from(height.revision_height).map_err(|e| Kind::InvalidHeight.context(e))?;
-        let (proof, _proof_height) = self.query_client_upgrade_proof(
-            ClientUpgradePath::UpgradedClientState(height.revision_height),
-            tm_height,
-        )?;
-
         Ok((tm_client_state, proof))
     }
 
@@ -586,29 +998,13 @@ impl Chain for CosmosSdkChain {
         let tm_height =
             Height::try_from(height.revision_height).map_err(|e| Kind::InvalidHeight.context(e))?;
 
-        let mut client = self
-            .block_on(
-                ibc_proto::cosmos::upgrade::v1beta1::query_client::QueryClient::connect(
-                    self.grpc_addr.clone(),
-                ),
-            )
-            .map_err(|e| Kind::Grpc.context(e))?;
-
-        let req = tonic::Request::new(QueryUpgradedConsensusStateRequest {
-            last_height: tm_height.into(),
-        });
-        let response = self
-            .block_on(client.upgraded_consensus_state(req))
-            .map_err(|e| Kind::Grpc.context(e))?;
-
-        let upgraded_consensus_state_raw = response
-            .into_inner()
-            .upgraded_consensus_state
-            .ok_or(Kind::EmptyResponseValue)?;
+        let (upgraded_consensus_state_raw, proof) = self.query_client_upgrade_state(
+            ClientUpgradePath::UpgradedClientConsensusState(height.revision_height),
+            tm_height,
+        )?;
 
-        // TODO: More explicit error kinds (should not reuse Grpc all over the place)
         let consensus_state = AnyConsensusState::try_from(upgraded_consensus_state_raw)
-            .map_err(|e| Kind::Grpc.context(e))?;
+            .map_err(|e| Kind::Query("upgraded consensus state".into()).context(e.to_string()))?;
 
         let tm_consensus_state = downcast!(consensus_state => AnyConsensusState::Tendermint)
             .ok_or_else(|| {
@@ -616,15 +1012,34 @@ impl Chain for CosmosSdkChain {
                     .context("unexpected consensus state type")
             })?;
 
-        // Fetch the proof.
-        let (proof, _proof_height) = self.query_client_upgrade_proof(
-            ClientUpgradePath::UpgradedClientConsensusState(height.revision_height),
-            tm_height,
-        )?;
-
         Ok((tm_consensus_state, proof))
     }
 
+    fn query_upgrade_plan(&self) -> Result<Option<UpgradePlan>, Error> {
+        crate::time!("query_upgrade_plan");
+
+        let mut client = self
+            .block_on(
+                ibc_proto::cosmos::upgrade::v1beta1::query_client::QueryClient::connect(
+                    self.grpc_addr(),
+                ),
+            )
+            .map_err(|e| self.failover(Kind::Grpc.context(e)))?;
+
+        let req = tonic::Request::new(QueryCurrentPlanRequest {});
+        let response = self
+            .block_on(client.current_plan(req))
+            .map_err(|e| Kind::Grpc.context(e))?;
+
+        let plan = response.into_inner().plan.map(|plan| UpgradePlan {
+            name: plan.name,
+            height: ICSHeight::new(self.config.id.version(), plan.height as u64),
+            has_upgraded_client_state: plan.upgraded_client_state.is_some(),
+        });
+
+        Ok(plan)
+    }
+
     /// Performs a query to retrieve the identifiers of all connections.
     fn query_consensus_states(
         &self,
@@ -635,10 +1050,10 @@ impl Chain for CosmosSdkChain {
         let mut client = self
             .block_on(
                 ibc_proto::ibc::core::client::v1::query_client::QueryClient::connect(
-                    self.grpc_addr.clone(),
+                    self.grpc_addr(),
                 ),
             )
-            .map_err(|e| Kind::Grpc.context(e))?;
+            .map_err(|e| self.failover(Kind::Grpc.context(e)))?;
 
         let request = tonic::Request::new(request);
         let response = self
@@ -679,10 +1094,10 @@ impl Chain for CosmosSdkChain {
         let mut client = self
             .block_on(
                 ibc_proto::ibc::core::connection::v1::query_client::QueryClient::connect(
-                    self.grpc_addr.clone(),
+                    self.grpc_addr(),
                 ),
             )
-            .map_err(|e| Kind::Grpc.context(e))?;
+            .map_err(|e| self.failover(Kind::Grpc.context(e)))?;
 
         let request = tonic::Request::new(request);
 
@@ -712,10 +1127,10 @@ impl Chain for CosmosSdkChain {
         let mut client = self
             .block_on(
                 ibc_proto::ibc::core::connection::v1::query_client::QueryClient::connect(
-                    self.grpc_addr.clone(),
+                    self.grpc_addr(),
                 ),
             )
-            .map_err(|e| Kind::Grpc.context(e))?;
+            .map_err(|e| self.failover(Kind::Grpc.context(e)))?;
 
         let request = tonic::Request::new(request);
 
@@ -755,10 +1170,10 @@ impl Chain for CosmosSdkChain {
         let mut client = self
             .block_on(
                 ibc_proto::ibc::core::channel::v1::query_client::QueryClient::connect(
-                    self.grpc_addr.clone(),
+                    self.grpc_addr(),
                 ),
             )
-            .map_err(|e| Kind::Grpc.context(e))?;
+            .map_err(|e| self.failover(Kind::Grpc.context(e)))?;
 
         let request = tonic::Request::new(request);
 
@@ -788,10 +1203,10 @@ impl Chain for CosmosSdkChain {
         let mut client = self
             .block_on(
                 ibc_proto::ibc::core::channel::v1::query_client::QueryClient::connect(
-                    self.grpc_addr.clone(),
+                    self.grpc_addr(),
                 ),
             )
-            .map_err(|e| Kind::Grpc.context(e))?;
+            .map_err(|e| self.failover(Kind::Grpc.context(e)))?;
 
         let request = tonic::Request::new(request);
 
@@ -833,10 +1248,10 @@ impl Chain for CosmosSdkChain {
         let mut client = self
             .block_on(
                 ibc_proto::ibc::core::channel::v1::query_client::QueryClient::connect(
-                    self.grpc_addr.clone(),
+                    self.grpc_addr(),
                 ),
             )
-            .map_err(|e| Kind::Grpc.context(e))?;
+            .map_err(|e| self.failover(Kind::Grpc.context(e)))?;
 
         let request = tonic::Request::new(request);
 
@@ -866,10 +1281,10 @@ impl Chain for CosmosSdkChain {
         let mut client = self
             .block_on(
                 ibc_proto::ibc::core::channel::v1::query_client::QueryClient::connect(
-                    self.grpc_addr.clone(),
+                    self.grpc_addr(),
                 ),
             )
-            .map_err(|e| Kind::Grpc.context(e))?;
+            .map_err(|e| self.failover(Kind::Grpc.context(e)))?;
 
         let request = tonic::Request::new(request);
 
@@ -892,10 +1307,10 @@ impl Chain for CosmosSdkChain {
         let mut client = self
             .block_on(
                 ibc_proto::ibc::core::channel::v1::query_client::QueryClient::connect(
-                    self.grpc_addr.clone(),
+                    self.grpc_addr(),
                 ),
             )
-            .map_err(|e| Kind::Grpc.context(e))?;
+            .map_err(|e| self.failover(Kind::Grpc.context(e)))?;
 
         let request = tonic::Request::new(request);
 
@@ -925,10 +1340,10 @@ impl Chain for CosmosSdkChain {
         let mut client = self
             .block_on(
                 ibc_proto::ibc::core::channel::v1::query_client::QueryClient::connect(
-                    self.grpc_addr.clone(),
+                    self.grpc_addr(),
                 ),
             )
-            .map_err(|e| Kind::Grpc.context(e))?;
+            .map_err(|e| self.failover(Kind::Grpc.context(e)))?;
 
         let request = tonic::Request::new(request);
 
@@ -950,10 +1365,10 @@ impl Chain for CosmosSdkChain {
         let mut client = self
             .block_on(
                 ibc_proto::ibc::core::channel::v1::query_client::QueryClient::connect(
-                    self.grpc_addr.clone(),
+                    self.grpc_addr(),
                 ),
             )
-            .map_err(|e| Kind::Grpc.context(e))?;
+            .map_err(|e| self.failover(Kind::Grpc.context(e)))?;
 
         let request = tonic::Request::new(request);
 
@@ -988,14 +1403,14 @@ impl Chain for CosmosSdkChain {
                 for seq in &request.sequences {
                     // query first (and only) Tx that includes the event specified in the query request
                     let response = self
-                        .block_on(self.rpc_client.tx_search(
+                        .block_on(self.rpc_client().tx_search(
                             packet_query(&request, *seq),
                             false,
                             1,
                             1, // get only the first Tx matching the query
                             Order::Ascending,
                         ))
-                        .map_err(|e| Kind::Grpc.context(e))?;
+                        .map_err(|e| self.failover(Kind::Grpc.context(e)))?;
 
                     assert!(
                         response.txs.len() <= 1,
@@ -1028,14 +1443,14 @@ impl Chain for CosmosSdkChain {
                 // failed on chain. Therefore only one Tx is of interest and current API returns
                 // the first one.
                 let mut response = self
-                    .block_on(self.rpc_client.tx_search(
+                    .block_on(self.rpc_client().tx_search(
                         header_query(&request),
                         false,
                         1,
                         1, // get only the first Tx matching the query
                         Order::Ascending,
                     ))
-                    .map_err(|e| Kind::Grpc.context(e))?;
+                    .map_err(|e| self.failover(Kind::Grpc.context(e)))?;
 
                 if response.txs.is_empty() {
                     return Ok(vec![]);
@@ -1059,7 +1474,7 @@ impl Chain for CosmosSdkChain {
         &self,
         client_id: &ClientId,
         height: ICSHeight,
-    ) -> Result<(Self::ClientState, MerkleProof), Error> {
+    ) -> Result<(AnyClientState, MerkleProof), Error> {
         crate::time!("proven_client_state");
 
         let res = self
@@ -1069,11 +1484,6 @@ impl Chain for CosmosSdkChain {
         let client_state = AnyClientState::decode_vec(&res.value)
             .map_err(|e| Kind::Query("client state".into()).context(e))?;
 
-        let client_state =
-            downcast!(client_state => AnyClientState::Tendermint).ok_or_else(|| {
-                Kind::Query("client state".into()).context("unexpected client state type")
-            })?;
-
         Ok((
             client_state,
             res.proof.ok_or_else(|| {
@@ -1385,11 +1795,11 @@ async fn abci_query(
         .rpc_client()
         .abci_query(Some(path), data.into_bytes(), height, prove)
         .await
-        .map_err(|e| Kind::Rpc(chain.config.rpc_addr.clone()).context(e))?;
+        .map_err(|e| chain.failover(Kind::Rpc(chain.rpc_addr()).context(e)))?;
 
     if !response.code.is_ok() {
         // Fail with response log.
-        return Err(Kind::Rpc(chain.config.rpc_addr.clone())
+        return Err(Kind::Rpc(chain.rpc_addr())
             .context(response.log.to_string())
             .into());
     }
@@ -1423,18 +1833,103 @@ async fn broadcast_tx_commit(
         .rpc_client()
         .broadcast_tx_commit(data.into())
         .await
-        .map_err(|e| Kind::Rpc(chain.config.rpc_addr.clone()).context(e))?;
+        .map_err(|e| chain.failover(Kind::Rpc(chain.rpc_addr()).context(e)))?;
 
     Ok(response)
 }
 
+/// Perform a `broadcast_tx_sync`, and return the corresponding deserialized response data.
+async fn broadcast_tx_sync(chain: &CosmosSdkChain, data: Vec<u8>) -> Result<SyncResponse, Error> {
+    let response = chain
+        .rpc_client()
+        .broadcast_tx_sync(data.into())
+        .await
+        .map_err(|e| chain.failover(Kind::Rpc(chain.rpc_addr()).context(e)))?;
+
+    Ok(response)
+}
+
+/// Polls the node for the transaction with the given `hash`, until it is included in a block,
+/// returning its events, or the `timeout` elapses.
+async fn wait_for_tx(
+    rpc_client: &HttpClient,
+    chain_id: &ChainId,
+    hash: TxHash,
+    timeout: Duration,
+) -> Result<Vec<IbcEvent>, Error> {
+    let start = Instant::now();
+
+    loop {
+        let response = rpc_client
+            .tx_search(
+                Query::eq("tx.hash", hash.to_string()),
+                false,
+                1,
+                1,
+                Order::Ascending,
+            )
+            .await;
+
+        match response {
+            Ok(mut response) if !response.txs.is_empty() => {
+                return Ok(tx_search_result_to_event(chain_id, response.txs.remove(0)));
+            }
+            Ok(_) => trace!("[{}] tx {} not committed yet", chain_id, hash),
+            Err(e) => warn!("[{}] failed to query tx {}: {}", chain_id, hash, e),
+        }
+
+        if start.elapsed() >= timeout {
+            return Err(Kind::TxNotCommitted(hash.to_string(), timeout).into());
+        }
+
+        tokio::time::sleep(TX_POLL_INTERVAL).await;
+    }
+}
+
+/// Tracks the inclusion in a block of the transaction with the given `hash`, and sends its
+/// events to `outcome`, or a `ChainError` if it is rejected or not committed within `timeout`.
+async fn track_tx(
+    rpc_client: HttpClient,
+    chain_id: ChainId,
+    hash: TxHash,
+    timeout: Duration,
+    outcome: crossbeam_channel::Sender<Vec<IbcEvent>>,
+) {
+    let events = match wait_for_tx(&rpc_client, &chain_id, hash, timeout).await {
+        Ok(events) => {
+            if let Some(error) = events
+                .iter()
+                .find(|event| matches!(event, IbcEvent::ChainError(_)))
+            {
+                telemetry::global().tx_failed(&chain_id);
+                error!("[{}] tx {} failed: {}", chain_id, hash, error);
+            } else {
+                debug!(
+                    "[{}] tx {} committed: {}",
+                    chain_id,
+                    hash,
+                    VecIbcEvents(events.clone())
+                );
+            }
+            events
+        }
+        Err(e) => {
+            telemetry::global().tx_failed(&chain_id);
+            error!("[{}] {}", chain_id, e);
+            vec![IbcEvent::ChainError(e.to_string())]
+        }
+    };
+
+    // The submitter of the transaction may not wait for its outcome
+    let _ = outcome.send(events);
+}
+
 /// Uses the GRPC client to retrieve the account sequence
 async fn query_account(chain: &CosmosSdkChain, address: String) -> Result<BaseAccount, Error> {
-    let mut client = ibc_proto::cosmos::auth::v1beta1::query_client::QueryClient::connect(
-        chain.grpc_addr.clone(),
-    )
-    .await
-    .map_err(|e| Kind::Grpc.context(e))?;
+    let mut client =
+        ibc_proto::cosmos::auth::v1beta1::query_client::QueryClient::connect(chain.grpc_addr())
+            .await
+            .map_err(|e| chain.failover(Kind::Grpc.context(e)))?;
 
     let request = tonic::Request::new(QueryAccountRequest { address });
 
@@ -1454,6 +1949,86 @@ async fn query_account(chain: &CosmosSdkChain, address: String) -> Result<BaseAc
     Ok(base_account)
 }
 
+/// Uses the GRPC client to retrieve the fee allowance granted by `granter` to `grantee`
+async fn query_allowance(
+    chain: &CosmosSdkChain,
+    granter: String,
+    grantee: String,
+) -> Result<Grant, Error> {
+    let mut client =
+        ibc_proto::cosmos::feegrant::v1beta1::query_client::QueryClient::connect(chain.grpc_addr())
+            .await
+            .map_err(|e| chain.failover(Kind::Grpc.context(e)))?;
+
+    let request = tonic::Request::new(QueryAllowanceRequest {
+        granter: granter.clone(),
+        grantee: grantee.clone(),
+    });
+
+    let response = client
+        .allowance(request)
+        .await
+        .map_err(|e| Kind::MissingFeeGrant(granter.clone(), grantee.clone()).context(e))?;
+
+    response
+        .into_inner()
+        .allowance
+        .ok_or_else(|| Kind::MissingFeeGrant(granter, grantee).into())
+}
+
+/// Simulates the given transaction, returning the amount of gas it used.
+async fn simulate(chain: &CosmosSdkChain, tx: Tx) -> Result<u64, Error> {
+    let mut client =
+        ibc_proto::cosmos::tx::v1beta1::service_client::ServiceClient::connect(chain.grpc_addr())
+            .await
+            .map_err(|e| chain.failover(Kind::Grpc.context(e)))?;
+
+    let request = tonic::Request::new(SimulateRequest { tx: Some(tx) });
+
+    let response = client
+        .simulate(request)
+        .await
+        .map_err(|e| Kind::Grpc.context(e))?;
+
+    let gas_info = response
+        .into_inner()
+        .gas_info
+        .ok_or_else(|| Kind::Grpc.context("missing gas info in simulation response"))?;
+
+    Ok(gas_info.gas_used)
+}
+
+/// The events of a committed transaction found with `tx_search`, or a `ChainError`
+/// if it failed.
+fn tx_search_result_to_event(chain_id: &ChainId, result: ResultTx) -> Vec<IbcEvent> {
+    if result.tx_result.code.is_err() {
+        return vec![IbcEvent::ChainError(format!(
+            "deliver_tx reports error: log={:?}",
+            result.tx_result.log
+        ))];
+    }
+
+    let height = ICSHeight::new(chain_id.version(), u64::from(result.height));
+
+    result
+        .tx_result
+        .events
+        .iter()
+        .filter_map(|event| from_tx_response_event(height, event))
+        .collect()
+}
+
+/// The body of a transaction with the given messages.
+fn tx_body(proto_msgs: Vec<Any>) -> TxBody {
+    TxBody {
+        messages: proto_msgs,
+        memo: "".to_string(),
+        timeout_height: 0_u64,
+        extension_options: Vec::<Any>::new(),
+        non_critical_extension_options: Vec::<Any>::new(),
+    }
+}
+
 pub fn tx_result_to_event(
     chain_id: &ChainId,
     response: Response,
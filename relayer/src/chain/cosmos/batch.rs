@@ -0,0 +1,160 @@
+//! Splitting of the messages sent to a chain into transactions which fit its configured limits.
+
+use prost_types::Any;
+
+use ibc::ics02_client::msgs::update_client;
+
+/// Splits `msgs` into batches of at most `max_msg_num` messages, whose transaction does not
+/// exceed `max_tx_size` bytes once encoded, given that everything but the messages takes up to
+/// `tx_envelope_len` bytes of it. The order of the messages is preserved. A message which alone
+/// exceeds the maximum size is placed in a batch of its own.
+///
+/// When the first message is a client update, the proofs in the other messages are verified
+/// against the consensus state it installs, so the client update is prepended to every batch,
+/// taking one of its `max_msg_num` slots. If a batch holds a single message, the client update
+/// is sent in the first batch on its own instead.
+pub fn batch_messages(
+    mut msgs: Vec<Any>,
+    max_msg_num: usize,
+    max_tx_size: usize,
+    tx_envelope_len: usize,
+) -> Vec<Vec<Any>> {
+    let max_msgs_size = max_tx_size.saturating_sub(tx_envelope_len);
+
+    let client_update = match msgs.first() {
+        Some(msg)
+            if msgs.len() > 1 && max_msg_num > 1 && msg.type_url == update_client::TYPE_URL =>
+        {
+            Some(msgs.remove(0))
+        }
+        _ => None,
+    };
+
+    let (base_num, base_size) = client_update
+        .as_ref()
+        .map_or((0, 0), |msg| (1, encoded_len(msg)));
+    let new_batch = || client_update.iter().cloned().collect::<Vec<_>>();
+
+    let mut batches = vec![];
+    let mut batch = new_batch();
+    let mut size = base_size;
+
+    for msg in msgs {
+        let msg_size = encoded_len(&msg);
+
+        if batch.len() > base_num && (batch.len() >= max_msg_num || size + msg_size > max_msgs_size)
+        {
+            batches.push(std::mem::replace(&mut batch, new_batch()));
+            size = base_size;
+        }
+
+        size += msg_size;
+        batch.push(msg);
+    }
+
+    if batch.len() > base_num {
+        batches.push(batch);
+    }
+
+    batches
+}
+
+/// The length of the encoding of `msg` as an element of the `messages` field of a `TxBody`.
+fn encoded_len(msg: &Any) -> usize {
+    prost::encoding::message::encoded_len(1, msg)
+}
+
+#[cfg(test)]
+mod tests {
+    use prost_types::Any;
+
+    use ibc::ics02_client::msgs::update_client;
+
+    use super::{batch_messages, encoded_len};
+
+    fn msg(type_url: &str, size: usize) -> Any {
+        Any {
+            type_url: type_url.to_string(),
+            value: vec![0; size],
+        }
+    }
+
+    fn packet(id: u8) -> Any {
+        Any {
+            type_url: "/ibc.core.channel.v1.MsgRecvPacket".to_string(),
+            value: vec![id; 10],
+        }
+    }
+
+    #[test]
+    fn split_by_message_number() {
+        let msgs: Vec<Any> = (0..5).map(packet).collect();
+
+        let batches = batch_messages(msgs.clone(), 2, usize::MAX, 0);
+        assert_eq!(
+            batches,
+            vec![msgs[0..2].to_vec(), msgs[2..4].to_vec(), msgs[4..].to_vec()]
+        );
+    }
+
+    #[test]
+    fn split_by_tx_size() {
+        let msgs: Vec<Any> = (0..3).map(packet).collect();
+        let max_tx_size = 2 * encoded_len(&msgs[0]);
+
+        let batches = batch_messages(msgs.clone(), 30, max_tx_size, 0);
+        assert_eq!(batches, vec![msgs[0..2].to_vec(), msgs[2..].to_vec()]);
+
+        // A message exceeding the maximum size is sent on its own.
+        let large = msg("/ibc.core.channel.v1.MsgRecvPacket", max_tx_size);
+        let batches = batch_messages(vec![large.clone(), msgs[0].clone()], 30, max_tx_size, 0);
+        assert_eq!(batches, vec![vec![large], vec![msgs[0].clone()]]);
+    }
+
+    #[test]
+    fn split_with_tx_envelope() {
+        let msgs: Vec<Any> = (0..2).map(packet).collect();
+        let max_tx_size = 2 * encoded_len(&msgs[0]);
+
+        // The messages no longer fit in one transaction along with its envelope.
+        let batches = batch_messages(msgs.clone(), 30, max_tx_size, 1);
+        assert_eq!(batches, vec![msgs[0..1].to_vec(), msgs[1..].to_vec()]);
+    }
+
+    #[test]
+    fn client_update_prepended_to_every_batch() {
+        let update = msg(update_client::TYPE_URL, 20);
+        let packets: Vec<Any> = (0..3).map(packet).collect();
+
+        let mut msgs = vec![update.clone()];
+        msgs.extend(packets.clone());
+
+        let batches = batch_messages(msgs, 3, usize::MAX, 0);
+        assert_eq!(
+            batches,
+            vec![
+                vec![update.clone(), packets[0].clone(), packets[1].clone()],
+                vec![update, packets[2].clone()],
+            ]
+        );
+    }
+
+    #[test]
+    fn client_update_sent_alone_with_single_message_batches() {
+        let update = msg(update_client::TYPE_URL, 20);
+        let packets: Vec<Any> = (0..2).map(packet).collect();
+
+        let mut msgs = vec![update.clone()];
+        msgs.extend(packets.clone());
+
+        let batches = batch_messages(msgs, 1, usize::MAX, 0);
+        assert_eq!(
+            batches,
+            vec![
+                vec![update],
+                vec![packets[0].clone()],
+                vec![packets[1].clone()]
+            ]
+        );
+    }
+}
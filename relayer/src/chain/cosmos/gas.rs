@@ -0,0 +1,59 @@
+//! Computation of the gas limit and fee of a transaction from the gas used when simulating it.
+//!
+//! The multipliers from the configuration are converted to fixed-point numbers (in parts per
+//! million) before being applied, so that exact products are not rounded up by floating-point
+//! errors (e.g., `100_000 * 1.1` is `110_000.00000000001` as an `f64`).
+
+/// The scale of the fixed-point multipliers: one unit is a millionth.
+const PPM: u128 = 1_000_000;
+
+/// Multiplies the `gas_used` during the simulation of a transaction by `gas_adjustment`,
+/// to account for the inaccuracy of the simulation, without exceeding `max_gas`.
+pub fn adjusted_gas(gas_used: u64, gas_adjustment: f64, max_gas: u64) -> u64 {
+    let gas = mul_ceil(gas_used, gas_adjustment);
+
+    if gas >= max_gas as u128 {
+        max_gas
+    } else {
+        gas as u64
+    }
+}
+
+/// The amount of fees to pay for `gas` units of gas at the given `gas_price`, rounded up.
+pub fn fee_amount(gas: u64, gas_price: f64) -> u64 {
+    let amount = mul_ceil(gas, gas_price);
+
+    if amount >= u64::MAX as u128 {
+        u64::MAX
+    } else {
+        amount as u64
+    }
+}
+
+/// Multiplies `value` by `multiplier`, rounded to the nearest millionth, and rounds the product up.
+fn mul_ceil(value: u64, multiplier: f64) -> u128 {
+    let multiplier_ppm = (multiplier * PPM as f64).round().max(0.0) as u128;
+
+    (value as u128 * multiplier_ppm + PPM - 1) / PPM
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{adjusted_gas, fee_amount};
+
+    #[test]
+    fn gas_is_adjusted_and_capped() {
+        assert_eq!(adjusted_gas(100_000, 1.1, 300_000), 110_000);
+        assert_eq!(adjusted_gas(100_001, 1.0, 300_000), 100_001);
+        assert_eq!(adjusted_gas(100_001, 1.1, 300_000), 110_002);
+        assert_eq!(adjusted_gas(290_000, 1.1, 300_000), 300_000);
+    }
+
+    #[test]
+    fn fee_is_rounded_up() {
+        assert_eq!(fee_amount(110_000, 0.025), 2750);
+        assert_eq!(fee_amount(110_001, 0.025), 2751);
+        assert_eq!(fee_amount(110_000, 0.0), 0);
+        assert_eq!(fee_amount(300_000, 0.1), 30_000);
+    }
+}
@@ -0,0 +1,45 @@
+//! Recovery from the account sequence mismatches reported by the ante handler of Cosmos SDK chains.
+
+/// The prefix of the log of a transaction rejected because of a wrong account sequence,
+/// which is followed by the expected sequence.
+const SEQUENCE_MISMATCH: &str = "account sequence mismatch, expected ";
+
+/// Parses the expected account sequence from the `log` of a transaction rejected because it
+/// was signed with a wrong sequence, eg. `account sequence mismatch, expected 12, got 11`.
+/// Returns `None` if the transaction was rejected for another reason.
+pub fn expected_sequence(log: &str) -> Option<u64> {
+    let start = log.find(SEQUENCE_MISMATCH)? + SEQUENCE_MISMATCH.len();
+
+    let digits: String = log[start..]
+        .chars()
+        .take_while(|c| c.is_ascii_digit())
+        .collect();
+
+    digits.parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::expected_sequence;
+
+    #[test]
+    fn parse_expected_sequence() {
+        assert_eq!(
+            expected_sequence(
+                "account sequence mismatch, expected 12, got 11: incorrect account sequence"
+            ),
+            Some(12)
+        );
+        assert_eq!(
+            expected_sequence(
+                "signature verification failed; account sequence mismatch, expected 7, got 9"
+            ),
+            Some(7)
+        );
+        assert_eq!(expected_sequence("insufficient fees; got: 10stake"), None);
+        assert_eq!(
+            expected_sequence("account sequence mismatch, expected x"),
+            None
+        );
+    }
+}
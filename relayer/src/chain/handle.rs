@@ -6,7 +6,7 @@ use dyn_clone::DynClone;
 use serde::{Serialize, Serializer};
 
 use ibc::ics02_client::client_consensus::{AnyConsensusState, AnyConsensusStateWithHeight};
-use ibc::ics02_client::client_state::AnyClientState;
+use ibc::ics02_client::client_state::{AnyClientState, IdentifiedAnyClientState};
 use ibc::ics02_client::events::UpdateClient;
 use ibc::ics02_client::misbehaviour::AnyMisbehaviour;
 use ibc::ics04_channel::channel::IdentifiedChannelEnd;
@@ -26,15 +26,19 @@ use ibc::{
     Height,
 };
 use ibc_proto::ibc::core::channel::v1::{
-    PacketState, QueryChannelsRequest, QueryNextSequenceReceiveRequest,
-    QueryPacketAcknowledgementsRequest, QueryPacketCommitmentsRequest, QueryUnreceivedAcksRequest,
-    QueryUnreceivedPacketsRequest,
+    PacketState, QueryChannelsRequest, QueryConnectionChannelsRequest,
+    QueryNextSequenceReceiveRequest, QueryPacketAcknowledgementsRequest,
+    QueryPacketCommitmentsRequest, QueryUnreceivedAcksRequest, QueryUnreceivedPacketsRequest,
 };
-use ibc_proto::ibc::core::client::v1::QueryConsensusStatesRequest;
+use ibc_proto::ibc::core::client::v1::{QueryClientStatesRequest, QueryConsensusStatesRequest};
 use ibc_proto::ibc::core::commitment::v1::MerkleProof;
+use ibc_proto::ibc::core::connection::v1::{
+    QueryClientConnectionsRequest, QueryConnectionsRequest,
+};
 pub use prod::ProdChainHandle;
 
 use crate::{
+    chain::{TxStatus, UpgradePlan},
     connection::ConnectionMsgType,
     error::Error,
     event::monitor::{EventBatch, Result as MonitorResult},
@@ -69,6 +73,11 @@ pub enum ChainRequest {
         reply_to: ReplyTo<Vec<IbcEvent>>,
     },
 
+    SubmitMsgs {
+        proto_msgs: Vec<prost_types::Any>,
+        reply_to: ReplyTo<Vec<TxStatus>>,
+    },
+
     Signer {
         reply_to: ReplyTo<Signer>,
     },
@@ -119,6 +128,11 @@ pub enum ChainRequest {
         reply_to: ReplyTo<(Option<AnyClientState>, Proofs)>,
     },
 
+    QueryClients {
+        request: QueryClientStatesRequest,
+        reply_to: ReplyTo<Vec<IdentifiedAnyClientState>>,
+    },
+
     QueryClientState {
         client_id: ClientId,
         height: Height,
@@ -147,6 +161,10 @@ pub enum ChainRequest {
         reply_to: ReplyTo<(AnyConsensusState, MerkleProof)>,
     },
 
+    QueryUpgradePlan {
+        reply_to: ReplyTo<Option<UpgradePlan>>,
+    },
+
     QueryCommitmentPrefix {
         reply_to: ReplyTo<CommitmentPrefix>,
     },
@@ -161,6 +179,21 @@ pub enum ChainRequest {
         reply_to: ReplyTo<ConnectionEnd>,
     },
 
+    QueryConnections {
+        request: QueryConnectionsRequest,
+        reply_to: ReplyTo<Vec<ConnectionId>>,
+    },
+
+    QueryClientConnections {
+        request: QueryClientConnectionsRequest,
+        reply_to: ReplyTo<Vec<ConnectionId>>,
+    },
+
+    QueryConnectionChannels {
+        request: QueryConnectionChannelsRequest,
+        reply_to: ReplyTo<Vec<ChannelId>>,
+    },
+
     QueryChannels {
         request: QueryChannelsRequest,
         reply_to: ReplyTo<Vec<IdentifiedChannelEnd>>,
@@ -247,9 +280,16 @@ pub trait ChainHandle: DynClone + Send + Sync + Debug {
 
     fn subscribe(&self) -> Result<Subscription, Error>;
 
+    /// Terminate the runtime of the chain, returning once the chain it runs is dropped.
+    fn shutdown(&self) -> Result<(), Error>;
+
     /// Send a transaction with `msgs` to chain.
     fn send_msgs(&self, proto_msgs: Vec<prost_types::Any>) -> Result<Vec<IbcEvent>, Error>;
 
+    /// Submit a transaction with `msgs` to chain, without waiting for its inclusion
+    /// in a block if the chain supports it.
+    fn submit_msgs(&self, proto_msgs: Vec<prost_types::Any>) -> Result<Vec<TxStatus>, Error>;
+
     fn get_signer(&self) -> Result<Signer, Error>;
 
     fn get_key(&self) -> Result<KeyEntry, Error>;
@@ -258,6 +298,11 @@ pub trait ChainHandle: DynClone + Send + Sync + Debug {
 
     fn query_latest_height(&self) -> Result<Height, Error>;
 
+    fn query_clients(
+        &self,
+        request: QueryClientStatesRequest,
+    ) -> Result<Vec<IdentifiedAnyClientState>, Error>;
+
     fn query_client_state(
         &self,
         client_id: &ClientId,
@@ -286,6 +331,9 @@ pub trait ChainHandle: DynClone + Send + Sync + Debug {
         height: Height,
     ) -> Result<(AnyConsensusState, MerkleProof), Error>;
 
+    /// Query the upgrade of the chain currently scheduled, if any.
+    fn query_upgrade_plan(&self) -> Result<Option<UpgradePlan>, Error>;
+
     fn query_commitment_prefix(&self) -> Result<CommitmentPrefix, Error>;
 
     fn query_compatible_versions(&self) -> Result<Vec<Version>, Error>;
@@ -296,6 +344,21 @@ pub trait ChainHandle: DynClone + Send + Sync + Debug {
         height: Height,
     ) -> Result<ConnectionEnd, Error>;
 
+    fn query_connections(
+        &self,
+        request: QueryConnectionsRequest,
+    ) -> Result<Vec<ConnectionId>, Error>;
+
+    fn query_client_connections(
+        &self,
+        request: QueryClientConnectionsRequest,
+    ) -> Result<Vec<ConnectionId>, Error>;
+
+    fn query_connection_channels(
+        &self,
+        request: QueryConnectionChannelsRequest,
+    ) -> Result<Vec<ChannelId>, Error>;
+
     fn query_next_sequence_receive(
         &self,
         request: QueryNextSequenceReceiveRequest,
@@ -3,7 +3,7 @@ use std::fmt::Debug;
 use crossbeam_channel as channel;
 
 use ibc::ics02_client::client_consensus::{AnyConsensusState, AnyConsensusStateWithHeight};
-use ibc::ics02_client::client_state::AnyClientState;
+use ibc::ics02_client::client_state::{AnyClientState, IdentifiedAnyClientState};
 use ibc::ics02_client::events::UpdateClient;
 use ibc::ics02_client::misbehaviour::AnyMisbehaviour;
 use ibc::ics04_channel::channel::IdentifiedChannelEnd;
@@ -24,14 +24,18 @@ use ibc::{
     Height,
 };
 use ibc_proto::ibc::core::channel::v1::{
-    PacketState, QueryChannelsRequest, QueryNextSequenceReceiveRequest,
-    QueryPacketAcknowledgementsRequest, QueryPacketCommitmentsRequest, QueryUnreceivedAcksRequest,
-    QueryUnreceivedPacketsRequest,
+    PacketState, QueryChannelsRequest, QueryConnectionChannelsRequest,
+    QueryNextSequenceReceiveRequest, QueryPacketAcknowledgementsRequest,
+    QueryPacketCommitmentsRequest, QueryUnreceivedAcksRequest, QueryUnreceivedPacketsRequest,
 };
-use ibc_proto::ibc::core::client::v1::QueryConsensusStatesRequest;
+use ibc_proto::ibc::core::client::v1::{QueryClientStatesRequest, QueryConsensusStatesRequest};
 use ibc_proto::ibc::core::commitment::v1::MerkleProof;
+use ibc_proto::ibc::core::connection::v1::{
+    QueryClientConnectionsRequest, QueryConnectionsRequest,
+};
 
 use crate::{
+    chain::{TxStatus, UpgradePlan},
     connection::ConnectionMsgType,
     error::{Error, Kind},
     keyring::KeyEntry,
@@ -81,6 +85,10 @@ impl ChainHandle for ProdChainHandle {
         self.send(|reply_to| ChainRequest::Subscribe { reply_to })
     }
 
+    fn shutdown(&self) -> Result<(), Error> {
+        self.send(|reply_to| ChainRequest::Terminate { reply_to })
+    }
+
     fn send_msgs(&self, proto_msgs: Vec<prost_types::Any>) -> Result<Vec<IbcEvent>, Error> {
         self.send(|reply_to| ChainRequest::SendMsgs {
             proto_msgs,
@@ -88,6 +96,13 @@ impl ChainHandle for ProdChainHandle {
         })
     }
 
+    fn submit_msgs(&self, proto_msgs: Vec<prost_types::Any>) -> Result<Vec<TxStatus>, Error> {
+        self.send(|reply_to| ChainRequest::SubmitMsgs {
+            proto_msgs,
+            reply_to,
+        })
+    }
+
     fn get_signer(&self) -> Result<Signer, Error> {
         self.send(|reply_to| ChainRequest::Signer { reply_to })
     }
@@ -107,6 +122,13 @@ impl ChainHandle for ProdChainHandle {
         self.send(|reply_to| ChainRequest::QueryLatestHeight { reply_to })
     }
 
+    fn query_clients(
+        &self,
+        request: QueryClientStatesRequest,
+    ) -> Result<Vec<IdentifiedAnyClientState>, Error> {
+        self.send(|reply_to| ChainRequest::QueryClients { request, reply_to })
+    }
+
     fn query_client_state(
         &self,
         client_id: &ClientId,
@@ -154,6 +176,10 @@ impl ChainHandle for ProdChainHandle {
         self.send(|reply_to| ChainRequest::QueryUpgradedConsensusState { height, reply_to })
     }
 
+    fn query_upgrade_plan(&self) -> Result<Option<UpgradePlan>, Error> {
+        self.send(|reply_to| ChainRequest::QueryUpgradePlan { reply_to })
+    }
+
     fn query_commitment_prefix(&self) -> Result<CommitmentPrefix, Error> {
         self.send(|reply_to| ChainRequest::QueryCommitmentPrefix { reply_to })
     }
@@ -174,6 +200,27 @@ impl ChainHandle for ProdChainHandle {
         })
     }
 
+    fn query_connections(
+        &self,
+        request: QueryConnectionsRequest,
+    ) -> Result<Vec<ConnectionId>, Error> {
+        self.send(|reply_to| ChainRequest::QueryConnections { request, reply_to })
+    }
+
+    fn query_client_connections(
+        &self,
+        request: QueryClientConnectionsRequest,
+    ) -> Result<Vec<ConnectionId>, Error> {
+        self.send(|reply_to| ChainRequest::QueryClientConnections { request, reply_to })
+    }
+
+    fn query_connection_channels(
+        &self,
+        request: QueryConnectionChannelsRequest,
+    ) -> Result<Vec<ChannelId>, Error> {
+        self.send(|reply_to| ChainRequest::QueryConnectionChannels { request, reply_to })
+    }
+
     fn query_next_sequence_receive(
         &self,
         request: QueryNextSequenceReceiveRequest,
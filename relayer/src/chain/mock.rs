@@ -1,27 +1,31 @@
-use std::ops::Add;
-use std::sync::Arc;
+use std::str::FromStr;
+use std::sync::{Arc, RwLock, RwLockReadGuard};
 use std::thread;
-use std::time::Duration;
 
 use crossbeam_channel as channel;
 use prost_types::Any;
-use tendermint_testgen::light_block::TmLightBlock;
+use tendermint::abci::transaction::Hash as TxHash;
 use tokio::runtime::Runtime;
 
+use ibc::application::ics20_fungible_token_transfer::coin::Coin;
 use ibc::downcast;
-use ibc::events::IbcEvent;
+use ibc::events::{IbcEvent, IbcEventType};
 use ibc::ics02_client::client_consensus::{AnyConsensusState, AnyConsensusStateWithHeight};
 use ibc::ics02_client::client_state::{AnyClientState, IdentifiedAnyClientState};
+use ibc::ics02_client::context::ClientReader;
+use ibc::ics02_client::events::NewBlock;
 use ibc::ics03_connection::connection::ConnectionEnd;
-use ibc::ics04_channel::channel::{ChannelEnd, IdentifiedChannelEnd};
+use ibc::ics03_connection::context::ConnectionReader;
+use ibc::ics04_channel::channel::{ChannelEnd, IdentifiedChannelEnd, Order};
+use ibc::ics04_channel::context::ChannelReader;
 use ibc::ics04_channel::packet::{PacketMsgType, Sequence};
-use ibc::ics07_tendermint::client_state::{AllowUpdate, ClientState as TendermintClientState};
-use ibc::ics07_tendermint::consensus_state::ConsensusState as TendermintConsensusState;
-use ibc::ics07_tendermint::header::Header as TendermintHeader;
 use ibc::ics18_relayer::context::Ics18Context;
 use ibc::ics23_commitment::commitment::CommitmentPrefix;
 use ibc::ics24_host::identifier::{ChainId, ChannelId, ClientId, ConnectionId, PortId};
+use ibc::ics26_routing::handler::deliver;
+use ibc::mock::client_state::{MockClientState, MockConsensusState};
 use ibc::mock::context::MockContext;
+use ibc::mock::header::MockHeader;
 use ibc::mock::host::HostType;
 use ibc::query::QueryTxRequest;
 use ibc::signer::Signer;
@@ -37,38 +41,141 @@ use ibc_proto::ibc::core::commitment::v1::MerkleProof;
 use ibc_proto::ibc::core::connection::v1::{
     QueryClientConnectionsRequest, QueryConnectionsRequest,
 };
+use ibc_proto::ics23::CommitmentProof;
 
-use crate::chain::Chain;
-use crate::config::ChainConfig;
+use crate::chain::{Chain, TxStatus, UpgradePlan};
+use crate::config::{BroadcastMode, ChainConfig};
 use crate::error::{Error, Kind};
-use crate::event::monitor::EventReceiver;
-use crate::keyring::{KeyEntry, KeyRing};
+use crate::event::monitor::{EventBatch, EventReceiver, Result as MonitorResult};
+use crate::keyring::{KeyEntry, KeyRing, Store};
 use crate::light_client::{mock::LightClient as MockLightClient, LightClient};
 
+/// The port bound by the mock chains, on which token transfers can be sent.
+pub const TRANSFER_PORT: &str = "transfer";
+
+/// The amount of tokens, in the fee denomination, held by the relayer account of a mock chain.
+const RELAYER_BALANCE: u64 = 1_000_000_000;
+
 /// The representation of a mocked chain as the relayer sees it.
-/// The relayer runtime and the light client will engage with the MockChain to query/send tx; the
-/// primary interface for doing so is captured by `ICS18Context` which this struct can access via
-/// the `context` field.
+/// The relayer runtime and the light client will engage with the MockChain to query/send tx.
+/// The IBC state of the chain is held by a `MockContext`, to which the messages are delivered
+/// through the ICS26 router, as a real chain would do. Every transaction is included in a block
+/// of its own, whose events are published to the in-process event monitor of the chain.
 pub struct MockChain {
     config: ChainConfig,
-    context: MockContext,
+    context: RwLock<MockContext>,
+    keybase: KeyRing,
+    /// The events of all the transactions included in the chain, searched by `query_txs`.
+    tx_events: Vec<IbcEvent>,
+    event_sender: channel::Sender<MonitorResult<EventBatch>>,
+    event_receiver: EventReceiver,
+}
+
+impl MockChain {
+    fn context(&self) -> RwLockReadGuard<'_, MockContext> {
+        self.context.read().unwrap()
+    }
+
+    /// Appends a block including the transaction `events` to the chain, and publishes them
+    /// to the event monitor. Returns the events, set at the height of the new block.
+    fn commit_block(&self, context: &mut MockContext, mut events: Vec<IbcEvent>) -> Vec<IbcEvent> {
+        context.advance_host_chain_height();
+        let height = context.query_latest_height();
+
+        for event in events.iter_mut() {
+            event.set_height(height);
+        }
+
+        let mut batch_events = vec![IbcEvent::NewBlock(NewBlock::new(height))];
+        batch_events.extend(events.iter().cloned());
+
+        // Nobody may be listening to the event monitor, which is fine.
+        let _ = self.event_sender.send(Ok(EventBatch {
+            chain_id: self.config.id.clone(),
+            height,
+            events: batch_events,
+        }));
+
+        events
+    }
+
+    /// Returns a proof of some state of the chain at `height`.
+    ///
+    /// As on Tendermint chains, the state at height `h` is proven against the header of the
+    /// block at `h + 1`, which relayers wait for before updating the counterparty client.
+    /// A mock chain only produces a block per transaction, so it commits an empty block when
+    /// a proof is requested at its latest height. Mock clients do not verify the proofs.
+    fn proof_at(&self, height: Height) -> MerkleProof {
+        let mut context = self.context.write().unwrap();
+        if height >= context.query_latest_height() {
+            self.commit_block(&mut context, vec![]);
+        }
+
+        MerkleProof {
+            proofs: vec![CommitmentProof::default()],
+        }
+    }
+
+    /// Returns the header of the first block of the chain upgraded at `height`, if a plan
+    /// committing to the upgraded client state was scheduled at that height.
+    fn upgraded_header(&self, height: Height) -> Result<MockHeader, Error> {
+        match test_utils::upgrade_plan(&self.config.id) {
+            Some(plan) if plan.height == height && plan.has_upgraded_client_state => {
+                Ok(MockHeader::new(Height::new(height.revision_number + 1, 1)))
+            }
+            _ => Err(Kind::EmptyUpgradedClientState.into()),
+        }
+    }
+
+    fn packet_states(
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        packets: Vec<(Sequence, String)>,
+    ) -> Vec<PacketState> {
+        packets
+            .into_iter()
+            .map(|(sequence, data)| PacketState {
+                port_id: port_id.to_string(),
+                channel_id: channel_id.to_string(),
+                sequence: sequence.into(),
+                data: data.into_bytes(),
+            })
+            .collect()
+    }
 }
 
 impl Chain for MockChain {
-    type LightBlock = TmLightBlock;
-    type Header = TendermintHeader;
-    type ConsensusState = TendermintConsensusState;
-    type ClientState = TendermintClientState;
+    type LightBlock = MockHeader;
+    type Header = MockHeader;
+    type ConsensusState = MockConsensusState;
+    type ClientState = MockClientState;
 
     fn bootstrap(config: ChainConfig, _rt: Arc<Runtime>) -> Result<Self, Error> {
+        let keybase =
+            KeyRing::new(Store::Memory, config.clone()).map_err(|e| Kind::KeyBase.context(e))?;
+
+        // The relayer account is funded, so that token transfers can be sent from it.
+        let mut context = MockContext::new(
+            config.id.clone(),
+            HostType::Mock,
+            50,
+            Height::new(config.id.version(), 20),
+        )
+        .with_balance(
+            get_dummy_account_id(),
+            Coin::new(config.fee_denom.clone(), RELAYER_BALANCE),
+        );
+        context.add_port(parse_id(TRANSFER_PORT)?);
+
+        let (event_sender, event_receiver) = channel::unbounded();
+
         Ok(MockChain {
-            config: config.clone(),
-            context: MockContext::new(
-                config.id.clone(),
-                HostType::SyntheticTendermint,
-                50,
-                Height::new(config.id.version(), 20),
-            ),
+            config,
+            context: RwLock::new(context),
+            keybase,
+            tx_events: vec![],
+            event_sender,
+            event_receiver,
         })
     }
 
@@ -80,304 +187,636 @@ impl Chain for MockChain {
         &self,
         _rt: Arc<Runtime>,
     ) -> Result<(EventReceiver, Option<thread::JoinHandle<()>>), Error> {
-        let (_, rx) = channel::unbounded();
-        Ok((rx, None))
+        Ok((self.event_receiver.clone(), None))
     }
 
     fn id(&self) -> &ChainId {
         &self.config.id
     }
 
+    fn config(&self) -> &ChainConfig {
+        &self.config
+    }
+
     fn keybase(&self) -> &KeyRing {
-        unimplemented!()
+        &self.keybase
     }
 
     fn keybase_mut(&mut self) -> &mut KeyRing {
-        unimplemented!()
+        &mut self.keybase
     }
 
+    /// Delivers the messages in a single transaction, included in a new block if it succeeds.
+    /// As with `CosmosSdkChain`, a failed transaction is reported by an `IbcEvent::ChainError`.
     fn send_msgs(&mut self, proto_msgs: Vec<Any>) -> Result<Vec<IbcEvent>, Error> {
-        // Use the ICS18Context interface to submit the set of messages.
-        let events = self
-            .context
-            .send(proto_msgs)
-            .map_err(|e| Kind::Rpc(self.config.rpc_addr.clone()).context(e))?;
+        if proto_msgs.is_empty() {
+            return Ok(vec![IbcEvent::Empty("No messages to send".to_string())]);
+        }
+
+        let mut context = self.context.write().unwrap();
 
+        // The context is only modified if all the messages are delivered successfully.
+        let events = match deliver(&mut *context, proto_msgs) {
+            Ok(events) => self.commit_block(&mut context, events),
+            Err(e) => return Ok(vec![IbcEvent::ChainError(e.to_string())]),
+        };
+        drop(context);
+
+        self.tx_events.extend(events.iter().cloned());
         Ok(events)
     }
 
+    /// In the `sync` broadcast mode, the messages are sent in transactions of at most
+    /// `max_msg_num` messages, which are reported as pending. Their outcome is available as
+    /// soon as they are included in a block, i.e. right away.
+    fn submit_msgs(&mut self, proto_msgs: Vec<Any>) -> Result<Vec<TxStatus>, Error> {
+        if self.config.broadcast_mode == BroadcastMode::Commit || proto_msgs.is_empty() {
+            let events = self.send_msgs(proto_msgs)?;
+            return Ok(vec![TxStatus::Completed { events }]);
+        }
+
+        let max_msg_num = self.config.max_msg_num.unwrap_or(proto_msgs.len()).max(1);
+
+        let mut statuses = vec![];
+        for msgs in proto_msgs.chunks(max_msg_num) {
+            let events = if test_utils::take_rejected_tx(&self.config.id) {
+                vec![IbcEvent::ChainError(
+                    "tx rejected after check_tx".to_string(),
+                )]
+            } else {
+                self.send_msgs(msgs.to_vec())?
+            };
+
+            let (sender, outcome) = channel::bounded(1);
+            sender.send(events).map_err(|e| Kind::Channel.context(e))?;
+
+            statuses.push(TxStatus::Pending {
+                hash: TxHash::new([0; 32]),
+                outcome,
+            });
+        }
+
+        Ok(statuses)
+    }
+
     fn get_signer(&mut self) -> Result<Signer, Error> {
         Ok(get_dummy_account_id())
     }
 
     fn get_key(&mut self) -> Result<KeyEntry, Error> {
-        unimplemented!()
+        self.keybase()
+            .get_key()
+            .map_err(|e| Kind::KeyBase.context(e).into())
     }
 
     fn query_commitment_prefix(&self) -> Result<CommitmentPrefix, Error> {
-        unimplemented!()
+        Ok(ConnectionReader::commitment_prefix(&*self.context()))
     }
 
     fn query_latest_height(&self) -> Result<Height, Error> {
-        Ok(self.context.query_latest_height())
+        Ok(self.context().query_latest_height())
     }
 
     fn query_clients(
         &self,
         _request: QueryClientStatesRequest,
     ) -> Result<Vec<IdentifiedAnyClientState>, Error> {
-        unimplemented!()
+        Ok(self.context().client_states())
     }
 
     fn query_client_state(
         &self,
         client_id: &ClientId,
         _height: Height,
-    ) -> Result<Self::ClientState, Error> {
-        // TODO: unclear what are the scenarios where we need to take height into account.
-        let any_state = self
-            .context
+    ) -> Result<AnyClientState, Error> {
+        // The mock context only keeps the latest state, so queries ignore the height.
+        let client_state = self
+            .context()
             .query_client_full_state(client_id)
             .ok_or(Kind::EmptyResponseValue)?;
-        let client_state = downcast!(any_state => AnyClientState::Tendermint).ok_or_else(|| {
-            Kind::Query("client state".into()).context("unexpected client state type")
-        })?;
         Ok(client_state)
     }
 
     fn query_upgraded_client_state(
         &self,
-        _height: Height,
+        height: Height,
     ) -> Result<(Self::ClientState, MerkleProof), Error> {
-        unimplemented!()
+        let header = self.upgraded_header(height)?;
+        let proof = MerkleProof {
+            proofs: vec![CommitmentProof::default()],
+        };
+
+        Ok((MockClientState::new(header), proof))
+    }
+
+    /// Returns the plan scheduled with `test_utils::schedule_upgrade` until the chain reaches
+    /// its height.
+    fn query_upgrade_plan(&self) -> Result<Option<UpgradePlan>, Error> {
+        let latest_height = self.context().query_latest_height();
+
+        Ok(test_utils::upgrade_plan(&self.config.id).filter(|plan| latest_height < plan.height))
     }
 
     fn query_connection(
         &self,
-        _connection_id: &ConnectionId,
+        connection_id: &ConnectionId,
         _height: Height,
     ) -> Result<ConnectionEnd, Error> {
-        unimplemented!()
+        let connection_end = ConnectionReader::connection_end(&*self.context(), connection_id)
+            .ok_or(Kind::EmptyResponseValue)?;
+        Ok(connection_end)
     }
 
     fn query_client_connections(
         &self,
-        _request: QueryClientConnectionsRequest,
+        request: QueryClientConnectionsRequest,
     ) -> Result<Vec<ConnectionId>, Error> {
-        unimplemented!()
+        let client_id: ClientId = parse_id(&request.client_id)?;
+        Ok(self
+            .context()
+            .client_connection(&client_id)
+            .into_iter()
+            .collect())
     }
 
     fn query_connections(
         &self,
         _request: QueryConnectionsRequest,
     ) -> Result<Vec<ConnectionId>, Error> {
-        unimplemented!()
+        Ok(self.context().connection_ids())
     }
 
     fn query_connection_channels(
         &self,
-        _request: QueryConnectionChannelsRequest,
+        request: QueryConnectionChannelsRequest,
     ) -> Result<Vec<ChannelId>, Error> {
-        unimplemented!()
+        let connection_id: ConnectionId = parse_id(&request.connection)?;
+        let channels = ChannelReader::connection_channels(&*self.context(), &connection_id)
+            .unwrap_or_default();
+        Ok(channels
+            .into_iter()
+            .map(|(_, channel_id)| channel_id)
+            .collect())
     }
 
     fn query_channels(
         &self,
         _request: QueryChannelsRequest,
     ) -> Result<Vec<IdentifiedChannelEnd>, Error> {
-        unimplemented!()
+        Ok(self.context().channels())
     }
 
     fn query_channel(
         &self,
-        _port_id: &PortId,
-        _channel_id: &ChannelId,
+        port_id: &PortId,
+        channel_id: &ChannelId,
         _height: Height,
     ) -> Result<ChannelEnd, Error> {
-        unimplemented!()
+        let channel_end = self
+            .context()
+            .channel_end(&(port_id.clone(), channel_id.clone()))
+            .ok_or(Kind::EmptyResponseValue)?;
+        Ok(channel_end)
     }
 
     fn query_packet_commitments(
         &self,
-        _request: QueryPacketCommitmentsRequest,
+        request: QueryPacketCommitmentsRequest,
     ) -> Result<(Vec<PacketState>, Height), Error> {
-        unimplemented!()
+        let port_id: PortId = parse_id(&request.port_id)?;
+        let channel_id: ChannelId = parse_id(&request.channel_id)?;
+
+        let context = self.context();
+        let commitments = context.packet_commitments(&port_id, &channel_id);
+
+        Ok((
+            Self::packet_states(&port_id, &channel_id, commitments),
+            context.query_latest_height(),
+        ))
     }
 
     fn query_unreceived_packets(
         &self,
-        _request: QueryUnreceivedPacketsRequest,
+        request: QueryUnreceivedPacketsRequest,
     ) -> Result<Vec<u64>, Error> {
-        unimplemented!()
+        let port_id: PortId = parse_id(&request.port_id)?;
+        let channel_id: ChannelId = parse_id(&request.channel_id)?;
+        let port_channel_id = (port_id, channel_id);
+
+        let context = self.context();
+        let channel_end = context
+            .channel_end(&port_channel_id)
+            .ok_or(Kind::EmptyResponseValue)?;
+
+        // Ordered channels receive the packets in sequence, unordered ones store receipts.
+        let received = |sequence: Sequence| match channel_end.ordering() {
+            Order::Ordered => context
+                .get_next_sequence_recv(&port_channel_id)
+                .map_or(false, |next| sequence < next),
+            _ => context
+                .get_packet_receipt(&(
+                    port_channel_id.0.clone(),
+                    port_channel_id.1.clone(),
+                    sequence,
+                ))
+                .is_some(),
+        };
+
+        Ok(request
+            .packet_commitment_sequences
+            .into_iter()
+            .filter(|sequence| !received(Sequence::from(*sequence)))
+            .collect())
     }
 
     fn query_packet_acknowledgements(
         &self,
-        _request: QueryPacketAcknowledgementsRequest,
+        request: QueryPacketAcknowledgementsRequest,
     ) -> Result<(Vec<PacketState>, Height), Error> {
-        unimplemented!()
+        let port_id: PortId = parse_id(&request.port_id)?;
+        let channel_id: ChannelId = parse_id(&request.channel_id)?;
+
+        let context = self.context();
+        let acks = context.packet_acknowledgements(&port_id, &channel_id);
+
+        Ok((
+            Self::packet_states(&port_id, &channel_id, acks),
+            context.query_latest_height(),
+        ))
     }
 
     fn query_unreceived_acknowledgements(
         &self,
-        _request: QueryUnreceivedAcksRequest,
+        request: QueryUnreceivedAcksRequest,
     ) -> Result<Vec<u64>, Error> {
-        unimplemented!()
+        let port_id: PortId = parse_id(&request.port_id)?;
+        let channel_id: ChannelId = parse_id(&request.channel_id)?;
+
+        // The commitment of a packet is deleted once its acknowledgement is received.
+        let context = self.context();
+        Ok(request
+            .packet_ack_sequences
+            .into_iter()
+            .filter(|sequence| {
+                context
+                    .get_packet_commitment(&(
+                        port_id.clone(),
+                        channel_id.clone(),
+                        Sequence::from(*sequence),
+                    ))
+                    .is_some()
+            })
+            .collect())
     }
 
     fn query_next_sequence_receive(
         &self,
-        _request: QueryNextSequenceReceiveRequest,
+        request: QueryNextSequenceReceiveRequest,
     ) -> Result<Sequence, Error> {
-        unimplemented!()
-    }
+        let port_id: PortId = parse_id(&request.port_id)?;
+        let channel_id: ChannelId = parse_id(&request.channel_id)?;
 
-    fn query_txs(&self, _request: QueryTxRequest) -> Result<Vec<IbcEvent>, Error> {
-        unimplemented!()
+        let sequence = self
+            .context()
+            .get_next_sequence_recv(&(port_id, channel_id))
+            .ok_or(Kind::EmptyResponseValue)?;
+        Ok(sequence)
+    }
+
+    fn query_txs(&self, request: QueryTxRequest) -> Result<Vec<IbcEvent>, Error> {
+        match request {
+            QueryTxRequest::Packet(request) => Ok(request
+                .sequences
+                .iter()
+                .filter_map(|seq| {
+                    self.tx_events.iter().find(|event| {
+                        let packet = match (event, &request.event_id) {
+                            (IbcEvent::SendPacket(ev), IbcEventType::SendPacket) => &ev.packet,
+                            (IbcEvent::WriteAcknowledgement(ev), IbcEventType::WriteAck) => {
+                                &ev.packet
+                            }
+                            _ => return false,
+                        };
+
+                        (request.height == Height::zero() || event.height() <= request.height)
+                            && packet.source_port == request.source_port_id
+                            && packet.source_channel == request.source_channel_id
+                            && packet.destination_port == request.destination_port_id
+                            && packet.destination_channel == request.destination_channel_id
+                            && packet.sequence == *seq
+                    })
+                })
+                .cloned()
+                .collect()),
+
+            QueryTxRequest::Client(request) => Ok(self
+                .tx_events
+                .iter()
+                .find(|event| match event {
+                    IbcEvent::UpdateClient(ev) => {
+                        ev.client_id() == &request.client_id
+                            && ev.consensus_height() == request.consensus_height
+                    }
+                    _ => false,
+                })
+                .cloned()
+                .into_iter()
+                .collect()),
+        }
     }
 
     fn proven_client_state(
         &self,
-        _client_id: &ClientId,
-        _height: Height,
-    ) -> Result<(Self::ClientState, MerkleProof), Error> {
-        unimplemented!()
+        client_id: &ClientId,
+        height: Height,
+    ) -> Result<(AnyClientState, MerkleProof), Error> {
+        let client_state = self.query_client_state(client_id, height)?;
+        Ok((client_state, self.proof_at(height)))
     }
 
     fn proven_connection(
         &self,
-        _connection_id: &ConnectionId,
-        _height: Height,
+        connection_id: &ConnectionId,
+        height: Height,
     ) -> Result<(ConnectionEnd, MerkleProof), Error> {
-        unimplemented!()
+        let connection_end = self.query_connection(connection_id, height)?;
+        Ok((connection_end, self.proof_at(height)))
     }
 
     fn proven_client_consensus(
         &self,
-        _client_id: &ClientId,
-        _consensus_height: Height,
-        _height: Height,
+        client_id: &ClientId,
+        consensus_height: Height,
+        height: Height,
     ) -> Result<(Self::ConsensusState, MerkleProof), Error> {
-        unimplemented!()
+        let consensus_state =
+            self.query_consensus_state(client_id.clone(), consensus_height, height)?;
+        let consensus_state = downcast!(consensus_state => AnyConsensusState::Mock)
+            .ok_or_else(|| Kind::Query("consensus state".into()).context("not a mock"))?;
+
+        Ok((consensus_state, self.proof_at(height)))
     }
 
     fn proven_channel(
         &self,
-        _port_id: &PortId,
-        _channel_id: &ChannelId,
-        _height: Height,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        height: Height,
     ) -> Result<(ChannelEnd, MerkleProof), Error> {
-        unimplemented!()
+        let channel_end = self.query_channel(port_id, channel_id, height)?;
+        Ok((channel_end, self.proof_at(height)))
     }
 
     fn proven_packet(
         &self,
-        _packet_type: PacketMsgType,
-        _port_id: PortId,
-        _channel_id: ChannelId,
-        _sequence: Sequence,
-        _height: Height,
+        packet_type: PacketMsgType,
+        port_id: PortId,
+        channel_id: ChannelId,
+        sequence: Sequence,
+        height: Height,
     ) -> Result<(Vec<u8>, MerkleProof), Error> {
-        unimplemented!()
+        let key = (port_id, channel_id, sequence);
+
+        let value = {
+            let context = self.context();
+            match packet_type {
+                PacketMsgType::Recv => context
+                    .get_packet_commitment(&key)
+                    .map(String::into_bytes)
+                    .ok_or(Kind::EmptyResponseValue)?,
+                PacketMsgType::Ack => context
+                    .get_packet_acknowledgement(&key)
+                    .map(String::into_bytes)
+                    .ok_or(Kind::EmptyResponseValue)?,
+                PacketMsgType::TimeoutOrdered => context
+                    .get_next_sequence_recv(&(key.0, key.1))
+                    .map(|next| u64::from(next).to_be_bytes().to_vec())
+                    .ok_or(Kind::EmptyResponseValue)?,
+                // Proofs of the absence of a receipt.
+                PacketMsgType::TimeoutUnordered | PacketMsgType::TimeoutOnClose => vec![],
+            }
+        };
+
+        Ok((value, self.proof_at(height)))
     }
 
     fn build_client_state(&self, height: Height) -> Result<Self::ClientState, Error> {
-        let client_state = TendermintClientState::new(
-            self.id().clone(),
-            self.config.trust_threshold,
-            self.config.trusting_period,
-            self.config.trusting_period.add(Duration::from_secs(1000)),
-            Duration::from_millis(3000),
-            height,
-            Height::zero(),
-            vec!["upgrade/upgradedClient".to_string()],
-            AllowUpdate {
-                after_expiry: false,
-                after_misbehaviour: false,
-            },
-        )
-        .map_err(|e| Kind::BuildClientStateFailure.context(e))?;
-
-        Ok(client_state)
+        Ok(MockClientState::new(MockHeader::new(height)))
     }
 
     fn build_consensus_state(
         &self,
         light_block: Self::LightBlock,
     ) -> Result<Self::ConsensusState, Error> {
-        Ok(Self::ConsensusState::from(light_block.signed_header.header))
+        Ok(MockConsensusState::new(light_block))
     }
 
     fn build_header(
         &self,
-        trusted_height: Height,
-        trusted_light_block: Self::LightBlock,
+        _trusted_height: Height,
+        _trusted_light_block: Self::LightBlock,
         target_light_block: Self::LightBlock,
     ) -> Result<Self::Header, Error> {
-        Ok(Self::Header {
-            signed_header: target_light_block.signed_header.clone(),
-            validator_set: target_light_block.validators,
-            trusted_height,
-            trusted_validator_set: trusted_light_block.validators,
-        })
+        Ok(target_light_block)
     }
 
     fn query_consensus_states(
         &self,
         request: QueryConsensusStatesRequest,
     ) -> Result<Vec<AnyConsensusStateWithHeight>, Error> {
-        Ok(self
-            .context
-            .consensus_states(&request.client_id.parse().unwrap()))
+        let client_id: ClientId = parse_id(&request.client_id)?;
+        Ok(self.context().consensus_states(&client_id))
     }
 
-    /// Performs a query to retrieve the identifiers of all connections.
     fn query_consensus_state(
         &self,
-        _client_id: ClientId,
-        _consensus_height: Height,
+        client_id: ClientId,
+        consensus_height: Height,
         _query_height: Height,
     ) -> Result<AnyConsensusState, Error> {
-        unimplemented!()
+        let consensus_state =
+            ClientReader::consensus_state(&*self.context(), &client_id, consensus_height)
+                .ok_or(Kind::EmptyResponseValue)?;
+        Ok(consensus_state)
     }
 
     fn query_upgraded_consensus_state(
         &self,
-        _height: Height,
+        height: Height,
     ) -> Result<(Self::ConsensusState, MerkleProof), Error> {
-        unimplemented!()
+        let header = self.upgraded_header(height)?;
+        let proof = MerkleProof {
+            proofs: vec![CommitmentProof::default()],
+        };
+
+        Ok((MockConsensusState::new(header), proof))
     }
 }
 
+fn parse_id<T>(id: &str) -> Result<T, Error>
+where
+    T: FromStr,
+    T::Err: std::error::Error + Send + Sync + 'static,
+{
+    id.parse().map_err(|e| {
+        Kind::Query(format!("invalid identifier '{}'", id))
+            .context(e)
+            .into()
+    })
+}
+
 // For integration tests with the modules
 #[cfg(test)]
 pub mod test_utils {
+    use std::collections::HashMap;
     use std::str::FromStr;
+    use std::sync::Mutex;
     use std::time::Duration;
 
-    use ibc::ics24_host::identifier::ChainId;
+    use once_cell::sync::Lazy;
+
+    use ibc::ics24_host::identifier::{ChainId, PortId};
+
+    use crate::chain::UpgradePlan;
+    use crate::config::{ChainConfig, ChainType};
+    use crate::keyring::Store;
+
+    /// The upgrades scheduled on each mock chain.
+    static UPGRADE_PLANS: Lazy<Mutex<HashMap<ChainId, UpgradePlan>>> = Lazy::new(Default::default);
+
+    /// The number of upcoming transactions that each mock chain rejects after `CheckTx`.
+    static REJECTED_TXS: Lazy<Mutex<HashMap<ChainId, usize>>> = Lazy::new(Default::default);
+
+    /// Makes the mock chain `chain_id` reject its next `count` transactions submitted in the
+    /// `sync` broadcast mode, as if they failed once included in a block.
+    pub fn reject_pending_txs(chain_id: &ChainId, count: usize) {
+        REJECTED_TXS.lock().unwrap().insert(chain_id.clone(), count);
+    }
+
+    /// Schedules an upgrade of the mock chain `chain_id`, which reports the `plan` until it
+    /// reaches the upgrade height.
+    pub fn schedule_upgrade(chain_id: &ChainId, plan: UpgradePlan) {
+        UPGRADE_PLANS.lock().unwrap().insert(chain_id.clone(), plan);
+    }
 
-    use crate::config::ChainConfig;
+    /// Returns the upgrade scheduled on the mock chain `chain_id`, if any.
+    pub(super) fn upgrade_plan(chain_id: &ChainId) -> Option<UpgradePlan> {
+        UPGRADE_PLANS.lock().unwrap().get(chain_id).cloned()
+    }
+
+    /// Returns `true` if the next transaction of the mock chain `chain_id` must be rejected.
+    pub(super) fn take_rejected_tx(chain_id: &ChainId) -> bool {
+        match REJECTED_TXS.lock().unwrap().get_mut(chain_id) {
+            Some(count) if *count > 0 => {
+                *count -= 1;
+                true
+            }
+            _ => false,
+        }
+    }
 
     /// Returns a very minimal chain configuration, to be used in initializing `MockChain`s.
     pub fn get_basic_chain_config(id: &str) -> ChainConfig {
         ChainConfig {
             id: ChainId::from_str(id).unwrap(),
+            chain_type: ChainType::new(ChainType::MOCK),
             rpc_addr: "http://127.0.0.1:26656".parse().unwrap(),
             grpc_addr: "http://127.0.0.1:9090".parse().unwrap(),
             websocket_addr: "ws://127.0.0.1:26656/websocket".parse().unwrap(),
             rpc_timeout: crate::config::default::rpc_timeout(),
             account_prefix: "".to_string(),
             key_name: "".to_string(),
+            key_type: Default::default(),
+            key_store_type: Store::Memory,
+            key_passphrase_file: None,
             store_prefix: "".to_string(),
             gas: None,
+            simulate_tx: false,
+            gas_adjustment: None,
+            gas_price: None,
             fee_denom: "stake".to_string(),
             fee_amount: Some(1000),
+            fee_granter: None,
+            fee_payer: None,
             max_msg_num: None,
+            broadcast_mode: Default::default(),
+            tx_confirmation_timeout: Duration::from_secs(60),
             max_tx_size: None,
             clock_drift: Duration::from_secs(5),
             trusting_period: Duration::from_secs(14 * 24 * 60 * 60), // 14 days
             trust_threshold: Default::default(),
+            light_store_path: None,
+            light_store_pruning_window: crate::config::default::light_store_pruning_window(),
+            packet_filter: Default::default(),
+            port_versions: vec![(PortId::from_str("transfer").unwrap(), "ics20-1".to_string())]
+                .into_iter()
+                .collect(),
+            connection_versions: vec![],
+            backup_endpoints: vec![],
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+    use std::str::FromStr;
+    use std::sync::Arc;
+
+    use tokio::runtime::Runtime;
+
+    use ibc::ics03_connection::version::{get_compatible_versions, Version};
+    use ibc::ics04_channel::channel::{ChannelEnd, Counterparty, Order, State};
+    use ibc::ics24_host::identifier::{ChannelId, ConnectionId, PortId};
+    use ibc_proto::ibc::core::connection::v1::Version as RawVersion;
+
+    use crate::chain::Chain;
+
+    use super::test_utils::get_basic_chain_config;
+    use super::MockChain;
+
+    #[test]
+    fn versions_from_config() {
+        let rt = Arc::new(Runtime::new().unwrap());
+        let chain = MockChain::bootstrap(get_basic_chain_config("chain_a"), rt.clone()).unwrap();
+
+        let oracle = PortId::from_str("oracle").unwrap();
+        let transfer = PortId::from_str("transfer").unwrap();
+
+        assert_eq!(chain.query_module_version(&transfer).unwrap(), "ics20-1");
+        assert!(chain.query_module_version(&oracle).is_err());
+        assert_eq!(
+            chain.query_compatible_versions().unwrap(),
+            get_compatible_versions()
+        );
+
+        // The versions of the open channels on the port are not taken into account.
+        let channel_end = ChannelEnd::new(
+            State::Open,
+            Order::Unordered,
+            Counterparty::new(oracle.clone(), Some(ChannelId::default())),
+            vec![ConnectionId::default()],
+            "oracle-1".to_string(),
+        );
+        {
+            let mut context = chain.context.write().unwrap();
+            *context =
+                context
+                    .clone()
+                    .with_channel(oracle.clone(), ChannelId::default(), channel_end);
+        }
+
+        assert!(chain.query_module_version(&oracle).is_err());
+
+        let version = Version::try_from(RawVersion {
+            identifier: "2".to_string(),
+            features: vec!["ORDER_UNORDERED".to_string()],
+        })
+        .unwrap();
+        let mut config = get_basic_chain_config("chain_b");
+        config.connection_versions = vec![version.clone()];
+        let chain = MockChain::bootstrap(config, rt).unwrap();
+
+        assert_eq!(chain.query_compatible_versions().unwrap(), vec![version]);
+    }
+}
@@ -9,7 +9,7 @@ use ibc::{
     events::IbcEvent,
     ics02_client::{
         client_consensus::{AnyConsensusState, AnyConsensusStateWithHeight, ConsensusState},
-        client_state::{AnyClientState, ClientState},
+        client_state::{AnyClientState, ClientState, IdentifiedAnyClientState},
         events::UpdateClient,
         header::{AnyHeader, Header},
         misbehaviour::AnyMisbehaviour,
@@ -29,12 +29,13 @@ use ibc::{
 
 use ibc_proto::ibc::core::{
     channel::v1::{
-        PacketState, QueryChannelsRequest, QueryNextSequenceReceiveRequest,
-        QueryPacketAcknowledgementsRequest, QueryPacketCommitmentsRequest,
-        QueryUnreceivedAcksRequest, QueryUnreceivedPacketsRequest,
+        PacketState, QueryChannelsRequest, QueryConnectionChannelsRequest,
+        QueryNextSequenceReceiveRequest, QueryPacketAcknowledgementsRequest,
+        QueryPacketCommitmentsRequest, QueryUnreceivedAcksRequest, QueryUnreceivedPacketsRequest,
     },
-    client::v1::QueryConsensusStatesRequest,
+    client::v1::{QueryClientStatesRequest, QueryConsensusStatesRequest},
     commitment::v1::MerkleProof,
+    connection::v1::{QueryClientConnectionsRequest, QueryConnectionsRequest},
 };
 
 use crate::{
@@ -46,12 +47,12 @@ use crate::{
         monitor::{EventBatch, EventReceiver, Result as MonitorResult},
     },
     keyring::KeyEntry,
-    light_client::LightClient,
+    light_client::{disabled::LightClient as DisabledLightClient, LightClient},
 };
 
 use super::{
     handle::{ChainHandle, ChainRequest, ProdChainHandle, ReplyTo, Subscription},
-    Chain,
+    Chain, TxStatus, UpgradePlan,
 };
 
 pub struct Threads {
@@ -110,6 +111,28 @@ impl<C: Chain + Send + 'static> ChainRuntime<C> {
         Ok((handle, threads))
     }
 
+    /// Spawns a new runtime for a specific Chain implementation, without a light client nor
+    /// an event monitor. Meant for one-off queries and transactions: the subscriptions to the
+    /// runtime receive no events, and the headers, consensus states and misbehaviour evidence
+    /// cannot be built.
+    pub fn spawn_minimal(
+        config: ChainConfig,
+        rt: Arc<TokioRuntime>,
+    ) -> Result<(Box<dyn ChainHandle>, Threads), Error> {
+        let chain = C::bootstrap(config, rt.clone())?;
+
+        let light_client = Box::new(DisabledLightClient::new(chain.id().clone()));
+
+        let (handle, runtime_thread) = Self::init(chain, light_client, channel::never(), rt);
+
+        let threads = Threads {
+            chain_runtime: runtime_thread,
+            event_monitor: None,
+        };
+
+        Ok((handle, threads))
+    }
+
     /// Initializes a runtime for a given chain, and spawns the associated thread
     fn init(
         chain: C,
@@ -161,7 +184,7 @@ impl<C: Chain + Send + 'static> ChainRuntime<C> {
     }
 
     fn run(mut self) -> Result<(), Error> {
-        loop {
+        let terminate_reply_to = loop {
             channel::select! {
                 recv(self.event_receiver) -> event_batch => {
                     match event_batch {
@@ -176,8 +199,7 @@ impl<C: Chain + Send + 'static> ChainRuntime<C> {
                 recv(self.request_receiver) -> event => {
                     match event {
                         Ok(ChainRequest::Terminate { reply_to }) => {
-                            reply_to.send(Ok(())).map_err(|_| Kind::Channel)?;
-                            break;
+                            break reply_to;
                         }
 
                         Ok(ChainRequest::Subscribe { reply_to }) => {
@@ -188,6 +210,10 @@ impl<C: Chain + Send + 'static> ChainRuntime<C> {
                             self.send_msgs(proto_msgs, reply_to)?
                         },
 
+                        Ok(ChainRequest::SubmitMsgs { proto_msgs, reply_to }) => {
+                            self.submit_msgs(proto_msgs, reply_to)?
+                        },
+
                         Ok(ChainRequest::Signer { reply_to }) => {
                             self.get_signer(reply_to)?
                         }
@@ -228,6 +254,10 @@ impl<C: Chain + Send + 'static> ChainRuntime<C> {
                             self.query_latest_height(reply_to)?
                         }
 
+                        Ok(ChainRequest::QueryClients { request, reply_to }) => {
+                            self.query_clients(request, reply_to)?
+                        },
+
                         Ok(ChainRequest::QueryClientState { client_id, height, reply_to }) => {
                             self.query_client_state(client_id, height, reply_to)?
                         },
@@ -248,6 +278,10 @@ impl<C: Chain + Send + 'static> ChainRuntime<C> {
                             self.query_upgraded_consensus_state(height, reply_to)?
                         }
 
+                        Ok(ChainRequest::QueryUpgradePlan { reply_to }) => {
+                            self.query_upgrade_plan(reply_to)?
+                        },
+
                         Ok(ChainRequest::QueryCommitmentPrefix { reply_to }) => {
                             self.query_commitment_prefix(reply_to)?
                         },
@@ -260,6 +294,18 @@ impl<C: Chain + Send + 'static> ChainRuntime<C> {
                             self.query_connection(connection_id, height, reply_to)?
                         },
 
+                        Ok(ChainRequest::QueryConnections { request, reply_to }) => {
+                            self.query_connections(request, reply_to)?
+                        },
+
+                        Ok(ChainRequest::QueryClientConnections { request, reply_to }) => {
+                            self.query_client_connections(request, reply_to)?
+                        },
+
+                        Ok(ChainRequest::QueryConnectionChannels { request, reply_to }) => {
+                            self.query_connection_channels(request, reply_to)?
+                        },
+
                         Ok(ChainRequest::QueryChannels { request, reply_to }) => {
                             self.query_channels(request, reply_to)?
                         },
@@ -312,7 +358,14 @@ impl<C: Chain + Send + 'static> ChainRuntime<C> {
                     }
                 },
             }
-        }
+        };
+
+        // Only reply once the chain and its light client are dropped, so that the resources
+        // they hold, such as the light store, are released when the runtime is shut down.
+        drop(self);
+        terminate_reply_to
+            .send(Ok(()))
+            .map_err(|e| Kind::Channel.context(e))?;
 
         Ok(())
     }
@@ -341,6 +394,20 @@ impl<C: Chain + Send + 'static> ChainRuntime<C> {
         Ok(())
     }
 
+    fn submit_msgs(
+        &mut self,
+        proto_msgs: Vec<prost_types::Any>,
+        reply_to: ReplyTo<Vec<TxStatus>>,
+    ) -> Result<(), Error> {
+        let result = self.chain.submit_msgs(proto_msgs);
+
+        reply_to
+            .send(result)
+            .map_err(|e| Kind::Channel.context(e))?;
+
+        Ok(())
+    }
+
     fn query_latest_height(&self, reply_to: ReplyTo<Height>) -> Result<(), Error> {
         let latest_height = self.chain.query_latest_height();
 
@@ -375,7 +442,7 @@ impl<C: Chain + Send + 'static> ChainRuntime<C> {
         let result = self.chain.query_module_version(&port_id);
 
         reply_to
-            .send(Ok(result))
+            .send(result)
             .map_err(|e| Kind::Channel.context(e))?;
 
         Ok(())
@@ -491,8 +558,19 @@ impl<C: Chain + Send + 'static> ChainRuntime<C> {
             height,
         );
 
-        let result = result
-            .map(|(opt_client_state, proofs)| (opt_client_state.map(|cs| cs.wrap_any()), proofs));
+        reply_to
+            .send(result)
+            .map_err(|e| Kind::Channel.context(e))?;
+
+        Ok(())
+    }
+
+    fn query_clients(
+        &self,
+        request: QueryClientStatesRequest,
+        reply_to: ReplyTo<Vec<IdentifiedAnyClientState>>,
+    ) -> Result<(), Error> {
+        let result = self.chain.query_clients(request);
 
         reply_to
             .send(result)
@@ -507,10 +585,7 @@ impl<C: Chain + Send + 'static> ChainRuntime<C> {
         height: Height,
         reply_to: ReplyTo<AnyClientState>,
     ) -> Result<(), Error> {
-        let client_state = self
-            .chain
-            .query_client_state(&client_id, height)
-            .map(|cs| cs.wrap_any());
+        let client_state = self.chain.query_client_state(&client_id, height);
 
         reply_to
             .send(client_state)
@@ -585,6 +660,14 @@ impl<C: Chain + Send + 'static> ChainRuntime<C> {
         Ok(())
     }
 
+    fn query_upgrade_plan(&self, reply_to: ReplyTo<Option<UpgradePlan>>) -> Result<(), Error> {
+        let plan = self.chain.query_upgrade_plan();
+
+        reply_to.send(plan).map_err(|e| Kind::Channel.context(e))?;
+
+        Ok(())
+    }
+
     fn query_commitment_prefix(&self, reply_to: ReplyTo<CommitmentPrefix>) -> Result<(), Error> {
         let prefix = self.chain.query_commitment_prefix();
 
@@ -620,6 +703,48 @@ impl<C: Chain + Send + 'static> ChainRuntime<C> {
         Ok(())
     }
 
+    fn query_connections(
+        &self,
+        request: QueryConnectionsRequest,
+        reply_to: ReplyTo<Vec<ConnectionId>>,
+    ) -> Result<(), Error> {
+        let result = self.chain.query_connections(request);
+
+        reply_to
+            .send(result)
+            .map_err(|e| Kind::Channel.context(e))?;
+
+        Ok(())
+    }
+
+    fn query_client_connections(
+        &self,
+        request: QueryClientConnectionsRequest,
+        reply_to: ReplyTo<Vec<ConnectionId>>,
+    ) -> Result<(), Error> {
+        let result = self.chain.query_client_connections(request);
+
+        reply_to
+            .send(result)
+            .map_err(|e| Kind::Channel.context(e))?;
+
+        Ok(())
+    }
+
+    fn query_connection_channels(
+        &self,
+        request: QueryConnectionChannelsRequest,
+        reply_to: ReplyTo<Vec<ChannelId>>,
+    ) -> Result<(), Error> {
+        let result = self.chain.query_connection_channels(request);
+
+        reply_to
+            .send(result)
+            .map_err(|e| Kind::Channel.context(e))?;
+
+        Ok(())
+    }
+
     fn query_channels(
         &self,
         request: QueryChannelsRequest,
@@ -656,10 +781,7 @@ impl<C: Chain + Send + 'static> ChainRuntime<C> {
         height: Height,
         reply_to: ReplyTo<(AnyClientState, MerkleProof)>,
     ) -> Result<(), Error> {
-        let result = self
-            .chain
-            .proven_client_state(&client_id, height)
-            .map(|(cs, mp)| (cs.wrap_any(), mp));
+        let result = self.chain.proven_client_state(&client_id, height);
 
         reply_to
             .send(result)
@@ -5,6 +5,7 @@ use tracing::error;
 
 use ibc::events::IbcEvent;
 use ibc::ics04_channel::channel::{ChannelEnd, Counterparty, Order, State};
+use ibc::ics04_channel::events::Attributes as ChannelAttributes;
 use ibc::ics04_channel::msgs::chan_close_confirm::MsgChannelCloseConfirm;
 use ibc::ics04_channel::msgs::chan_close_init::MsgChannelCloseInit;
 use ibc::ics04_channel::msgs::chan_open_ack::MsgChannelOpenAck;
@@ -37,6 +38,12 @@ pub enum ChannelError {
         "failed during a transaction submission step to chain id {0} with underlying error: {1}"
     )]
     SubmitError(ChainId, Error),
+
+    #[error("the connection identifier on chain {0} is not known yet")]
+    MissingConnectionId(ChainId),
+
+    #[error("the channel identifier on chain {0} is not known yet")]
+    MissingChannelId(ChainId),
 }
 
 #[derive(Clone, Debug, Serialize)]
@@ -45,7 +52,7 @@ pub struct ChannelSide {
     client_id: ClientId,
     connection_id: ConnectionId,
     port_id: PortId,
-    channel_id: ChannelId,
+    channel_id: Option<ChannelId>,
 }
 
 impl ChannelSide {
@@ -54,7 +61,7 @@ impl ChannelSide {
         client_id: ClientId,
         connection_id: ConnectionId,
         port_id: PortId,
-        channel_id: ChannelId,
+        channel_id: Option<ChannelId>,
     ) -> ChannelSide {
         Self {
             chain,
@@ -81,8 +88,8 @@ impl ChannelSide {
         &self.port_id
     }
 
-    pub fn channel_id(&self) -> &ChannelId {
-        &self.channel_id
+    pub fn channel_id(&self) -> Option<&ChannelId> {
+        self.channel_id.as_ref()
     }
 }
 
@@ -105,28 +112,38 @@ impl Channel {
         b_port: PortId,
         version: Option<String>,
     ) -> Result<Self, ChannelError> {
-        let b_side_chain = connection.dst_chain().clone();
-        let version = version.unwrap_or(
-            b_side_chain
+        let a_side_chain = connection.src_chain().clone();
+        let version = match version {
+            Some(version) => version,
+            None => a_side_chain
                 .module_version(&a_port)
-                .map_err(|e| ChannelError::QueryError(b_side_chain.id(), e))?,
-        );
+                .map_err(|e| ChannelError::QueryError(a_side_chain.id(), e))?,
+        };
+
+        let a_connection_id = connection
+            .src_connection_id()
+            .cloned()
+            .ok_or_else(|| ChannelError::MissingConnectionId(connection.src_chain().id()))?;
+        let b_connection_id = connection
+            .dst_connection_id()
+            .cloned()
+            .ok_or_else(|| ChannelError::MissingConnectionId(connection.dst_chain().id()))?;
 
         let mut channel = Self {
             ordering,
             a_side: ChannelSide::new(
                 connection.src_chain().clone(),
                 connection.src_client_id().clone(),
-                connection.src_connection_id().clone(),
+                a_connection_id,
                 a_port,
-                Default::default(),
+                None,
             ),
             b_side: ChannelSide::new(
                 connection.dst_chain().clone(),
                 connection.dst_client_id().clone(),
-                connection.dst_connection_id().clone(),
+                b_connection_id,
                 b_port,
-                Default::default(),
+                None,
             ),
             connection_delay: connection.delay_period,
             version: Some(version),
@@ -137,6 +154,60 @@ impl Channel {
         Ok(channel)
     }
 
+    /// Restores a channel from the attributes of a handshake event emitted by `chain`,
+    /// whose counterparty is `counterparty_chain`. The counterparty channel identifier is
+    /// missing from `ChanOpenInit` events, and is left unknown until the `ChanOpenTry` step
+    /// creates that channel end.
+    pub fn restore_from_event(
+        chain: Box<dyn ChainHandle>,
+        counterparty_chain: Box<dyn ChainHandle>,
+        channel_open_event: &ChannelAttributes,
+    ) -> Result<Channel, ChannelError> {
+        let port_id = channel_open_event.port_id().clone();
+        let channel_id = channel_open_event.channel_id().clone().ok_or_else(|| {
+            ChannelError::Failed(format!(
+                "missing channel id in channel handshake event {:?}",
+                channel_open_event
+            ))
+        })?;
+
+        let channel_end = chain
+            .query_channel(&port_id, &channel_id, Height::zero())
+            .map_err(|e| ChannelError::QueryError(chain.id(), e))?;
+
+        let connection_end = chain
+            .query_connection(&channel_open_event.connection_id, Height::zero())
+            .map_err(|e| ChannelError::QueryError(chain.id(), e))?;
+
+        let counterparty = connection_end.counterparty();
+
+        // The channel handshake runs over an open connection, whose counterparty end is known.
+        let counterparty_connection_id = counterparty
+            .connection_id()
+            .cloned()
+            .ok_or_else(|| ChannelError::MissingConnectionId(counterparty_chain.id()))?;
+
+        Ok(Channel {
+            ordering: *channel_end.ordering(),
+            a_side: ChannelSide::new(
+                chain,
+                connection_end.client_id().clone(),
+                channel_open_event.connection_id.clone(),
+                port_id,
+                Some(channel_id),
+            ),
+            b_side: ChannelSide::new(
+                counterparty_chain,
+                counterparty.client_id().clone(),
+                counterparty_connection_id,
+                channel_open_event.counterparty_port_id.clone(),
+                channel_open_event.counterparty_channel_id.clone(),
+            ),
+            connection_delay: connection_end.delay_period(),
+            version: Some(channel_end.version()),
+        })
+    }
+
     pub fn src_chain(&self) -> Box<dyn ChainHandle> {
         self.a_side.chain.clone()
     }
@@ -169,12 +240,26 @@ impl Channel {
         &self.b_side.port_id
     }
 
-    pub fn src_channel_id(&self) -> &ChannelId {
-        &self.a_side.channel_id
+    pub fn src_channel_id(&self) -> Option<&ChannelId> {
+        self.a_side.channel_id()
+    }
+
+    pub fn dst_channel_id(&self) -> Option<&ChannelId> {
+        self.b_side.channel_id()
     }
 
-    pub fn dst_channel_id(&self) -> &ChannelId {
-        &self.b_side.channel_id
+    /// The identifier of the channel end on the source chain, which the handshake
+    /// steps relying on it require to be known.
+    fn known_src_channel_id(&self) -> Result<&ChannelId, ChannelError> {
+        self.src_channel_id()
+            .ok_or_else(|| ChannelError::MissingChannelId(self.src_chain().id()))
+    }
+
+    /// The identifier of the channel end on the destination chain, which the handshake
+    /// steps relying on it require to be known.
+    fn known_dst_channel_id(&self) -> Result<&ChannelId, ChannelError> {
+        self.dst_channel_id()
+            .ok_or_else(|| ChannelError::MissingChannelId(self.dst_chain().id()))
     }
 
     pub fn flipped(&self) -> Channel {
@@ -205,7 +290,7 @@ impl Channel {
                     continue;
                 }
                 Ok(event) => {
-                    self.a_side.channel_id = extract_channel_id(&event)?.clone();
+                    self.a_side.channel_id = Some(extract_channel_id(&event)?.clone());
                     println!("{}  {} => {:#?}\n", done, a_chain.id(), event);
                     init_success = true;
                     break;
@@ -232,7 +317,7 @@ impl Channel {
                     continue;
                 }
                 Ok(event) => {
-                    self.b_side.channel_id = extract_channel_id(&event)?.clone();
+                    self.b_side.channel_id = Some(extract_channel_id(&event)?.clone());
                     println!("{}  {} => {:#?}\n", done, b_chain.id(), event);
                     try_success = true;
                     break;
@@ -247,18 +332,30 @@ impl Channel {
             )));
         };
 
-        counter = 0;
+        self.finish_handshake()
+    }
+
+    /// Completes the handshake of this channel, once the channel ends exist on both chains,
+    /// by sending the `ChanOpenAck` and `ChanOpenConfirm` messages still required.
+    pub fn finish_handshake(&self) -> Result<(), ChannelError> {
+        let done = '🥳';
+
+        let a_chain = self.src_chain();
+        let b_chain = self.dst_chain();
+
+        let a_channel_id = self.known_src_channel_id()?;
+        let b_channel_id = self.known_dst_channel_id()?;
+
+        let mut counter = 0;
         while counter < MAX_ITER {
             counter += 1;
 
             // Continue loop if query error
-            let a_channel =
-                a_chain.query_channel(&self.src_port_id(), &self.src_channel_id(), Height::zero());
+            let a_channel = a_chain.query_channel(self.src_port_id(), a_channel_id, Height::zero());
             if a_channel.is_err() {
                 continue;
             }
-            let b_channel =
-                b_chain.query_channel(&self.dst_port_id(), &self.dst_channel_id(), Height::zero());
+            let b_channel = b_chain.query_channel(self.dst_port_id(), b_channel_id, Height::zero());
             if b_channel.is_err() {
                 continue;
             }
@@ -311,42 +408,22 @@ impl Channel {
         })
     }
 
-    /// Returns the channel version if already set, otherwise it queries the destination chain
-    /// for the destination port's version.
-    /// Note: This query is currently not available and it is hardcoded in the `module_version()`
-    /// to be `ics20-1` for `transfer` port.
+    /// Returns the channel version if already set, otherwise the version configured on the
+    /// destination chain for the module bound to the destination port.
     pub fn dst_version(&self) -> Result<String, ChannelError> {
-        Ok(self.version.clone()
-            .unwrap_or(
-                self
-                    .dst_chain()
-                    .module_version(self.dst_port_id())
-                    .map_err(|e| {
-                        ChannelError::Failed(format!(
-                            "failed while getting the module version from dst chain ({}) with error: {}",
-                            self.dst_chain().id(),
-                            e
-                        ))
-                    })?
-            ))
-    }
-
-    /// Returns the channel version if already set, otherwise it queries the source chain
-    /// for the source port's version.
-    pub fn src_version(&self) -> Result<String, ChannelError> {
-        Ok(self.version.clone()
-            .unwrap_or(
-                self
-                    .src_chain()
-                    .module_version(self.src_port_id())
-                    .map_err(|e| {
-                        ChannelError::Failed(format!(
-                            "failed while getting the module version from src chain ({}) with error: {}",
-                            self.src_chain().id(),
-                            e
-                        ))
-                    })?
-            ))
+        match &self.version {
+            Some(version) => Ok(version.clone()),
+            None => self
+                .dst_chain()
+                .module_version(self.dst_port_id())
+                .map_err(|e| {
+                    ChannelError::Failed(format!(
+                        "failed while getting the module version from dst chain ({}) with error: {}",
+                        self.dst_chain().id(),
+                        e
+                    ))
+                }),
+        }
     }
 
     pub fn build_chan_open_init(&self) -> Result<Vec<Any>, ChannelError> {
@@ -413,11 +490,11 @@ impl Channel {
         &self,
         msg_type: ChannelMsgType,
     ) -> Result<ChannelEnd, ChannelError> {
+        let dst_channel_id = self.known_dst_channel_id()?;
+
         // If there is a channel present on the destination chain, it should look like this:
-        let counterparty = Counterparty::new(
-            self.src_port_id().clone(),
-            Option::from(self.src_channel_id().clone()),
-        );
+        let counterparty =
+            Counterparty::new(self.src_port_id().clone(), self.src_channel_id().cloned());
 
         // The highest expected state, depends on the message type:
         let highest_state = match msg_type {
@@ -427,20 +504,22 @@ impl Channel {
             _ => State::Uninitialized,
         };
 
+        // Retrieve existing channel if any
+        let dst_channel = self
+            .dst_chain()
+            .query_channel(self.dst_port_id(), dst_channel_id, Height::default())
+            .map_err(|e| ChannelError::QueryError(self.dst_chain().id(), e))?;
+
         let dst_expected_channel = ChannelEnd::new(
             highest_state,
             self.ordering,
             counterparty,
             vec![self.dst_connection_id().clone()],
-            self.dst_version()?,
+            self.version
+                .clone()
+                .unwrap_or_else(|| dst_channel.version()),
         );
 
-        // Retrieve existing channel if any
-        let dst_channel = self
-            .dst_chain()
-            .query_channel(self.dst_port_id(), self.dst_channel_id(), Height::default())
-            .map_err(|e| ChannelError::QueryError(self.dst_chain().id(), e))?;
-
         // Check if a connection is expected to exist on destination chain
         // A channel must exist on destination chain for Ack and Confirm Tx-es to succeed
         if dst_channel.state_matches(&State::Uninitialized) {
@@ -450,7 +529,7 @@ impl Channel {
         }
 
         check_destination_channel_state(
-            self.dst_channel_id().clone(),
+            dst_channel_id.clone(),
             dst_channel,
             dst_expected_channel.clone(),
         )?;
@@ -459,9 +538,11 @@ impl Channel {
     }
 
     pub fn build_chan_open_try(&self) -> Result<Vec<Any>, ChannelError> {
+        let src_channel_id = self.known_src_channel_id()?;
+
         let src_channel = self
             .src_chain()
-            .query_channel(self.src_port_id(), self.src_channel_id(), Height::default())
+            .query_channel(self.src_port_id(), src_channel_id, Height::default())
             .map_err(|e| ChannelError::QueryError(self.src_chain().id(), e))?;
 
         // Retrieve the connection
@@ -477,23 +558,23 @@ impl Channel {
 
         let proofs = self
             .src_chain()
-            .build_channel_proofs(self.src_port_id(), self.src_channel_id(), query_height)
+            .build_channel_proofs(self.src_port_id(), src_channel_id, query_height)
             .map_err(|e| ChannelError::Failed(format!("failed to build channel proofs: {}", e)))?;
 
         // Build message(s) to update client on destination
         let mut msgs = self.build_update_client_on_dst(proofs.height())?;
 
-        let counterparty = Counterparty::new(
-            self.src_port_id().clone(),
-            Some(self.src_channel_id().clone()),
-        );
+        let counterparty =
+            Counterparty::new(self.src_port_id().clone(), Some(src_channel_id.clone()));
 
         let channel = ChannelEnd::new(
             State::TryOpen,
             *src_channel.ordering(),
             counterparty,
             vec![self.dst_connection_id().clone()],
-            self.dst_version()?,
+            self.version
+                .clone()
+                .unwrap_or_else(|| src_channel.version()),
         );
 
         // Get signer
@@ -509,7 +590,7 @@ impl Channel {
         let new_msg = MsgChannelOpenTry {
             port_id: self.dst_port_id().clone(),
             previous_channel_id: src_channel.counterparty().channel_id.clone(),
-            counterparty_version: self.src_version()?,
+            counterparty_version: src_channel.version(),
             channel,
             proofs,
             signer,
@@ -551,9 +632,12 @@ impl Channel {
         // Check that the destination chain will accept the message
         let _dst_expected_channel = self.validated_expected_channel(ChannelMsgType::OpenAck)?;
 
-        let _src_channel = self
+        let src_channel_id = self.known_src_channel_id()?;
+        let dst_channel_id = self.known_dst_channel_id()?;
+
+        let src_channel = self
             .src_chain()
-            .query_channel(self.src_port_id(), self.src_channel_id(), Height::default())
+            .query_channel(self.src_port_id(), src_channel_id, Height::default())
             .map_err(|e| ChannelError::QueryError(self.src_chain().id(), e))?;
 
         // Retrieve the connection
@@ -569,7 +653,7 @@ impl Channel {
 
         let proofs = self
             .src_chain()
-            .build_channel_proofs(self.src_port_id(), self.src_channel_id(), query_height)
+            .build_channel_proofs(self.src_port_id(), src_channel_id, query_height)
             .map_err(|e| {
                 ChannelError::Failed(format!(
                     "failed while building the channel proofs at ACK step with error: {}",
@@ -592,9 +676,9 @@ impl Channel {
         // Build the domain type message
         let new_msg = MsgChannelOpenAck {
             port_id: self.dst_port_id().clone(),
-            channel_id: self.dst_channel_id().clone(),
-            counterparty_channel_id: self.src_channel_id().clone(),
-            counterparty_version: self.src_version()?,
+            channel_id: dst_channel_id.clone(),
+            counterparty_channel_id: src_channel_id.clone(),
+            counterparty_version: src_channel.version(),
             proofs,
             signer,
         };
@@ -635,9 +719,12 @@ impl Channel {
         // Check that the destination chain will accept the message
         let _dst_expected_channel = self.validated_expected_channel(ChannelMsgType::OpenConfirm)?;
 
+        let src_channel_id = self.known_src_channel_id()?;
+        let dst_channel_id = self.known_dst_channel_id()?;
+
         let _src_channel = self
             .src_chain()
-            .query_channel(self.src_port_id(), self.src_channel_id(), Height::default())
+            .query_channel(self.src_port_id(), src_channel_id, Height::default())
             .map_err(|e| ChannelError::QueryError(self.src_chain().id(), e))?;
 
         // Retrieve the connection
@@ -653,7 +740,7 @@ impl Channel {
 
         let proofs = self
             .src_chain()
-            .build_channel_proofs(self.src_port_id(), self.src_channel_id(), query_height)
+            .build_channel_proofs(self.src_port_id(), src_channel_id, query_height)
             .map_err(|e| ChannelError::Failed(format!("failed to build channel proofs: {}", e)))?;
 
         // Build message(s) to update client on destination
@@ -671,7 +758,7 @@ impl Channel {
         // Build the domain type message
         let new_msg = MsgChannelOpenConfirm {
             port_id: self.dst_port_id().clone(),
-            channel_id: self.dst_channel_id().clone(),
+            channel_id: dst_channel_id.clone(),
             proofs,
             signer,
         };
@@ -709,9 +796,11 @@ impl Channel {
     }
 
     pub fn build_chan_close_init(&self) -> Result<Vec<Any>, ChannelError> {
+        let dst_channel_id = self.known_dst_channel_id()?;
+
         let _channel = self
             .dst_chain()
-            .query_channel(self.dst_port_id(), self.dst_channel_id(), Height::default())
+            .query_channel(self.dst_port_id(), dst_channel_id, Height::default())
             .map_err(|e| ChannelError::QueryError(self.dst_chain().id(), e))?;
 
         let signer = self.dst_chain().get_signer().map_err(|e| {
@@ -725,7 +814,7 @@ impl Channel {
         // Build the domain type message
         let new_msg = MsgChannelCloseInit {
             port_id: self.dst_port_id().clone(),
-            channel_id: self.dst_channel_id().clone(),
+            channel_id: dst_channel_id.clone(),
             signer,
         };
 
@@ -766,9 +855,12 @@ impl Channel {
         let _dst_expected_channel =
             self.validated_expected_channel(ChannelMsgType::CloseConfirm)?;
 
+        let src_channel_id = self.known_src_channel_id()?;
+        let dst_channel_id = self.known_dst_channel_id()?;
+
         let _src_channel = self
             .src_chain()
-            .query_channel(self.src_port_id(), self.src_channel_id(), Height::default())
+            .query_channel(self.src_port_id(), src_channel_id, Height::default())
             .map_err(|e| ChannelError::QueryError(self.src_chain().id(), e))?;
 
         // Retrieve the connection
@@ -784,7 +876,7 @@ impl Channel {
 
         let proofs = self
             .src_chain()
-            .build_channel_proofs(self.src_port_id(), self.src_channel_id(), query_height)
+            .build_channel_proofs(self.src_port_id(), src_channel_id, query_height)
             .map_err(|e| ChannelError::Failed(format!("failed to build channel proofs: {}", e)))?;
 
         // Build message(s) to update client on destination
@@ -802,7 +894,7 @@ impl Channel {
         // Build the domain type message
         let new_msg = MsgChannelCloseConfirm {
             port_id: self.dst_port_id().clone(),
-            channel_id: self.dst_channel_id().clone(),
+            channel_id: dst_channel_id.clone(),
             proofs,
             signer,
         };
@@ -1,14 +1,26 @@
 //! Relayer configuration
 
-use std::{fs, fs::File, io::Write, path::Path, time::Duration};
+use std::{
+    collections::HashMap,
+    fmt, fs,
+    fs::File,
+    io::Write,
+    path::{Path, PathBuf},
+    time::Duration,
+};
 
 use serde_derive::{Deserialize, Serialize};
 use tendermint_light_client::types::TrustThreshold;
 
+use ibc::ics03_connection::version::Version;
 use ibc::ics04_channel::channel::Order;
 use ibc::ics24_host::identifier::{ChainId, PortId};
 
 use crate::error;
+use crate::keyring::{KeyType, Store};
+
+pub mod filter;
+pub use filter::PacketFilter;
 
 /// Defaults for various fields
 pub mod default {
@@ -18,6 +30,10 @@ pub mod default {
         Duration::from_secs(10)
     }
 
+    pub fn tx_confirmation_timeout() -> Duration {
+        Duration::from_secs(60)
+    }
+
     pub fn trusting_period() -> Duration {
         Duration::from_secs(336 * 60 * 60) // 336 hours ~ 14 days
     }
@@ -33,6 +49,22 @@ pub mod default {
     pub fn channel_ordering() -> Order {
         Order::Unordered
     }
+
+    pub fn light_store_pruning_window() -> usize {
+        100
+    }
+
+    pub fn telemetry_host() -> String {
+        "127.0.0.1".to_string()
+    }
+
+    pub fn telemetry_port() -> u16 {
+        3001
+    }
+
+    pub fn admin_port() -> u16 {
+        3000
+    }
 }
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
@@ -67,15 +99,39 @@ impl Config {
     }
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
+/// Which kinds of workers the supervisor spawns.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "kebab-case")]
 pub enum Strategy {
-    #[serde(rename = "naive")]
-    Naive,
+    /// Only relay packets on the channels which are already open.
+    Packets,
+    /// Relay packets, complete the connection and channel handshakes and refresh the clients.
+    #[serde(alias = "naive")]
+    All,
+    /// Only refresh the clients and watch them for misbehaviour.
+    ClientsOnly,
+}
+
+/// How the transactions are broadcast to a chain.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum BroadcastMode {
+    /// With `broadcast_tx_commit`, which returns once the transaction is committed.
+    Commit,
+    /// With `broadcast_tx_sync`, which returns once the transaction passed `CheckTx`,
+    /// after which its inclusion in a block is polled for.
+    Sync,
+}
+
+impl Default for BroadcastMode {
+    fn default() -> Self {
+        Self::Commit
+    }
 }
 
 impl Default for Strategy {
     fn default() -> Self {
-        Self::Naive
+        Self::All
     }
 }
 
@@ -87,6 +143,12 @@ pub struct GlobalConfig {
     /// All valid log levels, as defined in tracing:
     /// https://docs.rs/tracing-core/0.1.17/tracing_core/struct.Level.html
     pub log_level: String,
+
+    #[serde(default)]
+    pub telemetry: TelemetryConfig,
+
+    #[serde(default)]
+    pub admin: AdminConfig,
 }
 
 impl Default for GlobalConfig {
@@ -94,13 +156,64 @@ impl Default for GlobalConfig {
         Self {
             strategy: Strategy::default(),
             log_level: "info".to_string(),
+            telemetry: TelemetryConfig::default(),
+            admin: AdminConfig::default(),
+        }
+    }
+}
+
+/// Configuration of the admin service, which serves a JSON API to inspect and steer
+/// the supervisor at `http://127.0.0.1:<port>`.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct AdminConfig {
+    #[serde(default)]
+    pub enabled: bool,
+    #[serde(default = "default::admin_port")]
+    pub port: u16,
+    /// File holding the token which authorizes the `POST` requests, unless it is set
+    /// in the `HERMES_ADMIN_TOKEN` environment variable.
+    #[serde(default)]
+    pub token_file: Option<PathBuf>,
+}
+
+impl Default for AdminConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            port: default::admin_port(),
+            token_file: None,
         }
     }
 }
 
+/// Configuration of the telemetry service, which exposes the metrics of the relayer
+/// at `http://<host>:<port>/metrics`.
 #[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct TelemetryConfig {
+    #[serde(default)]
+    pub enabled: bool,
+    #[serde(default = "default::telemetry_host")]
+    pub host: String,
+    #[serde(default = "default::telemetry_port")]
+    pub port: u16,
+}
+
+impl Default for TelemetryConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            host: default::telemetry_host(),
+            port: default::telemetry_port(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
 pub struct ChainConfig {
     pub id: ChainId,
+    /// Type of the chain, which selects the implementation the relayer interacts with it through.
+    #[serde(default, rename = "type")]
+    pub chain_type: ChainType,
     pub rpc_addr: tendermint_rpc::Url,
     pub websocket_addr: tendermint_rpc::Url,
     pub grpc_addr: tendermint_rpc::Url,
@@ -108,18 +221,133 @@ pub struct ChainConfig {
     pub rpc_timeout: Duration,
     pub account_prefix: String,
     pub key_name: String,
+    /// Type of the key of the relayer on this chain, `secp256k1` or `eth_secp256k1`.
+    #[serde(default)]
+    pub key_type: KeyType,
+    /// Key store holding the key of the relayer on this chain.
+    #[serde(default)]
+    pub key_store_type: Store,
+    /// File containing the passphrase of the `encrypted` key store, if not set in the
+    /// `HERMES_KEYRING_PASSPHRASE` environment variable. Prompted for otherwise.
+    pub key_passphrase_file: Option<PathBuf>,
     pub store_prefix: String,
     pub gas: Option<u64>,
+    /// Whether to simulate transactions to estimate their gas, instead of always using `gas`.
+    #[serde(default)]
+    pub simulate_tx: bool,
+    /// Multiplier applied to the gas used in the simulation of a transaction.
+    pub gas_adjustment: Option<f64>,
+    /// Price of a unit of gas in `fee_denom`, used to compute the fee of simulated transactions.
+    pub gas_price: Option<f64>,
     pub fee_denom: String,
     pub fee_amount: Option<u64>,
+    /// Account which granted the relayer account an allowance, through the feegrant module,
+    /// from which the fees of the transactions are paid.
+    pub fee_granter: Option<String>,
+    /// Account paying the fees of the transactions. As the relayer account is the only signer
+    /// of the transactions, it must be the relayer account, which is the default.
+    pub fee_payer: Option<String>,
     pub max_msg_num: Option<usize>,
     pub max_tx_size: Option<usize>,
+    #[serde(default)]
+    pub broadcast_mode: BroadcastMode,
+    /// How long the inclusion of a transaction broadcast in the `sync` mode is polled for.
+    #[serde(default = "default::tx_confirmation_timeout", with = "humantime_serde")]
+    pub tx_confirmation_timeout: Duration,
     #[serde(default = "default::clock_drift", with = "humantime_serde")]
     pub clock_drift: Duration,
     #[serde(default = "default::trusting_period", with = "humantime_serde")]
     pub trusting_period: Duration,
+    /// Location of the light client store of this chain.
+    /// Defaults to `~/.hermes/light_store/<chain_id>`.
+    pub light_store_path: Option<PathBuf>,
+    /// Maximum number of verified light blocks kept in the light client store.
+    #[serde(default = "default::light_store_pruning_window")]
+    pub light_store_pruning_window: usize,
     #[serde(default)]
     pub trust_threshold: TrustThreshold,
+    /// Channels on which packets are relayed, by default all of them.
+    #[serde(default)]
+    pub packet_filter: PacketFilter,
+    /// Versions of the application modules bound to the given ports, used when opening
+    /// channels instead of the versions found on the chain.
+    #[serde(default)]
+    pub port_versions: HashMap<PortId, String>,
+    /// Versions supported by the connection module of the chain, by default the versions
+    /// returned by `get_compatible_versions`.
+    #[serde(default)]
+    pub connection_versions: Vec<Version>,
+    /// Endpoints of other nodes of the chain, to which the relayer fails over, in order,
+    /// when the node in use is unreachable.
+    #[serde(default)]
+    pub backup_endpoints: Vec<Endpoints>,
+}
+
+impl ChainConfig {
+    /// The endpoints of all the nodes of the chain, starting with `rpc_addr`,
+    /// `websocket_addr` and `grpc_addr`, followed by the `backup_endpoints`.
+    pub fn endpoints(&self) -> Vec<Endpoints> {
+        let primary = Endpoints {
+            rpc_addr: self.rpc_addr.clone(),
+            websocket_addr: self.websocket_addr.clone(),
+            grpc_addr: self.grpc_addr.clone(),
+        };
+
+        std::iter::once(primary)
+            .chain(self.backup_endpoints.iter().cloned())
+            .collect()
+    }
+}
+
+/// The type of a chain, by which the relayer selects the `Chain` implementation
+/// that a chain runtime is spawned with, see [`crate::registry`].
+///
+/// Defaults to `cosmos-sdk`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[serde(transparent)]
+pub struct ChainType(String);
+
+impl ChainType {
+    /// Chains built with the Cosmos SDK, relayed to by `CosmosSdkChain`.
+    pub const COSMOS_SDK: &'static str = "cosmos-sdk";
+
+    /// In-process chains relayed to by `MockChain`, only available in tests.
+    pub const MOCK: &'static str = "mock";
+
+    pub fn new(chain_type: impl Into<String>) -> Self {
+        Self(chain_type.into())
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Default for ChainType {
+    fn default() -> Self {
+        Self::new(Self::COSMOS_SDK)
+    }
+}
+
+impl fmt::Display for ChainType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+/// The endpoints of a node of a chain.
+///
+/// ```toml
+/// [[chains.backup_endpoints]]
+/// rpc_addr = 'http://127.0.0.1:26667'
+/// websocket_addr = 'ws://127.0.0.1:26667/websocket'
+/// grpc_addr = 'http://127.0.0.1:9091'
+/// ```
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct Endpoints {
+    pub rpc_addr: tendermint_rpc::Url,
+    pub websocket_addr: tendermint_rpc::Url,
+    pub grpc_addr: tendermint_rpc::Url,
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
@@ -174,7 +402,7 @@ pub(crate) fn store_writer(config: &Config, mut writer: impl Write) -> Result<()
 
 #[cfg(test)]
 mod tests {
-    use super::{parse, store_writer};
+    use super::{parse, store_writer, GlobalConfig, Strategy};
 
     #[test]
     fn parse_valid_config() {
@@ -201,4 +429,27 @@ mod tests {
         let result = store_writer(&config, &mut buffer);
         assert!(result.is_ok());
     }
+
+    #[test]
+    fn parse_strategies() {
+        let strategy = |toml: &str| {
+            toml::from_str::<GlobalConfig>(toml)
+                .expect("could not parse global config")
+                .strategy
+        };
+
+        assert_eq!(strategy("log_level = 'info'"), Strategy::All);
+        assert_eq!(
+            strategy("strategy = 'naive'\nlog_level = 'info'"),
+            Strategy::All
+        );
+        assert_eq!(
+            strategy("strategy = 'packets'\nlog_level = 'info'"),
+            Strategy::Packets
+        );
+        assert_eq!(
+            strategy("strategy = 'clients-only'\nlog_level = 'info'"),
+            Strategy::ClientsOnly
+        );
+    }
 }
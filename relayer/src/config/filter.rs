@@ -0,0 +1,163 @@
+//! Policy restricting the channels on which the relayer relays packets.
+
+use serde_derive::{Deserialize, Serialize};
+
+use ibc::ics24_host::identifier::{ChannelId, PortId};
+
+/// Restricts the channels of a chain on which packets are relayed.
+///
+/// ```toml
+/// [chains.packet_filter]
+/// policy = 'allow'
+/// list = [
+///   ['transfer', 'channel-0'],
+///   ['ica*', '*'],
+/// ]
+/// ```
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[serde(tag = "policy", content = "list", rename_all = "snake_case")]
+pub enum PacketFilter {
+    /// Relay packets only on the channels matching one of the patterns.
+    Allow(Vec<ChannelFilter>),
+    /// Relay packets on all the channels except those matching one of the patterns.
+    Deny(Vec<ChannelFilter>),
+    /// Relay packets on all the channels.
+    AllowAll,
+}
+
+impl Default for PacketFilter {
+    fn default() -> Self {
+        Self::AllowAll
+    }
+}
+
+impl PacketFilter {
+    /// Whether packets sent on the given channel should be relayed.
+    pub fn is_allowed(&self, port_id: &PortId, channel_id: &ChannelId) -> bool {
+        match self {
+            Self::Allow(filters) => filters.iter().any(|f| f.matches(port_id, channel_id)),
+            Self::Deny(filters) => !filters.iter().any(|f| f.matches(port_id, channel_id)),
+            Self::AllowAll => true,
+        }
+    }
+}
+
+/// A pair of port and channel identifier patterns.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct ChannelFilter(pub FilterPattern, pub FilterPattern);
+
+impl ChannelFilter {
+    pub fn matches(&self, port_id: &PortId, channel_id: &ChannelId) -> bool {
+        self.0.matches(port_id.as_str()) && self.1.matches(channel_id.as_str())
+    }
+}
+
+/// An identifier pattern, in which `*` matches any (possibly empty) sequence of characters.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(transparent)]
+pub struct FilterPattern(pub String);
+
+impl FilterPattern {
+    pub fn matches(&self, value: &str) -> bool {
+        wildcard_match(self.0.as_bytes(), value.as_bytes())
+    }
+}
+
+/// Matches `value` against `pattern`, backtracking to the last `*` seen on a mismatch.
+fn wildcard_match(pattern: &[u8], value: &[u8]) -> bool {
+    let (mut p, mut v) = (0, 0);
+    let mut backtrack = None;
+
+    while v < value.len() {
+        if p < pattern.len() && pattern[p] == b'*' {
+            backtrack = Some((p, v));
+            p += 1;
+        } else if p < pattern.len() && pattern[p] == value[v] {
+            p += 1;
+            v += 1;
+        } else if let Some((star, matched)) = backtrack {
+            backtrack = Some((star, matched + 1));
+            p = star + 1;
+            v = matched + 1;
+        } else {
+            return false;
+        }
+    }
+
+    pattern[p..].iter().all(|c| *c == b'*')
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use ibc::ics24_host::identifier::{ChannelId, PortId};
+
+    use super::{ChannelFilter, FilterPattern, PacketFilter};
+
+    fn filter(port: &str, channel: &str) -> ChannelFilter {
+        ChannelFilter(
+            FilterPattern(port.to_string()),
+            FilterPattern(channel.to_string()),
+        )
+    }
+
+    fn allowed(policy: &PacketFilter, port: &str, channel: &str) -> bool {
+        policy.is_allowed(
+            &PortId::from_str(port).unwrap(),
+            &ChannelId::from_str(channel).unwrap(),
+        )
+    }
+
+    #[test]
+    fn wildcard_patterns() {
+        let pattern = FilterPattern("ica*".to_string());
+        assert!(pattern.matches("ica"));
+        assert!(pattern.matches("icacontroller"));
+        assert!(!pattern.matches("transfer"));
+
+        let pattern = FilterPattern("channel-*0".to_string());
+        assert!(pattern.matches("channel-0"));
+        assert!(pattern.matches("channel-100"));
+        assert!(!pattern.matches("channel-101"));
+
+        assert!(FilterPattern("*".to_string()).matches(""));
+        assert!(!FilterPattern("transfer".to_string()).matches("transfers"));
+    }
+
+    #[test]
+    fn allow_and_deny_policies() {
+        let allow = PacketFilter::Allow(vec![filter("transfer", "channel-0"), filter("ica*", "*")]);
+        assert!(allowed(&allow, "transfer", "channel-0"));
+        assert!(allowed(&allow, "icahost", "channel-7"));
+        assert!(!allowed(&allow, "transfer", "channel-1"));
+
+        let deny = PacketFilter::Deny(vec![filter("transfer", "channel-1*")]);
+        assert!(allowed(&deny, "transfer", "channel-0"));
+        assert!(!allowed(&deny, "transfer", "channel-12"));
+
+        assert!(allowed(&PacketFilter::default(), "transfer", "channel-1"));
+    }
+
+    #[test]
+    fn parse_packet_filter() {
+        #[derive(serde_derive::Deserialize)]
+        struct Chain {
+            packet_filter: PacketFilter,
+        }
+
+        let chain: Chain = toml::from_str(
+            r#"
+            [packet_filter]
+            policy = 'allow'
+            list = [['transfer', 'channel-0'], ['ica*', '*']]
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            chain.packet_filter,
+            PacketFilter::Allow(vec![filter("transfer", "channel-0"), filter("ica*", "*")])
+        );
+    }
+}
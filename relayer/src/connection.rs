@@ -10,6 +10,7 @@ use ibc::ics02_client::height::Height;
 use ibc::ics03_connection::connection::{
     ConnectionEnd, Counterparty, IdentifiedConnectionEnd, State,
 };
+use ibc::ics03_connection::events::Attributes as ConnectionAttributes;
 use ibc::ics03_connection::msgs::conn_open_ack::MsgConnectionOpenAck;
 use ibc::ics03_connection::msgs::conn_open_confirm::MsgConnectionOpenConfirm;
 use ibc::ics03_connection::msgs::conn_open_init::MsgConnectionOpenInit;
@@ -44,20 +45,23 @@ pub enum ConnectionError {
         "failed during a transaction submission step to chain id {0} with underlying error: {1}"
     )]
     SubmitError(ChainId, Error),
+
+    #[error("the connection identifier on chain {0} is not known yet")]
+    MissingConnectionId(ChainId),
 }
 
 #[derive(Clone, Debug)]
 pub struct ConnectionSide {
     pub(crate) chain: Box<dyn ChainHandle>,
     client_id: ClientId,
-    connection_id: ConnectionId,
+    connection_id: Option<ConnectionId>,
 }
 
 impl ConnectionSide {
     pub fn new(
         chain: Box<dyn ChainHandle>,
         client_id: ClientId,
-        connection_id: ConnectionId,
+        connection_id: Option<ConnectionId>,
     ) -> Self {
         Self {
             chain,
@@ -75,12 +79,12 @@ impl Serialize for ConnectionSide {
         #[derive(Debug, Serialize)]
         struct ConnectionSide<'a> {
             client_id: &'a ClientId,
-            connection_id: &'a ConnectionId,
+            connection_id: Option<&'a ConnectionId>,
         }
 
         let value = ConnectionSide {
             client_id: &self.client_id,
-            connection_id: &self.connection_id,
+            connection_id: self.connection_id.as_ref(),
         };
 
         value.serialize(serializer)
@@ -114,16 +118,8 @@ impl Connection {
 
         let mut c = Self {
             delay_period,
-            a_side: ConnectionSide::new(
-                a_client.dst_chain(),
-                a_client.id().clone(),
-                Default::default(),
-            ),
-            b_side: ConnectionSide::new(
-                b_client.dst_chain(),
-                b_client.id().clone(),
-                Default::default(),
-            ),
+            a_side: ConnectionSide::new(a_client.dst_chain(), a_client.id().clone(), None),
+            b_side: ConnectionSide::new(b_client.dst_chain(), b_client.id().clone(), None),
         };
 
         c.handshake()?;
@@ -131,6 +127,41 @@ impl Connection {
         Ok(c)
     }
 
+    /// Restores a connection from the attributes of a handshake event emitted by `chain`,
+    /// whose counterparty is `counterparty_chain`. The counterparty connection identifier
+    /// is missing from `ConnOpenInit` events, and is left unknown until the `ConnOpenTry`
+    /// step creates that connection end.
+    pub fn restore_from_event(
+        chain: Box<dyn ChainHandle>,
+        counterparty_chain: Box<dyn ChainHandle>,
+        connection_open_event: &ConnectionAttributes,
+    ) -> Result<Connection, ConnectionError> {
+        let connection_id = connection_open_event.connection_id.clone().ok_or_else(|| {
+            ConnectionError::Failed(format!(
+                "missing connection id in connection handshake event {:?}",
+                connection_open_event
+            ))
+        })?;
+
+        let connection_end = chain
+            .query_connection(&connection_id, Height::zero())
+            .map_err(|e| ConnectionError::QueryError(chain.id(), e))?;
+
+        Ok(Connection {
+            delay_period: connection_end.delay_period(),
+            a_side: ConnectionSide::new(
+                chain,
+                connection_open_event.client_id.clone(),
+                Some(connection_id),
+            ),
+            b_side: ConnectionSide::new(
+                counterparty_chain,
+                connection_open_event.counterparty_client_id.clone(),
+                connection_open_event.counterparty_connection_id.clone(),
+            ),
+        })
+    }
+
     pub fn find(
         a_client: ForeignClient,
         b_client: ForeignClient,
@@ -174,12 +205,12 @@ impl Connection {
             a_side: ConnectionSide {
                 chain: a_client.dst_chain.clone(),
                 client_id: a_client.id.clone(),
-                connection_id: conn_end_a.id().clone(),
+                connection_id: Some(conn_end_a.id().clone()),
             },
             b_side: ConnectionSide {
                 chain: b_client.dst_chain.clone(),
                 client_id: b_client.id.clone(),
-                connection_id: b_conn_id,
+                connection_id: Some(b_conn_id),
             },
         };
 
@@ -226,12 +257,26 @@ impl Connection {
         &self.b_side.client_id
     }
 
-    pub fn src_connection_id(&self) -> &ConnectionId {
-        &self.a_side.connection_id
+    pub fn src_connection_id(&self) -> Option<&ConnectionId> {
+        self.a_side.connection_id.as_ref()
     }
 
-    pub fn dst_connection_id(&self) -> &ConnectionId {
-        &self.b_side.connection_id
+    pub fn dst_connection_id(&self) -> Option<&ConnectionId> {
+        self.b_side.connection_id.as_ref()
+    }
+
+    /// The identifier of the connection end on the source chain, which the handshake
+    /// steps relying on it require to be known.
+    fn known_src_connection_id(&self) -> Result<&ConnectionId, ConnectionError> {
+        self.src_connection_id()
+            .ok_or_else(|| ConnectionError::MissingConnectionId(self.src_chain().id()))
+    }
+
+    /// The identifier of the connection end on the destination chain, which the handshake
+    /// steps relying on it require to be known.
+    fn known_dst_connection_id(&self) -> Result<&ConnectionId, ConnectionError> {
+        self.dst_connection_id()
+            .ok_or_else(|| ConnectionError::MissingConnectionId(self.dst_chain().id()))
     }
 
     pub fn flipped(&self) -> Connection {
@@ -246,9 +291,6 @@ impl Connection {
     fn handshake(&mut self) -> Result<(), ConnectionError> {
         let done = '🥂';
 
-        let a_chain = self.a_side.chain.clone();
-        let b_chain = self.b_side.chain.clone();
-
         // Try connOpenInit on a_chain
         let mut counter = 0;
         while counter < MAX_ITER {
@@ -259,7 +301,7 @@ impl Connection {
                     continue;
                 }
                 Ok(result) => {
-                    self.a_side.connection_id = extract_connection_id(&result)?.clone();
+                    self.a_side.connection_id = Some(extract_connection_id(&result)?.clone());
                     println!("🥂  {} => {:#?}\n", self.a_side.chain.id(), result);
                     break;
                 }
@@ -276,23 +318,37 @@ impl Connection {
                     continue;
                 }
                 Ok(result) => {
-                    self.b_side.connection_id = extract_connection_id(&result)?.clone();
+                    self.b_side.connection_id = Some(extract_connection_id(&result)?.clone());
                     println!("{}  {} => {:#?}\n", done, self.b_side.chain.id(), result);
                     break;
                 }
             }
         }
 
-        counter = 0;
+        self.finish_handshake()
+    }
+
+    /// Completes the handshake of this connection, once the connection ends exist on both chains,
+    /// by sending the `ConnOpenAck` and `ConnOpenConfirm` messages still required.
+    pub fn finish_handshake(&self) -> Result<(), ConnectionError> {
+        let done = '🥂';
+
+        let a_chain = self.a_side.chain.clone();
+        let b_chain = self.b_side.chain.clone();
+
+        let a_connection_id = self.known_src_connection_id()?;
+        let b_connection_id = self.known_dst_connection_id()?;
+
+        let mut counter = 0;
         while counter < MAX_ITER {
             counter += 1;
 
             // Continue loop if query error
-            let a_connection = a_chain.query_connection(&self.src_connection_id(), Height::zero());
+            let a_connection = a_chain.query_connection(a_connection_id, Height::zero());
             if a_connection.is_err() {
                 continue;
             }
-            let b_connection = b_chain.query_connection(&self.dst_connection_id(), Height::zero());
+            let b_connection = b_chain.query_connection(b_connection_id, Height::zero());
             if b_connection.is_err() {
                 continue;
             }
@@ -352,6 +408,9 @@ impl Connection {
         &self,
         msg_type: ConnectionMsgType,
     ) -> Result<ConnectionEnd, ConnectionError> {
+        let src_connection_id = self.known_src_connection_id()?;
+        let dst_connection_id = self.known_dst_connection_id()?;
+
         let prefix = self
             .src_chain()
             .query_commitment_prefix()
@@ -360,7 +419,7 @@ impl Connection {
         // If there is a connection present on the destination chain, it should look like this:
         let counterparty = Counterparty::new(
             self.src_client_id().clone(),
-            Option::from(self.src_connection_id().clone()),
+            Some(src_connection_id.clone()),
             prefix,
         );
 
@@ -387,7 +446,7 @@ impl Connection {
         // Retrieve existing connection if any
         let dst_connection = self
             .dst_chain()
-            .query_connection(self.dst_connection_id(), ICSHeight::default())
+            .query_connection(dst_connection_id, ICSHeight::default())
             .map_err(|e| ConnectionError::QueryError(self.dst_chain().id(), e))?;
 
         // Check if a connection is expected to exist on destination chain
@@ -395,13 +454,13 @@ impl Connection {
         if dst_connection.state_matches(&State::Uninitialized) {
             return Err(ConnectionError::Failed(format!(
                 "missing connection {} on source chain {}",
-                self.src_connection_id().clone(),
+                src_connection_id,
                 self.dst_chain().id()
             )));
         }
 
         check_destination_connection_state(
-            self.dst_connection_id().clone(),
+            dst_connection_id.clone(),
             dst_connection,
             dst_expected_connection.clone(),
         )?;
@@ -494,9 +553,11 @@ impl Connection {
 
     /// Attempts to build a MsgConnOpenTry.
     pub fn build_conn_try(&self) -> Result<Vec<Any>, ConnectionError> {
+        let src_connection_id = self.known_src_connection_id()?;
+
         let src_connection = self
             .src_chain()
-            .query_connection(self.src_connection_id(), ICSHeight::default())
+            .query_connection(src_connection_id, ICSHeight::default())
             .map_err(|e| ConnectionError::QueryError(self.src_chain().id(), e))?;
 
         // TODO - check that the src connection is consistent with the try options
@@ -535,7 +596,7 @@ impl Connection {
             .src_chain()
             .build_connection_proofs_and_client_state(
                 ConnectionMsgType::OpenTry,
-                self.src_connection_id(),
+                src_connection_id,
                 self.src_client_id(),
                 query_height,
             )
@@ -570,7 +631,7 @@ impl Connection {
 
         let counterparty = Counterparty::new(
             self.src_client_id().clone(),
-            Option::from(self.src_connection_id().clone()),
+            Some(src_connection_id.clone()),
             prefix,
         );
 
@@ -626,9 +687,12 @@ impl Connection {
                     "ack options inconsistent with existing connection on destination chain; context={}", e
                 )))?;
 
+        let src_connection_id = self.known_src_connection_id()?;
+        let dst_connection_id = self.known_dst_connection_id()?;
+
         let src_connection = self
             .src_chain()
-            .query_connection(self.src_connection_id(), ICSHeight::default())
+            .query_connection(src_connection_id, ICSHeight::default())
             .map_err(|e| ConnectionError::QueryError(self.src_chain().id(), e))?;
 
         // TODO - check that the src connection is consistent with the ack options
@@ -652,7 +716,7 @@ impl Connection {
             .src_chain()
             .build_connection_proofs_and_client_state(
                 ConnectionMsgType::OpenAck,
-                self.src_connection_id(),
+                src_connection_id,
                 self.src_client_id(),
                 query_height,
             )
@@ -673,8 +737,8 @@ impl Connection {
         })?;
 
         let new_msg = MsgConnectionOpenAck {
-            connection_id: self.dst_connection_id().clone(),
-            counterparty_connection_id: self.src_connection_id().clone(),
+            connection_id: dst_connection_id.clone(),
+            counterparty_connection_id: src_connection_id.clone(),
             client_state,
             proofs,
             version: src_connection.versions()[0].clone(),
@@ -722,17 +786,20 @@ impl Connection {
                     "confirm options inconsistent with existing connection on destination chain; context={}", e))
             })?;
 
+        let src_connection_id = self.known_src_connection_id()?;
+        let dst_connection_id = self.known_dst_connection_id()?;
+
         let query_height = self
             .src_chain()
             .query_latest_height()
             .map_err(|e| ConnectionError::QueryError(self.src_chain().id(), e))?;
         let _src_connection = self
             .src_chain()
-            .query_connection(self.src_connection_id(), query_height)
+            .query_connection(src_connection_id, query_height)
             .map_err(|_| {
                 ConnectionError::Failed(format!(
                     "missing connection {} on source chain",
-                    self.src_connection_id()
+                    src_connection_id
                 ))
             })?;
 
@@ -742,7 +809,7 @@ impl Connection {
             .src_chain()
             .build_connection_proofs_and_client_state(
                 ConnectionMsgType::OpenConfirm,
-                self.src_connection_id(),
+                src_connection_id,
                 self.src_client_id(),
                 query_height,
             )
@@ -763,7 +830,7 @@ impl Connection {
         })?;
 
         let new_msg = MsgConnectionOpenConfirm {
-            connection_id: self.dst_connection_id().clone(),
+            connection_id: dst_connection_id.clone(),
             proofs,
             signer,
         };
@@ -46,10 +46,30 @@ pub enum Kind {
     #[error("Light client error for RPC address {0}")]
     LightClient(String),
 
+    /// Light client request to a chain runtime spawned without light client
+    #[error("The runtime of chain {0} runs without a light client")]
+    LightClientDisabled(String),
+
     /// Trusted store error, raised by instances of `Store`
     #[error("Store error")]
     Store,
 
+    /// Telemetry service error
+    #[error("Telemetry error")]
+    Telemetry,
+
+    /// Admin service error
+    #[error("Admin service error")]
+    Admin,
+
+    /// Missing fee grant
+    #[error("No fee allowance granted by {0} to {1}")]
+    MissingFeeGrant(String, String),
+
+    /// Transaction not committed in time
+    #[error("Transaction {0} was not committed within {1:?}")]
+    TxNotCommitted(String, std::time::Duration),
+
     /// Event error (raised by the event monitor)
     #[error("Bad Notification")]
     Event,
@@ -19,6 +19,8 @@ use tendermint_rpc::{
 
 use ibc::{events::IbcEvent, ics02_client::height::Height, ics24_host::identifier::ChainId};
 
+use crate::telemetry;
+use crate::util::failover::Failover;
 use crate::util::retry::Clamped;
 
 const MAX_RETRIES: usize = 1000;
@@ -99,8 +101,8 @@ pub struct EventMonitor {
     rx_err: mpsc::UnboundedReceiver<tendermint_rpc::Error>,
     /// Channel where to send client driver errors
     tx_err: mpsc::UnboundedSender<tendermint_rpc::Error>,
-    /// Node Address
-    node_addr: tendermint_rpc::Url,
+    /// WebSocket addresses of the nodes of the chain, the one in use first
+    node_addrs: Failover<tendermint_rpc::Url>,
     /// Queries
     event_queries: Vec<Query>,
     /// All subscriptions combined in a single stream
@@ -120,19 +122,45 @@ async fn run_driver(
     }
 }
 
+/// Connect to the node in use, failing over to the next ones until one of them is reachable.
+fn connect(
+    rt: &TokioRuntime,
+    node_addrs: &mut Failover<tendermint_rpc::Url>,
+) -> Result<(WebSocketClient, WebSocketClientDriver)> {
+    let mut attempts = node_addrs.len();
+
+    loop {
+        let node_addr = node_addrs.current().clone();
+
+        match rt.block_on(WebSocketClient::new(node_addr.clone())) {
+            Ok(connection) => return Ok(connection),
+            Err(e) if attempts > 1 => {
+                warn!(
+                    "failed to connect to WebSocket endpoint {}: {}",
+                    node_addr, e
+                );
+                node_addrs.failed();
+                attempts -= 1;
+            }
+            Err(e) => return Err(Error::ClientCreationFailed(e)),
+        }
+    }
+}
+
 impl EventMonitor {
-    /// Create an event monitor, and connect to a node
+    /// Create an event monitor, and connect to the first reachable node among `node_addrs`.
+    ///
+    /// ## Panics
+    /// If `node_addrs` is empty.
     pub fn new(
         chain_id: ChainId,
-        node_addr: tendermint_rpc::Url,
+        node_addrs: Vec<tendermint_rpc::Url>,
         rt: Arc<TokioRuntime>,
     ) -> Result<(Self, EventReceiver)> {
         let (tx_batch, rx_batch) = channel::unbounded();
 
-        let ws_addr = node_addr.clone();
-        let (client, driver) = rt
-            .block_on(async move { WebSocketClient::new(ws_addr).await })
-            .map_err(Error::ClientCreationFailed)?;
+        let mut node_addrs = Failover::new(node_addrs);
+        let (client, driver) = connect(&rt, &mut node_addrs)?;
 
         let (tx_err, rx_err) = mpsc::unbounded_channel();
         let websocket_driver_handle = rt.spawn(run_driver(driver, tx_err.clone()));
@@ -149,7 +177,7 @@ impl EventMonitor {
             tx_batch,
             rx_err,
             tx_err,
-            node_addr,
+            node_addrs,
             subscriptions: Box::new(futures::stream::empty()),
         };
 
@@ -197,14 +225,18 @@ impl EventMonitor {
     fn try_reconnect(&mut self) -> Result<()> {
         warn!(
             "trying to reconnect to WebSocket endpoint: {}",
-            self.node_addr
+            self.node_addrs.current()
         );
 
         // Try to reconnect
         let (mut client, driver) = self
             .rt
-            .block_on(WebSocketClient::new(self.node_addr.clone()))
-            .map_err(Error::ClientCreationFailed)?;
+            .block_on(WebSocketClient::new(self.node_addrs.current().clone()))
+            .map_err(|e| {
+                // Fail over to the next healthy node for the next attempt
+                self.node_addrs.failed();
+                Error::ClientCreationFailed(e)
+            })?;
 
         let mut driver_handle = self.rt.spawn(run_driver(driver, self.tx_err.clone()));
 
@@ -213,7 +245,10 @@ impl EventMonitor {
         std::mem::swap(&mut self.client, &mut client);
         std::mem::swap(&mut self.driver_handle, &mut driver_handle);
 
-        warn!("reconnected to WebSocket endpoint: {}", self.node_addr);
+        warn!(
+            "reconnected to WebSocket endpoint: {}",
+            self.node_addrs.current()
+        );
 
         // Shut down previous client
         debug!("gracefully shutting down previous client");
@@ -238,13 +273,17 @@ impl EventMonitor {
         self.subscribe()
     }
 
-    /// Attempt to restart the WebSocket client using the given retry stragegy.
+    /// Attempt to restart the WebSocket client using the given retry stragegy,
+    /// failing over to the next healthy node at each attempt.
     ///
     /// See the [`retry`](https://docs.rs/retry) crate and the
     /// [`crate::util::retry`] module for more information.
     fn restart(&mut self) {
         use retry::{retry_with_index, OperationResult as TryResult};
 
+        // The connection to the node in use failed
+        self.node_addrs.failed();
+
         let strategy = Clamped::new(
             Fibonacci::from(INITIAL_RETRY_DELAY),
             MAX_RETRY_DELAY,
@@ -264,6 +303,8 @@ impl EventMonitor {
                 return TryResult::Retry(index);
             }
 
+            telemetry::global().ws_reconnect(&self.chain_id);
+
             TryResult::Ok(())
         })
         .unwrap_or_else(|retries| error!("failed to reconnect after {} retries", retries));
@@ -11,6 +11,7 @@ use ibc::ics02_client::client_consensus::{
     AnyConsensusState, AnyConsensusStateWithHeight, ConsensusState, QueryClientEventRequest,
 };
 use ibc::ics02_client::client_state::ClientState;
+use ibc::ics02_client::client_type::ClientType;
 use ibc::ics02_client::events::UpdateClient;
 use ibc::ics02_client::header::Header;
 use ibc::ics02_client::misbehaviour::AnyMisbehaviour;
@@ -18,6 +19,7 @@ use ibc::ics02_client::msgs::create_client::MsgCreateAnyClient;
 use ibc::ics02_client::msgs::misbehavior::MsgSubmitAnyMisbehaviour;
 use ibc::ics02_client::msgs::update_client::MsgUpdateAnyClient;
 use ibc::ics02_client::msgs::upgrade_client::MsgUpgradeAnyClient;
+use ibc::ics09_localhost::client_state::ClientState as LocalhostClientState;
 use ibc::ics24_host::identifier::{ChainId, ClientId};
 use ibc::query::QueryTxRequest;
 use ibc::timestamp::Timestamp;
@@ -26,7 +28,9 @@ use ibc::Height;
 use ibc_proto::ibc::core::client::v1::QueryConsensusStatesRequest;
 
 use crate::chain::handle::ChainHandle;
+use crate::error::Kind;
 use crate::relay::MAX_ITER;
+use crate::telemetry;
 
 const MAX_MISBEHAVIOUR_CHECK_DURATION: Duration = Duration::from_secs(120);
 
@@ -61,6 +65,12 @@ pub enum ForeignClientError {
 
     #[error("failed while trying to upgrade client id {0} with error: {1}")]
     ClientUpgrade(ClientId, String),
+
+    #[error("chain {1} committed to no upgraded client state at height {2}, client {0} cannot be upgraded")]
+    EmptyUpgradedClientState(ClientId, ChainId, Height),
+
+    #[error("client {0} is a solo machine client, which only the solo machine itself can update")]
+    SoloMachineClient(ClientId),
 }
 
 #[derive(Clone, Debug)]
@@ -99,7 +109,7 @@ impl ForeignClient {
         // Sanity check
         if src_chain.id().eq(&dst_chain.id()) {
             return Err(ForeignClientError::ClientCreate(format!(
-                "the source ({}) and destination ({}) chains must be different",
+                "the source ({}) and destination ({}) chains must be different, use a localhost client instead",
                 src_chain.id(),
                 dst_chain.id(),
             )));
@@ -116,6 +126,26 @@ impl ForeignClient {
         Ok(client)
     }
 
+    /// Creates a new localhost (loopback) client on `chain`, i.e., a client of `chain` hosted on
+    /// `chain` itself. Connections and channels built on top of this client link two modules
+    /// of the same chain. Blocks until the client is created, or an error occurs.
+    pub fn new_localhost(chain: Box<dyn ChainHandle>) -> Result<ForeignClient, ForeignClientError> {
+        let mut client = ForeignClient {
+            id: ClientId::default(),
+            dst_chain: chain.clone(),
+            src_chain: chain,
+        };
+
+        client.create()?;
+
+        Ok(client)
+    }
+
+    /// Returns true if this is a localhost client, i.e., if it is hosted by the chain it verifies.
+    pub fn is_localhost(&self) -> bool {
+        self.src_chain.id() == self.dst_chain.id()
+    }
+
     pub fn restore(
         client_id: &ClientId,
         dst_chain: Box<dyn ChainHandle>,
@@ -141,6 +171,9 @@ impl ForeignClient {
         let height = Height::new(expected_target_chain.id().version(), 0);
 
         match host_chain.query_client_state(&client_id, height) {
+            Ok(cs) if cs.client_type() == ClientType::SoloMachine => {
+                Err(ForeignClientError::SoloMachineClient(client_id.clone()))
+            }
             Ok(cs) => {
                 if cs.chain_id() != expected_target_chain.id() {
                     Err(ForeignClientError::ClientFind(
@@ -165,19 +198,10 @@ impl ForeignClient {
         }
     }
 
-    pub fn upgrade(&self) -> Result<Vec<IbcEvent>, ForeignClientError> {
-        // Fetch the latest height of the source chain.
-        let src_height = self.src_chain.query_latest_height().map_err(|e| {
-            ForeignClientError::ClientUpgrade(
-                self.id.clone(),
-                format!(
-                    "failed while querying src chain ({}) for latest height: {}",
-                    self.src_chain.id(),
-                    e
-                ),
-            )
-        })?;
-
+    /// Upgrades the client to the client and consensus states which the source chain committed
+    /// to for its upgrade at `src_height`, the height of the upgrade plan, after updating the
+    /// client to that height.
+    pub fn upgrade(&self, src_height: Height) -> Result<Vec<IbcEvent>, ForeignClientError> {
         info!("[{}] upgrade Height: {}", self, src_height);
 
         let mut msgs = self.build_update_client(src_height)?;
@@ -186,15 +210,20 @@ impl ForeignClient {
         let (client_state, proof_upgrade_client) = self
             .src_chain
             .query_upgraded_client_state(src_height)
-            .map_err(|e| {
-                ForeignClientError::ClientUpgrade(
+            .map_err(|e| match e.kind() {
+                Kind::EmptyUpgradedClientState => ForeignClientError::EmptyUpgradedClientState(
+                    self.id.clone(),
+                    self.src_chain.id(),
+                    src_height,
+                ),
+                _ => ForeignClientError::ClientUpgrade(
                     self.id.clone(),
                     format!(
                         "failed while fetching from chain {} the upgraded client state: {}",
                         self.src_chain.id(),
                         e
                     ),
-                )
+                ),
             })?;
 
         debug!("[{}] upgraded client state {:?}", self, client_state);
@@ -283,7 +312,7 @@ impl ForeignClient {
             ))
         })?;
 
-        let client_state = self
+        let src_client_state = self
             .src_chain
             .build_client_state(latest_height)
             .map_err(|e| {
@@ -296,10 +325,17 @@ impl ForeignClient {
             .wrap_any();
 
         let consensus_state = self.src_chain
-            .build_consensus_state(client_state.latest_height(),  latest_height, client_state.clone())
+            .build_consensus_state(src_client_state.latest_height(),  latest_height, src_client_state.clone())
             .map_err(|e| ForeignClientError::ClientCreate(format!("failed while building client consensus state from src chain ({}) with error: {}", self.src_chain.id(), e)))?
             .wrap_any();
 
+        // A localhost client only tracks the height of its host, whose consensus states it stores.
+        let client_state = if self.is_localhost() {
+            LocalhostClientState::new(self.src_chain.id(), latest_height).wrap_any()
+        } else {
+            src_client_state
+        };
+
         //TODO Get acct_prefix
         let msg = MsgCreateAnyClient::new(client_state, consensus_state, signer).map_err(|e| {
             ForeignClientError::ClientCreate(format!(
@@ -424,6 +460,11 @@ impl ForeignClient {
                 ))
             })?;
 
+        // The headers of a solo machine are signed by the solo machine, not built by the relayer.
+        if client_state.client_type() == ClientType::SoloMachine {
+            return Err(ForeignClientError::SoloMachineClient(self.id.clone()));
+        }
+
         // If not specified, set trusted state to the highest height smaller than target height.
         // Otherwise ensure that a consensus state at trusted height exists on-chain.
         let cs_heights = self.consensus_state_heights()?;
@@ -460,6 +501,21 @@ impl ForeignClient {
             return Ok(vec![]);
         }
 
+        // The header is ignored by localhost clients, which update to the latest height of their
+        // host. The light client of the host still needs a client state of its own type, though.
+        let client_state = if self.is_localhost() {
+            self.src_chain()
+                .build_client_state(trusted_height)
+                .map_err(|e| {
+                    ForeignClientError::ClientUpdate(format!(
+                        "failed building client state with error: {}",
+                        e
+                    ))
+                })?
+        } else {
+            client_state
+        };
+
         let header = self
             .src_chain()
             .build_header(trusted_height, target_height, client_state)
@@ -490,6 +546,8 @@ impl ForeignClient {
             signer,
         };
 
+        telemetry::global().client_update(&self.dst_chain.id(), &self.id);
+
         Ok(vec![new_msg.to_any()])
     }
 
@@ -715,6 +773,25 @@ impl ForeignClient {
                 ))
             })?;
 
+        // The misbehaviour of a solo machine consists of two conflicting signatures, which are
+        // not observable on the source chain: the evidence must be submitted by its operators.
+        if client_state.client_type() == ClientType::SoloMachine {
+            debug!(
+                "[{}] skipping misbehaviour detection for solo machine client",
+                self
+            );
+            return Ok(None);
+        }
+
+        // The consensus states of a localhost client are read from its host, they cannot conflict.
+        if client_state.client_type() == ClientType::Localhost {
+            debug!(
+                "[{}] skipping misbehaviour detection for localhost client",
+                self
+            );
+            return Ok(None);
+        }
+
         // Get the list of consensus state heights in descending order.
         // Note: If chain does not prune consensus states then the last consensus state is
         // the one installed by the `CreateClient` which does not include a header.
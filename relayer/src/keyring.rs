@@ -1,6 +1,7 @@
+pub mod encrypted;
 pub mod errors;
 
-use std::convert::{TryFrom, TryInto};
+use std::convert::TryFrom;
 use std::fs::{self, File};
 use std::path::{Path, PathBuf};
 use std::str::FromStr;
@@ -13,17 +14,24 @@ use bitcoin::{
     util::bip32::{DerivationPath, ExtendedPrivKey, ExtendedPubKey},
 };
 use hdpath::StandardHDPath;
-use k256::ecdsa::{signature::Signer, Signature, SigningKey};
+use k256::ecdsa::{
+    recoverable,
+    signature::{DigestSigner, Signer},
+    Signature, SigningKey,
+};
 use ripemd160::Ripemd160;
 use serde::{Deserialize, Serialize};
 use sha2::{Digest, Sha256};
+use sha3::Keccak256;
 
 use crate::config::ChainConfig;
 
+use encrypted::{Encrypted, Passphrase};
 use errors::{Error, Kind};
 
 pub const KEYSTORE_DEFAULT_FOLDER: &str = ".hermes/keys/";
 pub const KEYSTORE_DISK_BACKEND: &str = "keyring-test"; // TODO: Change to "keyring"
+pub const KEYSTORE_ENCRYPTED_BACKEND: &str = "keyring-encrypted";
 pub const KEYSTORE_FILE_EXTENSION: &str = "json";
 
 /// [Coin type][coin-type] associated with a key.
@@ -39,6 +47,9 @@ impl CoinType {
     /// Atom (Cosmos) coin type with number 118.
     pub const ATOM: CoinType = CoinType(118);
 
+    /// Ether coin type with number 60, used by the Ethermint-based chains.
+    pub const ETH: CoinType = CoinType(60);
+
     pub fn new(coin_type: u32) -> Self {
         Self(coin_type)
     }
@@ -62,6 +73,41 @@ impl FromStr for CoinType {
     }
 }
 
+/// Type of the secp256k1 keys of an account, which determines how its address is derived,
+/// how its public key is encoded in transactions and how it signs messages.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum KeyType {
+    /// Cosmos SDK keys: SHA-256 + RIPEMD-160 addresses and SHA-256 signature digests.
+    Secp256k1,
+    /// Ethermint keys: Keccak-256 addresses and Keccak-256 signature digests.
+    EthSecp256k1,
+}
+
+impl KeyType {
+    /// The coin type used to derive the keys of this type when none is specified.
+    pub fn default_coin_type(&self) -> CoinType {
+        match self {
+            Self::Secp256k1 => CoinType::ATOM,
+            Self::EthSecp256k1 => CoinType::ETH,
+        }
+    }
+
+    /// The type URL of the protobuf encoding of the public keys of this type.
+    pub fn pubkey_type_url(&self) -> &'static str {
+        match self {
+            Self::Secp256k1 => "/cosmos.crypto.secp256k1.PubKey",
+            Self::EthSecp256k1 => "/ethermint.crypto.v1.ethsecp256k1.PubKey",
+        }
+    }
+}
+
+impl Default for KeyType {
+    fn default() -> Self {
+        Self::Secp256k1
+    }
+}
+
 /// Key entry stores the Private Key and Public Key as well the address
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct KeyEntry {
@@ -79,6 +125,10 @@ pub struct KeyEntry {
 
     /// Coin type
     pub coin_type: CoinType,
+
+    /// Key type
+    #[serde(default)]
+    pub key_type: KeyType,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
@@ -95,14 +145,23 @@ impl TryFrom<KeyFile> for KeyEntry {
     type Error = Error;
 
     fn try_from(key_file: KeyFile) -> Result<Self, Self::Error> {
+        Self::from_key_file(key_file, KeyType::Secp256k1)
+    }
+}
+
+impl KeyEntry {
+    /// Decode a key entry of the given type from a key file.
+    pub fn from_key_file(key_file: KeyFile, key_type: KeyType) -> Result<Self, Error> {
         // Decode the Bech32-encoded address from the key file
         let keyfile_address_bytes = decode_bech32(&key_file.address)?;
 
         // Decode the Bech32-encoded public key from the key file
         let mut keyfile_pubkey_bytes = decode_bech32(&key_file.pubkey)?;
 
-        // Use coin type if present or default coin type (ie. Atom).
-        let coin_type = key_file.coin_type.unwrap_or_default();
+        // Use coin type if present or the default coin type of the key type.
+        let coin_type = key_file
+            .coin_type
+            .unwrap_or_else(|| key_type.default_coin_type());
 
         // Decode the private key from the mnemonic
         let private_key = private_key_from_mnemonic(&key_file.mnemonic, coin_type)?;
@@ -133,6 +192,7 @@ impl TryFrom<KeyFile> for KeyEntry {
             account: key_file.address,
             address: keyfile_address_bytes,
             coin_type,
+            key_type,
         })
     }
 }
@@ -192,6 +252,17 @@ impl Test {
     }
 }
 
+impl Test {
+    /// Deletes the key file of this store, e.g. once the key was migrated to another store.
+    pub fn delete_key(&self) -> Result<(), Error> {
+        let mut filename = self.store.join(&self.key_name);
+        filename.set_extension(KEYSTORE_FILE_EXTENSION);
+
+        fs::remove_file(filename)
+            .map_err(|_| Kind::KeyStore.context("error deleting the key file").into())
+    }
+}
+
 impl KeyStore for Test {
     fn get_key(&self) -> Result<KeyEntry, Error> {
         let mut filename = self.store.join(&self.key_name);
@@ -224,16 +295,35 @@ impl KeyStore for Test {
     }
 }
 
-#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
 pub enum Store {
     Memory,
     Test,
+    Encrypted,
+}
+
+impl Default for Store {
+    fn default() -> Self {
+        Self::Test
+    }
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub enum KeyRing {
     Memory(Memory),
     Test(Test),
+    Encrypted(Encrypted),
+}
+
+/// Reads the passphrase of the key store of the given chain if it is encrypted, prompting
+/// for it if needed, so that the key store can later be opened without prompting.
+pub fn unlock(chain_config: &ChainConfig) -> Result<(), Error> {
+    if chain_config.key_store_type == Store::Encrypted {
+        Passphrase::read(chain_config)?;
+    }
+
+    Ok(())
 }
 
 impl KeyRing {
@@ -242,14 +332,7 @@ impl KeyRing {
             Store::Memory => Ok(Self::Memory(Memory::new(chain_config.account_prefix, None))),
 
             Store::Test => {
-                let keys_folder = disk_store_path(chain_config.id.as_str()).map_err(|e| {
-                    Kind::KeyStore.context(format!("failed to compute keys folder path: {:?}", e))
-                })?;
-
-                // Create keys folder if it does not exist
-                fs::create_dir_all(&keys_folder).map_err(|e| {
-                    Kind::KeyStore.context(format!("failed to create keys folder: {:?}", e))
-                })?;
+                let keys_folder = keys_folder(chain_config.id.as_str(), KEYSTORE_DISK_BACKEND)?;
 
                 Ok(Self::Test(Test::new(
                     chain_config.key_name,
@@ -257,6 +340,19 @@ impl KeyRing {
                     keys_folder,
                 )))
             }
+
+            Store::Encrypted => {
+                let keys_folder =
+                    keys_folder(chain_config.id.as_str(), KEYSTORE_ENCRYPTED_BACKEND)?;
+                let passphrase = Passphrase::lookup(&chain_config)?;
+
+                Ok(Self::Encrypted(Encrypted::new(
+                    chain_config.key_name,
+                    chain_config.account_prefix,
+                    keys_folder,
+                    passphrase,
+                )))
+            }
         }
     }
 
@@ -264,6 +360,7 @@ impl KeyRing {
         match self {
             KeyRing::Memory(m) => m.get_key(),
             KeyRing::Test(d) => d.get_key(),
+            KeyRing::Encrypted(e) => e.get_key(),
         }
     }
 
@@ -271,15 +368,20 @@ impl KeyRing {
         match self {
             KeyRing::Memory(m) => m.add_key(key_entry),
             KeyRing::Test(d) => d.add_key(key_entry),
+            KeyRing::Encrypted(e) => e.add_key(key_entry),
         }
     }
 
     /// Get key from seed file
-    pub fn key_from_seed_file(&self, key_file_content: &str) -> Result<KeyEntry, Error> {
+    pub fn key_from_seed_file(
+        &self,
+        key_file_content: &str,
+        key_type: KeyType,
+    ) -> Result<KeyEntry, Error> {
         let key_file: KeyFile =
             serde_json::from_str(key_file_content).map_err(|e| Kind::InvalidKey.context(e))?;
 
-        key_file.try_into()
+        KeyEntry::from_key_file(key_file, key_type)
     }
 
     /// Add a key entry in the store using a mnemonic.
@@ -287,6 +389,7 @@ impl KeyRing {
         &self,
         mnemonic_words: &str,
         coin_type: CoinType,
+        key_type: KeyType,
     ) -> Result<KeyEntry, Error> {
         // Get the private key from the mnemonic
         let private_key = private_key_from_mnemonic(mnemonic_words, coin_type)?;
@@ -295,7 +398,7 @@ impl KeyRing {
         let public_key = ExtendedPubKey::from_private(&Secp256k1::new(), &private_key);
 
         // Get address from the public Key
-        let address = get_address(public_key);
+        let address = get_address(public_key, key_type);
 
         // Compute Bech32 account
         let account = bech32::encode(self.account_prefix(), address.to_base32(), Variant::Bech32)
@@ -307,6 +410,7 @@ impl KeyRing {
             account,
             address,
             coin_type,
+            key_type,
         })
     }
 
@@ -319,14 +423,25 @@ impl KeyRing {
             Kind::InvalidKey.context("could not build signing key from private key bytes")
         })?;
 
-        let signature: Signature = signing_key.sign(&msg);
-        Ok(signature.as_ref().to_vec())
+        match key.key_type {
+            KeyType::Secp256k1 => {
+                let signature: Signature = signing_key.sign(&msg);
+                Ok(signature.as_ref().to_vec())
+            }
+            KeyType::EthSecp256k1 => {
+                // Ethermint expects a recoverable `r || s || v` signature of the Keccak-256 digest
+                let digest = Keccak256::new().chain(&msg);
+                let signature: recoverable::Signature = signing_key.sign_digest(digest);
+                Ok(signature.as_ref().to_vec())
+            }
+        }
     }
 
     pub fn account_prefix(&self) -> &str {
         match self {
             KeyRing::Memory(m) => &m.account_prefix,
             KeyRing::Test(d) => &d.account_prefix,
+            KeyRing::Encrypted(e) => &e.account_prefix,
         }
     }
 }
@@ -354,7 +469,15 @@ fn private_key_from_mnemonic(
 }
 
 /// Return an address from a Public Key
-fn get_address(pk: ExtendedPubKey) -> Vec<u8> {
+fn get_address(pk: ExtendedPubKey, key_type: KeyType) -> Vec<u8> {
+    match key_type {
+        KeyType::Secp256k1 => get_cosmos_address(pk),
+        KeyType::EthSecp256k1 => get_eth_address(pk),
+    }
+}
+
+/// The RIPEMD-160 hash of the SHA-256 hash of the compressed public key.
+fn get_cosmos_address(pk: ExtendedPubKey) -> Vec<u8> {
     let mut hasher = Sha256::new();
     hasher.update(pk.public_key.to_bytes().as_slice());
 
@@ -369,6 +492,18 @@ fn get_address(pk: ExtendedPubKey) -> Vec<u8> {
     rip_result.to_vec()
 }
 
+/// The last 20 bytes of the Keccak-256 hash of the uncompressed public key,
+/// stripped of its `0x04` prefix.
+fn get_eth_address(pk: ExtendedPubKey) -> Vec<u8> {
+    let uncompressed = pk.public_key.key.serialize_uncompressed();
+
+    let mut hasher = Keccak256::new();
+    hasher.update(&uncompressed[1..]);
+    let pk_hash = hasher.finalize();
+
+    pk_hash[pk_hash.len() - 20..].to_vec()
+}
+
 fn decode_bech32(input: &str) -> Result<Vec<u8>, Error> {
     use bech32::FromBase32;
 
@@ -379,14 +514,66 @@ fn decode_bech32(input: &str) -> Result<Vec<u8>, Error> {
     Ok(bytes)
 }
 
-fn disk_store_path(folder_name: &str) -> Result<PathBuf, Error> {
+/// Returns the folder of the given key store backend for a chain, creating it if needed.
+fn keys_folder(folder_name: &str, backend: &str) -> Result<PathBuf, Error> {
+    let keys_folder = disk_store_path(folder_name, backend).map_err(|e| {
+        Kind::KeyStore.context(format!("failed to compute keys folder path: {:?}", e))
+    })?;
+
+    // Create keys folder if it does not exist
+    fs::create_dir_all(&keys_folder)
+        .map_err(|e| Kind::KeyStore.context(format!("failed to create keys folder: {:?}", e)))?;
+
+    Ok(keys_folder)
+}
+
+fn disk_store_path(folder_name: &str, backend: &str) -> Result<PathBuf, Error> {
     let home = dirs_next::home_dir()
         .ok_or_else(|| Kind::KeyStore.context("cannot retrieve home folder location"))?;
 
     let folder = Path::new(home.as_path())
         .join(KEYSTORE_DEFAULT_FOLDER)
         .join(folder_name)
-        .join(KEYSTORE_DISK_BACKEND);
+        .join(backend);
 
     Ok(folder)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{get_address, private_key_from_mnemonic, CoinType, KeyType};
+    use bitcoin::{secp256k1::Secp256k1, util::bip32::ExtendedPubKey};
+
+    const MNEMONIC: &str = "abandon abandon abandon abandon abandon abandon abandon abandon \
+                            abandon abandon abandon about";
+
+    fn address(coin_type: CoinType, key_type: KeyType) -> String {
+        let private_key = private_key_from_mnemonic(MNEMONIC, coin_type).unwrap();
+        let public_key = ExtendedPubKey::from_private(&Secp256k1::new(), &private_key);
+        hex::encode(get_address(public_key, key_type))
+    }
+
+    #[test]
+    fn derive_eth_secp256k1_address() {
+        assert_eq!(
+            address(
+                KeyType::EthSecp256k1.default_coin_type(),
+                KeyType::EthSecp256k1
+            ),
+            "9858effd232b4033e47d90003d41ec34ecaeda94"
+        );
+    }
+
+    #[test]
+    fn derive_secp256k1_address() {
+        let address = address(KeyType::Secp256k1.default_coin_type(), KeyType::Secp256k1);
+        let account = bech32::encode(
+            "cosmos",
+            bech32::ToBase32::to_base32(&hex::decode(address).unwrap()),
+            bech32::Variant::Bech32,
+        )
+        .unwrap();
+
+        assert_eq!(account, "cosmos19rl4cm2hmr8afy4kldpxz3fka4jguq0auqdal4");
+    }
+}
@@ -0,0 +1,330 @@
+//! Key store keeping each key entry in a file encrypted with a passphrase.
+//!
+//! The encryption key is derived from the passphrase with scrypt, and the key entry
+//! is encrypted and authenticated with ChaCha20-Poly1305.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::fs::File;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use chacha20poly1305::aead::{Aead, NewAead};
+use chacha20poly1305::{ChaCha20Poly1305, Key, Nonce};
+use ibc::ics24_host::identifier::ChainId;
+use once_cell::sync::Lazy;
+use rand::{rngs::OsRng, RngCore};
+use serde::{Deserialize, Serialize};
+
+use crate::config::ChainConfig;
+
+use super::errors::{Error, Kind};
+use super::{KeyEntry, KeyStore, KEYSTORE_FILE_EXTENSION};
+
+/// Environment variable from which the passphrase of the encrypted key store is read.
+pub const KEYSTORE_PASSPHRASE_ENV_VAR: &str = "HERMES_KEYRING_PASSPHRASE";
+
+const SALT_LEN: usize = 32;
+const NONCE_LEN: usize = 12;
+const KEY_LEN: usize = 32;
+
+/// Default scrypt parameters, as recommended for interactive logins.
+const SCRYPT_LOG_N: u8 = 15;
+const SCRYPT_R: u32 = 8;
+const SCRYPT_P: u32 = 1;
+
+/// Passphrases already read in this process, so that each one is read only once.
+static PASSPHRASES: Lazy<Mutex<HashMap<ChainId, Passphrase>>> = Lazy::new(Default::default);
+
+/// A passphrase, which is never displayed.
+#[derive(Clone, Default)]
+pub struct Passphrase(String);
+
+impl Passphrase {
+    pub fn new(passphrase: String) -> Self {
+        Self(passphrase)
+    }
+
+    /// Reads the passphrase of the encrypted key store of the given chain from, in order:
+    /// the `HERMES_KEYRING_PASSPHRASE` environment variable, the `key_passphrase_file`
+    /// of the chain configuration, or a prompt on the terminal.
+    ///
+    /// The passphrase is cached for the lifetime of the process. This is meant to be called
+    /// by the CLI, before it spawns the chain runtimes, so that the prompt never comes from
+    /// a runtime thread.
+    pub fn read(chain_config: &ChainConfig) -> Result<Self, Error> {
+        if let Some(passphrase) = Self::lookup_non_interactive(chain_config)? {
+            return Ok(passphrase);
+        }
+
+        let prompt = format!(
+            "Passphrase of key '{}' for chain {}: ",
+            chain_config.key_name, chain_config.id
+        );
+
+        let passphrase = rpassword::read_password_from_tty(Some(&prompt))
+            .map(Self)
+            .map_err(|e| Kind::Passphrase.context(e))?;
+
+        Ok(Self::cache(chain_config, passphrase))
+    }
+
+    /// Looks up the passphrase of the encrypted key store of the given chain without
+    /// prompting for it: either it was already read with [`Passphrase::read`], or it is
+    /// taken from the environment variable or the passphrase file.
+    pub fn lookup(chain_config: &ChainConfig) -> Result<Self, Error> {
+        Self::lookup_non_interactive(chain_config)?.ok_or_else(|| {
+            Kind::Passphrase
+                .context(format!(
+                    "the passphrase of the key store of chain {} was not provided, \
+                     set `key_passphrase_file` or the {} environment variable",
+                    chain_config.id, KEYSTORE_PASSPHRASE_ENV_VAR
+                ))
+                .into()
+        })
+    }
+
+    fn lookup_non_interactive(chain_config: &ChainConfig) -> Result<Option<Self>, Error> {
+        if let Some(passphrase) = PASSPHRASES.lock().unwrap().get(&chain_config.id) {
+            return Ok(Some(passphrase.clone()));
+        }
+
+        if let Ok(passphrase) = std::env::var(KEYSTORE_PASSPHRASE_ENV_VAR) {
+            return Ok(Some(Self::cache(chain_config, Self(passphrase))));
+        }
+
+        if let Some(path) = &chain_config.key_passphrase_file {
+            let passphrase = std::fs::read_to_string(path)
+                .map_err(|e| Kind::Passphrase.context(format!("cannot read {:?}: {}", path, e)))?;
+
+            let passphrase = Self(passphrase.trim_end_matches(&['\r', '\n'][..]).to_string());
+            return Ok(Some(Self::cache(chain_config, passphrase)));
+        }
+
+        Ok(None)
+    }
+
+    fn cache(chain_config: &ChainConfig, passphrase: Self) -> Self {
+        PASSPHRASES
+            .lock()
+            .unwrap()
+            .insert(chain_config.id.clone(), passphrase.clone());
+
+        passphrase
+    }
+}
+
+impl fmt::Debug for Passphrase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Passphrase(<redacted>)")
+    }
+}
+
+/// Content of an encrypted key file.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EncryptedKeyFile {
+    pub scrypt_log_n: u8,
+    pub scrypt_r: u32,
+    pub scrypt_p: u32,
+    /// Hex-encoded scrypt salt
+    pub salt: String,
+    /// Hex-encoded ChaCha20-Poly1305 nonce
+    pub nonce: String,
+    /// Hex-encoded encryption of the JSON-encoded key entry
+    pub ciphertext: String,
+}
+
+impl EncryptedKeyFile {
+    /// Encrypts the given key entry with the passphrase, using a fresh salt and nonce.
+    pub fn encrypt(key_entry: &KeyEntry, passphrase: &Passphrase) -> Result<Self, Error> {
+        let mut salt = [0u8; SALT_LEN];
+        let mut nonce = [0u8; NONCE_LEN];
+        OsRng.fill_bytes(&mut salt);
+        OsRng.fill_bytes(&mut nonce);
+
+        let key = derive_key(passphrase, &salt, SCRYPT_LOG_N, SCRYPT_R, SCRYPT_P)?;
+
+        let plaintext =
+            serde_json::to_vec(key_entry).map_err(|e| Kind::Encryption.context(e.to_string()))?;
+
+        let ciphertext = ChaCha20Poly1305::new(Key::from_slice(&key))
+            .encrypt(Nonce::from_slice(&nonce), plaintext.as_slice())
+            .map_err(|_| Kind::Encryption.context("cannot encrypt key entry"))?;
+
+        Ok(Self {
+            scrypt_log_n: SCRYPT_LOG_N,
+            scrypt_r: SCRYPT_R,
+            scrypt_p: SCRYPT_P,
+            salt: hex::encode(salt),
+            nonce: hex::encode(nonce),
+            ciphertext: hex::encode(ciphertext),
+        })
+    }
+
+    /// Decrypts the key entry with the passphrase, failing if either the passphrase
+    /// is wrong or the file was tampered with.
+    pub fn decrypt(&self, passphrase: &Passphrase) -> Result<KeyEntry, Error> {
+        let decode = |field: &str| {
+            hex::decode(field).map_err(|e| Kind::Encryption.context(format!("invalid hex: {}", e)))
+        };
+
+        let salt = decode(&self.salt)?;
+        let nonce = decode(&self.nonce)?;
+        let ciphertext = decode(&self.ciphertext)?;
+
+        if nonce.len() != NONCE_LEN {
+            return Err(Kind::Encryption.context("invalid nonce length").into());
+        }
+
+        let key = derive_key(
+            passphrase,
+            &salt,
+            self.scrypt_log_n,
+            self.scrypt_r,
+            self.scrypt_p,
+        )?;
+
+        let plaintext = ChaCha20Poly1305::new(Key::from_slice(&key))
+            .decrypt(Nonce::from_slice(&nonce), ciphertext.as_slice())
+            .map_err(|_| {
+                Kind::Encryption.context("cannot decrypt key entry, the passphrase may be wrong")
+            })?;
+
+        serde_json::from_slice(&plaintext)
+            .map_err(|e| Kind::Encryption.context(e.to_string()).into())
+    }
+}
+
+fn derive_key(
+    passphrase: &Passphrase,
+    salt: &[u8],
+    log_n: u8,
+    r: u32,
+    p: u32,
+) -> Result<[u8; KEY_LEN], Error> {
+    let params =
+        scrypt::Params::new(log_n, r, p).map_err(|e| Kind::Encryption.context(e.to_string()))?;
+
+    let mut key = [0u8; KEY_LEN];
+    scrypt::scrypt(passphrase.0.as_bytes(), salt, &params, &mut key)
+        .map_err(|e| Kind::Encryption.context(e.to_string()))?;
+
+    Ok(key)
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Encrypted {
+    pub(super) key_name: String,
+    pub(super) account_prefix: String,
+    store: PathBuf,
+    #[serde(skip)]
+    passphrase: Passphrase,
+}
+
+impl Encrypted {
+    pub fn new(
+        key_name: String,
+        account_prefix: String,
+        store: PathBuf,
+        passphrase: Passphrase,
+    ) -> Self {
+        Self {
+            key_name,
+            account_prefix,
+            store,
+            passphrase,
+        }
+    }
+
+    fn key_file(&self) -> PathBuf {
+        let mut filename = self.store.join(&self.key_name);
+        filename.set_extension(KEYSTORE_FILE_EXTENSION);
+        filename
+    }
+}
+
+impl KeyStore for Encrypted {
+    fn get_key(&self) -> Result<KeyEntry, Error> {
+        let filename = self.key_file();
+
+        if !filename.as_path().exists() {
+            return Err(Kind::KeyStore.context("cannot find key file").into());
+        }
+
+        let file =
+            File::open(filename).map_err(|_| Kind::KeyStore.context("cannot open key file"))?;
+
+        let key_file: EncryptedKeyFile = serde_json::from_reader(file)
+            .map_err(|_| Kind::KeyStore.context("cannot read key file"))?;
+
+        key_file.decrypt(&self.passphrase)
+    }
+
+    fn add_key(&mut self, key_entry: KeyEntry) -> Result<(), Error> {
+        let key_file = EncryptedKeyFile::encrypt(&key_entry, &self.passphrase)?;
+        write_key_file(&self.key_file(), &key_file)
+    }
+}
+
+/// Writes the key file, readable and writable only by its owner on Unix.
+fn write_key_file(path: &Path, key_file: &EncryptedKeyFile) -> Result<(), Error> {
+    let mut options = std::fs::OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
+    }
+
+    let file = options
+        .open(path)
+        .map_err(|_| Kind::KeyStore.context("error creating the key file"))?;
+
+    serde_json::to_writer_pretty(file, key_file)
+        .map_err(|_| Kind::KeyStore.context("error writing the key file").into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{EncryptedKeyFile, Passphrase};
+    use crate::keyring::{KeyRing, Store};
+
+    #[test]
+    fn encrypt_decrypt_roundtrip() {
+        let chain_config = crate::chain::mock::test_utils::get_basic_chain_config("chain_a");
+        let keyring = KeyRing::new(Store::Memory, chain_config).unwrap();
+
+        let mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon \
+                        abandon abandon abandon about";
+        let key_entry = keyring
+            .key_from_mnemonic(mnemonic, Default::default(), Default::default())
+            .unwrap();
+
+        let passphrase = Passphrase::new("correct horse battery staple".to_string());
+        let key_file = EncryptedKeyFile::encrypt(&key_entry, &passphrase).unwrap();
+        assert!(!key_file.ciphertext.contains(&key_entry.account));
+
+        assert_eq!(key_file.decrypt(&passphrase).unwrap(), key_entry);
+
+        let wrong = Passphrase::new("wrong".to_string());
+        assert!(key_file.decrypt(&wrong).is_err());
+    }
+
+    #[test]
+    fn passphrase_is_read_once() {
+        let path = std::env::temp_dir().join("hermes_passphrase_is_read_once");
+        std::fs::write(&path, "correct horse battery staple\n").unwrap();
+
+        let mut chain_config =
+            crate::chain::mock::test_utils::get_basic_chain_config("chain_passphrase_cache");
+        chain_config.key_passphrase_file = Some(path.clone());
+
+        let passphrase = Passphrase::lookup(&chain_config).unwrap();
+        assert_eq!(passphrase.0, "correct horse battery staple");
+
+        // The passphrase is not read again once known
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(Passphrase::lookup(&chain_config).unwrap().0, passphrase.0);
+    }
+}
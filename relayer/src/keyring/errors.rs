@@ -34,6 +34,12 @@ pub enum Kind {
 
     #[error("invalid HD path: {0}")]
     InvalidHdPath(String),
+
+    #[error("cannot read the passphrase of the key store")]
+    Passphrase,
+
+    #[error("key encryption error")]
+    Encryption,
 }
 
 impl Kind {
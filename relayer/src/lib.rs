@@ -13,6 +13,7 @@
 //!
 //! [Hermes]: https://docs.rs/ibc-relayer-cli/0.2.0/
 
+pub mod admin;
 pub mod chain;
 pub mod channel;
 pub mod config;
@@ -27,6 +28,7 @@ pub mod macros;
 pub mod registry;
 pub mod relay;
 pub mod supervisor;
+pub mod telemetry;
 pub mod transfer;
 pub mod upgrade_chain;
 pub mod util;
@@ -5,6 +5,7 @@ use crate::error;
 use ibc::ics02_client::events::UpdateClient;
 use ibc::ics02_client::misbehaviour::AnyMisbehaviour;
 
+pub mod disabled;
 pub mod tendermint;
 
 #[cfg(test)]
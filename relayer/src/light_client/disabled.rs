@@ -0,0 +1,47 @@
+use ibc::ics02_client::client_state::AnyClientState;
+use ibc::ics02_client::events::UpdateClient;
+use ibc::ics02_client::misbehaviour::AnyMisbehaviour;
+use ibc::ics24_host::identifier::ChainId;
+use ibc::Height;
+
+use crate::chain::Chain;
+use crate::error::{Error, Kind};
+
+/// Stands in for the light client of a chain whose runtime was spawned without one,
+/// failing every request.
+pub struct LightClient {
+    chain_id: ChainId,
+}
+
+impl LightClient {
+    pub fn new(chain_id: ChainId) -> LightClient {
+        LightClient { chain_id }
+    }
+
+    fn error(&self) -> Error {
+        Kind::LightClientDisabled(self.chain_id.to_string()).into()
+    }
+}
+
+impl<C: Chain> super::LightClient<C> for LightClient {
+    fn verify(
+        &mut self,
+        _trusted: Height,
+        _target: Height,
+        _client_state: &AnyClientState,
+    ) -> Result<C::LightBlock, Error> {
+        Err(self.error())
+    }
+
+    fn fetch(&mut self, _height: Height) -> Result<C::LightBlock, Error> {
+        Err(self.error())
+    }
+
+    fn check_misbehaviour(
+        &mut self,
+        _update: UpdateClient,
+        _client_state: &AnyClientState,
+    ) -> Result<Option<AnyMisbehaviour>, Error> {
+        Err(self.error())
+    }
+}
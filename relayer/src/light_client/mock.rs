@@ -1,10 +1,9 @@
-use tendermint_testgen::light_block::TmLightBlock;
-
 use ibc::ics02_client::client_state::AnyClientState;
 use ibc::ics02_client::events::UpdateClient;
 use ibc::ics02_client::misbehaviour::AnyMisbehaviour;
 use ibc::ics24_host::identifier::ChainId;
-use ibc::mock::host::HostBlock;
+use ibc::mock::header::MockHeader;
+use ibc::mock::host::{HostBlock, HostType};
 use ibc::Height;
 
 use crate::chain::mock::MockChain;
@@ -24,8 +23,11 @@ impl LightClient {
     }
 
     /// Returns a LightBlock at the requested height `h`.
-    fn light_block(&self, h: Height) -> TmLightBlock {
-        HostBlock::generate_tm_block(self.chain_id.clone(), h.revision_height)
+    fn light_block(&self, h: Height) -> MockHeader {
+        match HostBlock::generate_block(self.chain_id.clone(), HostType::Mock, h.revision_height) {
+            HostBlock::Mock(header) => header,
+            HostBlock::SyntheticTendermint(_) => unreachable!("mock host blocks are mock headers"),
+        }
     }
 }
 
@@ -35,11 +37,11 @@ impl super::LightClient<MockChain> for LightClient {
         _trusted: Height,
         target: Height,
         _client_state: &AnyClientState,
-    ) -> Result<TmLightBlock, Error> {
+    ) -> Result<MockHeader, Error> {
         Ok(self.light_block(target))
     }
 
-    fn fetch(&mut self, height: Height) -> Result<TmLightBlock, Error> {
+    fn fetch(&mut self, height: Height) -> Result<MockHeader, Error> {
         Ok(self.light_block(height))
     }
 
@@ -48,6 +50,6 @@ impl super::LightClient<MockChain> for LightClient {
         _update: UpdateClient,
         _client_state: &AnyClientState,
     ) -> Result<Option<AnyMisbehaviour>, Error> {
-        unimplemented!()
+        Ok(None)
     }
 }
@@ -1,4 +1,5 @@
 use std::convert::TryFrom;
+use std::time::Duration;
 
 use tendermint_light_client::{
     components::{self, io::AtHeight},
@@ -9,7 +10,8 @@ use tendermint_light_client::{
     types::Height as TMHeight,
     types::{LightBlock, PeerId, Status},
 };
-use tendermint_rpc as rpc;
+use tendermint_rpc::{self as rpc, Client};
+use tracing::warn;
 
 use ibc::{
     downcast,
@@ -29,14 +31,47 @@ use crate::{
     chain::CosmosSdkChain,
     config::ChainConfig,
     error::{self, Error},
+    util::{block_on, failover::Failover},
 };
 
+use self::store::SledStore;
+
+pub mod store;
+
 pub struct LightClient {
     chain_id: ChainId,
-    peer_id: PeerId,
+    /// The RPC addresses of the nodes of the chain, and the one in use.
+    rpc_addrs: Failover<rpc::Url>,
+    rpc_timeout: Duration,
+    /// The node in use, once connected to.
+    peer: Option<Peer>,
+    /// Light blocks verified so far, persisted across restarts of the relayer.
+    store: SledStore,
+    pruning_window: usize,
+}
+
+/// A node from which the light client fetches the light blocks.
+struct Peer {
+    id: PeerId,
     io: components::io::ProdIo,
 }
 
+impl Peer {
+    fn connect(rpc_addr: &rpc::Url, timeout: Duration) -> Result<Self, Error> {
+        let rpc_client = rpc::HttpClient::new(rpc_addr.clone())
+            .map_err(|e| error::Kind::LightClient(rpc_addr.to_string()).context(e))?;
+
+        let status = block_on(tokio::time::timeout(timeout, rpc_client.status()))
+            .map_err(|e| error::Kind::Rpc(rpc_addr.clone()).context(e))?
+            .map_err(|e| error::Kind::Rpc(rpc_addr.clone()).context(e))?;
+
+        let id = status.node_info.id;
+        let io = components::io::ProdIo::new(id, rpc_client, Some(timeout));
+
+        Ok(Self { id, io })
+    }
+}
+
 impl super::LightClient<CosmosSdkChain> for LightClient {
     fn verify(
         &mut self,
@@ -48,11 +83,17 @@ impl super::LightClient<CosmosSdkChain> for LightClient {
             .map_err(|e| error::Kind::InvalidHeight.context(e))?;
 
         let client = self.prepare_client(client_state)?;
-        let mut state = self.prepare_state(trusted)?;
+        let mut state = self.prepare_state(trusted, target_height)?;
 
         let light_block = client
             .verify_to_target(target_height, &mut state)
-            .map_err(|e| error::Kind::LightClient(self.chain_id.to_string()).context(e))?;
+            .map_err(|e| {
+                // The node may have failed to serve the blocks needed for the verification.
+                self.failover();
+                error::Kind::LightClient(self.chain_id.to_string()).context(e)
+            })?;
+
+        self.persist_state(&state)?;
 
         Ok(light_block)
     }
@@ -139,20 +180,73 @@ impl super::LightClient<CosmosSdkChain> for LightClient {
 }
 
 impl LightClient {
-    pub fn from_config(config: &ChainConfig, peer_id: PeerId) -> Result<Self, Error> {
-        let rpc_client = rpc::HttpClient::new(config.rpc_addr.clone())
-            .map_err(|e| error::Kind::LightClient(config.rpc_addr.to_string()).context(e))?;
-
-        let io = components::io::ProdIo::new(peer_id, rpc_client, Some(config.rpc_timeout));
+    /// Creates a light client fetching its blocks from the nodes of the chain, starting with
+    /// the first one which can be reached, and failing over to the next one when it fails.
+    pub fn from_config(config: &ChainConfig) -> Result<Self, Error> {
+        let rpc_addrs = config
+            .endpoints()
+            .into_iter()
+            .map(|endpoints| endpoints.rpc_addr)
+            .collect();
+
+        let store_path = match &config.light_store_path {
+            Some(path) => path.clone(),
+            None => store::default_path(&config.id)?,
+        };
+        let store = SledStore::open(store_path)?;
 
-        Ok(Self {
+        let mut light_client = Self {
             chain_id: config.id.clone(),
-            peer_id,
-            io,
-        })
+            rpc_addrs: Failover::new(rpc_addrs),
+            rpc_timeout: config.rpc_timeout,
+            peer: None,
+            store,
+            pruning_window: config.light_store_pruning_window,
+        };
+
+        let mut attempts = light_client.rpc_addrs.len();
+        while let Err(e) = light_client.peer() {
+            attempts -= 1;
+            if attempts == 0 {
+                return Err(e);
+            }
+        }
+
+        Ok(light_client)
+    }
+
+    /// The node in use, connecting to it first if needed. If it cannot be reached,
+    /// the light client fails over to the next node.
+    fn peer(&mut self) -> Result<&Peer, Error> {
+        if self.peer.is_none() {
+            match Peer::connect(self.rpc_addrs.current(), self.rpc_timeout) {
+                Ok(peer) => self.peer = Some(peer),
+                Err(e) => {
+                    self.failover();
+                    return Err(e);
+                }
+            }
+        }
+
+        Ok(self.peer.as_ref().unwrap())
+    }
+
+    /// Switches to the next healthy node of the chain, after the node in use failed.
+    fn failover(&mut self) {
+        let failed = self.rpc_addrs.current().clone();
+        let next = self.rpc_addrs.failed();
+
+        if *next != failed {
+            warn!(
+                "[{}] light client node {} failed, failing over to node {}",
+                self.chain_id, failed, next
+            );
+        }
+
+        self.peer = None;
     }
 
-    fn prepare_client(&self, client_state: &AnyClientState) -> Result<TmLightClient, Error> {
+    fn prepare_client(&mut self, client_state: &AnyClientState) -> Result<TmLightClient, Error> {
         let clock = components::clock::SystemClock;
         let hasher = operations::hasher::ProdHasher;
         let verifier = components::verifier::ProdVerifier::default();
@@ -172,33 +266,79 @@ impl LightClient {
             clock_drift: client_state.max_clock_drift,
         };
 
+        let peer = self.peer()?;
+
         Ok(TmLightClient::new(
-            self.peer_id,
+            peer.id,
             params,
             clock,
             scheduler,
             verifier,
             hasher,
-            self.io.clone(),
+            peer.io.clone(),
         ))
     }
 
-    fn prepare_state(&self, trusted: ibc::Height) -> Result<LightClientState, Error> {
+    /// Builds the state of the light client for verifying up to `target`. Verification starts from
+    /// the highest block already verified in the persistent store below `target`, if any, and
+    /// otherwise from the block at the `trusted` height.
+    fn prepare_state(
+        &mut self,
+        trusted: ibc::Height,
+        target: TMHeight,
+    ) -> Result<LightClientState, Error> {
         let trusted_height = TMHeight::try_from(trusted.revision_height)
             .map_err(|e| error::Kind::InvalidHeight.context(e))?;
 
-        let trusted_block = self.fetch_light_block(AtHeight::At(trusted_height))?;
+        let trusted_block = match self.store.get_trusted_or_verified(trusted_height) {
+            Some(light_block) => light_block,
+            None => self.fetch_light_block(AtHeight::At(trusted_height))?,
+        };
 
         let mut store = MemoryStore::new();
         store.insert(trusted_block, Status::Trusted);
 
+        let highest_verified = self
+            .store
+            .all(Status::Trusted)
+            .chain(self.store.all(Status::Verified))
+            .filter(|lb| lb.height() > trusted_height && lb.height() <= target)
+            .max_by_key(|lb| lb.height());
+
+        if let Some(light_block) = highest_verified {
+            store.insert(light_block, Status::Verified);
+        }
+
         Ok(LightClientState::new(store))
     }
 
-    fn fetch_light_block(&self, height: AtHeight) -> Result<LightBlock, Error> {
+    /// Saves the blocks verified by the light client into the persistent store,
+    /// then prunes the store down to the configured window.
+    fn persist_state(&mut self, state: &LightClientState) -> Result<(), Error> {
+        for status in &[Status::Trusted, Status::Verified] {
+            for light_block in state.light_store.all(*status) {
+                if self
+                    .store
+                    .get_trusted_or_verified(light_block.height())
+                    .is_none()
+                {
+                    self.store.insert(light_block, *status);
+                }
+            }
+
+            self.store.prune(*status, self.pruning_window);
+        }
+
+        self.store.flush()
+    }
+
+    fn fetch_light_block(&mut self, height: AtHeight) -> Result<LightBlock, Error> {
         use tendermint_light_client::components::io::Io;
 
-        self.io.fetch_light_block(height).map_err(|e| {
+        let result = self.peer()?.io.fetch_light_block(height);
+
+        result.map_err(|e| {
+            self.failover();
             error::Kind::LightClient(self.chain_id.to_string())
                 .context(e)
                 .into()
@@ -0,0 +1,258 @@
+//! Persistent light store for the Tendermint light client, backed by sled.
+
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+    sync::Mutex,
+};
+
+use once_cell::sync::Lazy;
+use tracing::warn;
+
+use tendermint_light_client::{
+    store::LightStore,
+    types::{Height as TMHeight, LightBlock, Status},
+};
+
+use ibc::ics24_host::identifier::ChainId;
+
+use crate::error::{self, Error};
+use crate::util::sled::{self as db, KeyValueDb};
+
+/// Folder, relative to the home directory, under which the light stores are kept by default.
+pub const LIGHT_STORE_DEFAULT_FOLDER: &str = ".hermes/light_store/";
+
+/// The databases opened by this process, indexed by path. Sled takes an exclusive lock on the
+/// database it opens, hence all the light clients of a chain (e.g., the one of a chain runtime
+/// reloaded by the supervisor, or of a CLI command) share the same handle.
+static OPEN_DATABASES: Lazy<Mutex<HashMap<PathBuf, sled::Db>>> = Lazy::new(Default::default);
+
+const STATUSES: [Status; 4] = [
+    Status::Unverified,
+    Status::Verified,
+    Status::Trusted,
+    Status::Failed,
+];
+
+/// Returns the default location of the light store of the given chain,
+/// i.e. `~/.hermes/light_store/<chain_id>`.
+pub fn default_path(chain_id: &ChainId) -> Result<PathBuf, Error> {
+    let home = dirs_next::home_dir()
+        .ok_or_else(|| error::Kind::Store.context("cannot retrieve home folder location"))?;
+
+    Ok(home
+        .join(LIGHT_STORE_DEFAULT_FOLDER)
+        .join(chain_id.to_string()))
+}
+
+/// A `LightStore` which persists light blocks in a sled database, indexed by status and height.
+#[derive(Clone, Debug)]
+pub struct SledStore {
+    db: sled::Db,
+    unverified_db: KeyValueDb<u64, LightBlock>,
+    verified_db: KeyValueDb<u64, LightBlock>,
+    trusted_db: KeyValueDb<u64, LightBlock>,
+    failed_db: KeyValueDb<u64, LightBlock>,
+}
+
+impl SledStore {
+    /// Opens (or creates) the light store located at the given path, reusing the database if
+    /// this process already opened it. If the database is locked by another process (e.g., a
+    /// running relayer), falls back to a temporary in-memory store.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, Error> {
+        let path = path.as_ref();
+
+        let mut databases = OPEN_DATABASES
+            .lock()
+            .map_err(|e| error::Kind::Store.context(e.to_string()))?;
+
+        if let Some(db) = databases.get(path) {
+            return Ok(Self::new(db.clone()));
+        }
+
+        match sled::open(path) {
+            Ok(db) => {
+                databases.insert(path.to_path_buf(), db.clone());
+                Ok(Self::new(db))
+            }
+            Err(sled::Error::Io(e)) => {
+                warn!(
+                    "light store at {} is unavailable ({}), verified blocks will not be persisted",
+                    path.display(),
+                    e
+                );
+
+                let db = sled::Config::new()
+                    .temporary(true)
+                    .open()
+                    .map_err(|e| error::Kind::Store.context(e))?;
+                Ok(Self::new(db))
+            }
+            Err(e) => Err(error::Kind::Store.context(e).into()),
+        }
+    }
+
+    pub fn new(db: sled::Db) -> Self {
+        Self {
+            db,
+            unverified_db: db::key_value("light_store/unverified/"),
+            verified_db: db::key_value("light_store/verified/"),
+            trusted_db: db::key_value("light_store/trusted/"),
+            failed_db: db::key_value("light_store/failed/"),
+        }
+    }
+
+    fn db(&self, status: Status) -> &KeyValueDb<u64, LightBlock> {
+        match status {
+            Status::Unverified => &self.unverified_db,
+            Status::Verified => &self.verified_db,
+            Status::Trusted => &self.trusted_db,
+            Status::Failed => &self.failed_db,
+        }
+    }
+
+    /// Removes the lowest light blocks with the given status, such that
+    /// at most `window` of them are left in the store.
+    pub fn prune(&mut self, status: Status, window: usize) {
+        let mut heights: Vec<TMHeight> = self.all(status).map(|lb| lb.height()).collect();
+        if heights.len() <= window {
+            return;
+        }
+
+        heights.sort();
+        let excess = heights.len() - window;
+        for height in heights.into_iter().take(excess) {
+            self.remove(height, status);
+        }
+    }
+
+    /// Flushes the pending writes to disk.
+    pub fn flush(&self) -> Result<(), Error> {
+        self.db
+            .flush()
+            .map(|_| ())
+            .map_err(|e| error::Kind::Store.context(e).into())
+    }
+}
+
+impl LightStore for SledStore {
+    fn get(&self, height: TMHeight, status: Status) -> Option<LightBlock> {
+        self.db(status)
+            .fetch(&self.db, &height.value())
+            .ok()
+            .flatten()
+    }
+
+    fn update(&mut self, light_block: &LightBlock, status: Status) {
+        let height = light_block.height();
+
+        for other in STATUSES.iter().filter(|s| **s != status) {
+            self.remove(height, *other);
+        }
+
+        self.insert(light_block.clone(), status);
+    }
+
+    fn insert(&mut self, light_block: LightBlock, status: Status) {
+        let height = light_block.height();
+        self.db(status)
+            .insert(&self.db, &height.value(), &light_block)
+            .ok();
+    }
+
+    fn remove(&mut self, height: TMHeight, status: Status) {
+        self.db(status).remove(&self.db, &height.value()).ok();
+    }
+
+    fn highest(&self, status: Status) -> Option<LightBlock> {
+        self.all(status).max_by_key(|lb| lb.height())
+    }
+
+    fn lowest(&self, status: Status) -> Option<LightBlock> {
+        self.all(status).min_by_key(|lb| lb.height())
+    }
+
+    fn all(&self, status: Status) -> Box<dyn Iterator<Item = LightBlock>> {
+        Box::new(self.db(status).values(&self.db))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tendermint_light_client::{
+        store::LightStore,
+        types::{LightBlock, Status},
+    };
+
+    use ibc::ics24_host::identifier::ChainId;
+    use ibc::mock::host::HostBlock;
+
+    use super::SledStore;
+
+    fn temporary_store() -> SledStore {
+        let db = sled::Config::new().temporary(true).open().unwrap();
+        SledStore::new(db)
+    }
+
+    fn light_block(height: u64) -> LightBlock {
+        let block = HostBlock::generate_tm_block(ChainId::new("mockgaia".to_string(), 1), height);
+        LightBlock {
+            signed_header: block.signed_header,
+            validators: block.validators,
+            next_validators: block.next_validators,
+            provider: block.provider,
+        }
+    }
+
+    fn heights(store: &SledStore, status: Status) -> Vec<u64> {
+        let mut heights: Vec<u64> = store.all(status).map(|lb| lb.height().value()).collect();
+        heights.sort_unstable();
+        heights
+    }
+
+    #[test]
+    fn insert_update_and_prune() {
+        let mut store = temporary_store();
+
+        for height in 1..=5 {
+            store.insert(light_block(height), Status::Verified);
+        }
+
+        assert_eq!(heights(&store, Status::Verified), vec![1, 2, 3, 4, 5]);
+        assert_eq!(
+            store.lowest(Status::Verified).map(|lb| lb.height().value()),
+            Some(1)
+        );
+        assert_eq!(
+            store
+                .highest(Status::Verified)
+                .map(|lb| lb.height().value()),
+            Some(5)
+        );
+
+        // Updating a block moves it out of its previous status.
+        store.update(&light_block(5), Status::Trusted);
+        assert_eq!(heights(&store, Status::Verified), vec![1, 2, 3, 4]);
+        assert_eq!(heights(&store, Status::Trusted), vec![5]);
+
+        // Only the highest blocks are kept after pruning.
+        store.prune(Status::Verified, 2);
+        assert_eq!(heights(&store, Status::Verified), vec![3, 4]);
+        assert_eq!(heights(&store, Status::Trusted), vec![5]);
+    }
+
+    #[test]
+    fn open_shares_database_within_process() {
+        let path =
+            std::env::temp_dir().join(format!("hermes-light-store-test-{}", std::process::id()));
+
+        let mut store = SledStore::open(&path).unwrap();
+        store.insert(light_block(1), Status::Verified);
+
+        // Opening the store again must not fail on the lock held by the first handle.
+        let other = SledStore::open(&path).unwrap();
+        assert_eq!(heights(&other, Status::Verified), vec![1]);
+
+        std::fs::remove_dir_all(&path).ok();
+    }
+}
@@ -36,7 +36,8 @@ use crate::error::Error;
 use crate::event::monitor::EventBatch;
 use crate::foreign_client::{ForeignClient, ForeignClientError};
 use crate::relay::MAX_ITER;
-use crate::{chain::handle::ChainHandle, transfer::PacketError};
+use crate::telemetry;
+use crate::{chain::handle::ChainHandle, chain::TxStatus, transfer::PacketError};
 use crate::{
     channel::{Channel, ChannelError, ChannelSide},
     event::monitor::UnwrapOrClone,
@@ -114,6 +115,9 @@ pub struct OperationalData {
     /// Stores the time when the clients on the target chain has been updated, i.e., when this data
     /// was scheduled. Necessary for packet delays.
     scheduled_time: Instant,
+    /// Number of times the transactions carrying this data were rejected or timed out after
+    /// passing `CheckTx`.
+    tx_failures: usize,
 }
 
 impl OperationalData {
@@ -123,6 +127,7 @@ impl OperationalData {
             batch: vec![],
             target,
             scheduled_time: Instant::now(),
+            tx_failures: 0,
         }
     }
 
@@ -140,7 +145,8 @@ impl OperationalData {
 
         let mut msgs: Vec<Any> = self.batch.iter().map(|gm| gm.msg.clone()).collect();
 
-        // For zero delay we prepend the client update msgs.
+        // For zero delay we prepend the client update msgs. If the messages are split into
+        // several transactions, the client update is repeated at the start of each of them.
         if relay_path.zero_delay() {
             let update_height = self.proofs_height.increment();
 
@@ -187,10 +193,22 @@ impl fmt::Display for OperationalData {
     }
 }
 
+/// The transactions submitted to the target chain of an operational data, awaiting their
+/// inclusion in a block.
+struct PendingTx {
+    odata: OperationalData,
+    /// The outcomes of the transactions which are not included in a block yet.
+    outcomes: Vec<crossbeam_channel::Receiver<Vec<IbcEvent>>>,
+    /// The error of the first transaction which failed, if any.
+    error: Option<String>,
+}
+
 pub struct RelayPath {
     src_chain: Box<dyn ChainHandle>,
     dst_chain: Box<dyn ChainHandle>,
     channel: Channel,
+    src_channel_id: ChannelId,
+    dst_channel_id: ChannelId,
     clear_packets: bool,
 
     // Operational data, targeting both the source and destination chain.
@@ -200,22 +218,38 @@ pub struct RelayPath {
     src_operational_data: Vec<OperationalData>,
     // The operational data targeting the destination chain comprises mostly RecvPacket and Ack msgs.
     dst_operational_data: Vec<OperationalData>,
+
+    // The transactions submitted in the `sync` broadcast mode which are not committed yet.
+    pending_txs: Vec<PendingTx>,
 }
 
 impl RelayPath {
+    /// Creates a relay path over `channel`, whose channel ends must exist on both chains.
     pub fn new(
         src_chain: Box<dyn ChainHandle>,
         dst_chain: Box<dyn ChainHandle>,
         channel: Channel,
-    ) -> Self {
-        Self {
+    ) -> Result<Self, LinkError> {
+        let src_channel_id = channel
+            .src_channel_id()
+            .cloned()
+            .ok_or_else(|| ChannelError::MissingChannelId(src_chain.id()))?;
+        let dst_channel_id = channel
+            .dst_channel_id()
+            .cloned()
+            .ok_or_else(|| ChannelError::MissingChannelId(dst_chain.id()))?;
+
+        Ok(Self {
             src_chain,
             dst_chain,
             channel,
+            src_channel_id,
+            dst_channel_id,
             clear_packets: true,
             src_operational_data: Default::default(),
             dst_operational_data: Default::default(),
-        }
+            pending_txs: Default::default(),
+        })
     }
 
     pub fn src_chain(&self) -> Box<dyn ChainHandle> {
@@ -251,11 +285,11 @@ impl RelayPath {
     }
 
     pub fn src_channel_id(&self) -> &ChannelId {
-        &self.channel.src_channel_id()
+        &self.src_channel_id
     }
 
     pub fn dst_channel_id(&self) -> &ChannelId {
-        &self.channel.dst_channel_id()
+        &self.dst_channel_id
     }
 
     pub fn channel(&self) -> &Channel {
@@ -358,21 +392,21 @@ impl RelayPath {
                     }
                 }
                 IbcEvent::WriteAcknowledgement(write_ack_ev) => {
-                    if self.channel.src_channel_id() == &write_ack_ev.packet.destination_channel
+                    if self.src_channel_id() == &write_ack_ev.packet.destination_channel
                         && self.channel.src_port_id() == &write_ack_ev.packet.destination_port
                     {
                         result.push(event.clone());
                     }
                 }
                 IbcEvent::CloseInitChannel(chan_close_ev) => {
-                    if self.channel.src_channel_id() == chan_close_ev.channel_id()
+                    if self.src_channel_id() == chan_close_ev.channel_id()
                         && self.channel.src_port_id() == chan_close_ev.port_id()
                     {
                         result.push(event.clone());
                     }
                 }
                 IbcEvent::TimeoutPacket(timeout_ev) => {
-                    if self.channel.src_channel_id() == timeout_ev.src_channel_id()
+                    if self.src_channel_id() == timeout_ev.src_channel_id()
                         && self.channel.src_port_id() == timeout_ev.src_port_id()
                     {
                         result.push(event.clone());
@@ -416,6 +450,13 @@ impl RelayPath {
         Ok(())
     }
 
+    /// Clears the pending packets sent before `above_height`, even if they were already
+    /// cleared once during this execution.
+    pub fn force_clear_packets(&mut self, above_height: Height) -> Result<(), LinkError> {
+        self.clear_packets = true;
+        self.clear_packets(above_height)
+    }
+
     /// Generate & schedule operational data from the input `batch` of IBC events.
     pub fn update_schedule(&mut self, batch: EventBatch) -> Result<(), LinkError> {
         self.clear_packets(batch.height)?;
@@ -595,7 +636,12 @@ impl RelayPath {
                     error!("[{}] error {}", self, ev);
                     match self.regenerate_operational_data(odata.clone()) {
                         None => return Ok(vec![]), // Nothing to retry
-                        Some(new_od) => odata = new_od,
+                        Some(new_od) => {
+                            odata = OperationalData {
+                                tx_failures: odata.tx_failures,
+                                ..new_od
+                            }
+                        }
                     }
                 }
                 Err(e) => {
@@ -686,7 +732,8 @@ impl RelayPath {
 
     /// Sends a transaction to the chain targeted by the operational data `odata`.
     /// If the transaction generates an error, returns the error as well as  `LinkError::SendError` if  input events if a sending failure occurs.
-    /// Returns the events generated by the target chain upon success.
+    /// Returns the events generated by the target chain upon success, except those of the
+    /// transactions which are still pending, whose failure is handled by `reschedule_failed_txs`.
     fn send_from_operational_data(
         &mut self,
         odata: OperationalData,
@@ -703,8 +750,21 @@ impl RelayPath {
 
         let msgs = odata.assemble_msgs(self)?;
 
-        let tx_events = target.send_msgs(msgs)?;
-        info!("[{}] result {}\n", self, VecIbcEvents(tx_events.clone()));
+        let mut tx_events = vec![];
+        let mut outcomes = vec![];
+        for status in target.submit_msgs(msgs)? {
+            match status {
+                TxStatus::Pending { outcome, .. } => outcomes.push(outcome),
+                TxStatus::Completed { mut events } => tx_events.append(&mut events),
+            }
+        }
+
+        info!(
+            "[{}] result {}{} pending tx(s)\n",
+            self,
+            VecIbcEvents(tx_events.clone()),
+            outcomes.len()
+        );
 
         let ev = tx_events
             .clone()
@@ -712,11 +772,38 @@ impl RelayPath {
             .find(|event| matches!(event, IbcEvent::ChainError(_)));
 
         match ev {
+            // The whole operational data is retried, so its pending transactions are not tracked.
             Some(ev) => Err(LinkError::SendError(Box::new(ev))),
-            None => Ok(tx_events),
+            None => {
+                // The pending transactions do not block the relaying of the next operational
+                // data, they are tracked together until all their outcomes are known, and the
+                // packets are only recorded as relayed once they all succeeded.
+                if outcomes.is_empty() {
+                    self.record_packets_relayed(&odata);
+                } else {
+                    self.pending_txs.push(PendingTx {
+                        odata,
+                        outcomes,
+                        error: None,
+                    });
+                }
+
+                Ok(tx_events)
+            }
         }
     }
 
+    /// Records the packets of the operational data `odata` as relayed.
+    fn record_packets_relayed(&self, odata: &OperationalData) {
+        telemetry::global().packets_relayed(
+            &self.src_chain.id(),
+            self.src_channel_id(),
+            self.src_port_id(),
+            &self.dst_chain.id(),
+            odata.batch.len() as u64,
+        );
+    }
+
     /// Returns `true` if the delay for this relaying path is zero.
     /// Conversely, returns `false` if the delay is non-zero.
     fn zero_delay(&self) -> bool {
@@ -1230,6 +1317,8 @@ impl RelayPath {
     /// Checks if there are any operational data items ready, and if so performs the relaying
     /// of corresponding packets to the target chain.
     pub fn execute_schedule(&mut self) -> Result<(), LinkError> {
+        self.reschedule_failed_txs();
+
         let (src_ods, dst_ods) = self.try_fetch_scheduled_operational_data();
         for od in src_ods {
             self.relay_from_operational_data(od)?;
@@ -1242,6 +1331,73 @@ impl RelayPath {
         Ok(())
     }
 
+    /// Checks the outcome of the pending transactions. Once the outcomes of all the transactions
+    /// of an operational data are known, its packets are recorded as relayed if they all
+    /// succeeded, and it is scheduled anew otherwise. The operational data is discarded after
+    /// `MAX_ITER` failures.
+    fn reschedule_failed_txs(&mut self) {
+        let mut failed = vec![];
+
+        for mut pending_tx in std::mem::take(&mut self.pending_txs) {
+            let mut outcomes = vec![];
+            for outcome in std::mem::take(&mut pending_tx.outcomes) {
+                let error = match outcome.try_recv() {
+                    Ok(events) => events
+                        .into_iter()
+                        .find(|event| matches!(event, IbcEvent::ChainError(_)))
+                        .map(|error| error.to_string()),
+                    Err(crossbeam_channel::TryRecvError::Empty) => {
+                        outcomes.push(outcome);
+                        None
+                    }
+                    Err(e) => Some(e.to_string()),
+                };
+
+                if let Some(error) = error {
+                    pending_tx.error.get_or_insert(error);
+                }
+            }
+
+            if !outcomes.is_empty() {
+                pending_tx.outcomes = outcomes;
+                self.pending_txs.push(pending_tx);
+                continue;
+            }
+
+            match pending_tx.error {
+                Some(error) => {
+                    error!(
+                        "[{}] pending tx for {} failed: {}",
+                        self, pending_tx.odata, error
+                    );
+                    failed.push(pending_tx.odata);
+                }
+                None => self.record_packets_relayed(&pending_tx.odata),
+            }
+        }
+
+        for odata in failed {
+            let tx_failures = odata.tx_failures + 1;
+            if tx_failures >= MAX_ITER {
+                error!(
+                    "[{}] discarding {} after {} failed tx(s)",
+                    self, odata, tx_failures
+                );
+                continue;
+            }
+
+            if let Some(mut new_od) = self.regenerate_operational_data(odata) {
+                new_od.tx_failures = tx_failures;
+                if let Err(e) = self.schedule_operational_data(new_od) {
+                    error!(
+                        "[{}] failed to schedule the regenerated op. data: {}",
+                        self, e
+                    );
+                }
+            }
+        }
+    }
+
     /// Refreshes the scheduled batches.
     /// Verifies if any sendPacket messages timed-out. If so, moves them from destination op. data
     /// to source operational data, and adjusts the events and messages accordingly.
@@ -1446,15 +1602,15 @@ pub struct Link {
 }
 
 impl Link {
-    pub fn new(channel: Channel) -> Self {
+    pub fn new(channel: Channel) -> Result<Self, LinkError> {
         let a_chain = channel.src_chain();
         let b_chain = channel.dst_chain();
         let flipped = channel.flipped();
 
-        Self {
-            a_to_b: RelayPath::new(a_chain.clone(), b_chain.clone(), channel),
-            b_to_a: RelayPath::new(b_chain, a_chain, flipped),
-        }
+        Ok(Self {
+            a_to_b: RelayPath::new(a_chain.clone(), b_chain.clone(), channel)?,
+            b_to_a: RelayPath::new(b_chain, a_chain, flipped)?,
+        })
     }
 
     pub fn relay(&mut self) -> Result<(), LinkError> {
@@ -1612,20 +1768,20 @@ impl Link {
                 a_connection.client_id().clone(),
                 a_connection_id,
                 opts.src_port_id.clone(),
-                opts.src_channel_id.clone(),
+                Some(opts.src_channel_id.clone()),
             ),
             b_side: ChannelSide::new(
                 b_chain,
                 a_connection.counterparty().client_id().clone(),
                 a_connection.counterparty().connection_id().unwrap().clone(),
                 a_channel.counterparty().port_id.clone(),
-                b_channel_id,
+                Some(b_channel_id),
             ),
             connection_delay: a_connection.delay_period(),
             version: None,
         };
 
-        Ok(Link::new(channel))
+        Link::new(channel)
     }
 
     pub fn build_and_send_recv_packet_messages(&mut self) -> Result<Vec<IbcEvent>, LinkError> {
@@ -1656,3 +1812,243 @@ impl Link {
         Ok(results)
     }
 }
+
+/// Tests the relaying of packets between two chains built using `MockChain`, from the opening
+/// of the connection and channel to the acknowledgement of a token transfer.
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    use tokio::runtime::Runtime as TokioRuntime;
+
+    use ibc::application::ics20_fungible_token_transfer::msgs::transfer::MsgTransfer;
+    use ibc::events::IbcEvent;
+    use ibc::ics04_channel::channel::Order;
+    use ibc::ics24_host::identifier::{ChannelId, PortId};
+    use ibc::tx_msg::Msg;
+    use ibc_proto::ibc::core::channel::v1::{
+        QueryPacketCommitmentsRequest, QueryUnreceivedPacketsRequest,
+    };
+
+    use crate::chain::handle::ChainHandle;
+    use crate::chain::mock::test_utils::{get_basic_chain_config, reject_pending_txs};
+    use crate::chain::mock::{MockChain, TRANSFER_PORT};
+    use crate::chain::runtime::ChainRuntime;
+    use crate::channel::Channel;
+    use crate::config::BroadcastMode;
+    use crate::connection::Connection;
+    use crate::foreign_client::ForeignClient;
+
+    use super::{Link, LinkParameters};
+
+    /// Opens a connection and a transfer channel between the two chains, and returns the
+    /// identifiers of the channel ends on chain `a` and chain `b`.
+    fn open_transfer_channel(
+        a_chain: Box<dyn ChainHandle>,
+        b_chain: Box<dyn ChainHandle>,
+    ) -> (ChannelId, ChannelId) {
+        let client_on_a = ForeignClient::new(a_chain.clone(), b_chain.clone()).unwrap();
+        let client_on_b = ForeignClient::new(b_chain, a_chain).unwrap();
+        let connection = Connection::new(client_on_a, client_on_b, Duration::from_secs(0)).unwrap();
+
+        let port_id = PortId::from_str(TRANSFER_PORT).unwrap();
+        let channel =
+            Channel::new(connection, Order::Unordered, port_id.clone(), port_id, None).unwrap();
+
+        (
+            channel.src_channel_id().cloned().unwrap(),
+            channel.dst_channel_id().cloned().unwrap(),
+        )
+    }
+
+    /// Sends a token transfer from chain `a` to chain `b` on the channel `a_channel_id`.
+    fn send_transfer(
+        a_chain: &dyn ChainHandle,
+        b_chain: &dyn ChainHandle,
+        a_channel_id: &ChannelId,
+    ) {
+        let transfer = MsgTransfer {
+            source_port: PortId::from_str(TRANSFER_PORT).unwrap(),
+            source_channel: a_channel_id.clone(),
+            token: Some(ibc_proto::cosmos::base::v1beta1::Coin {
+                denom: "stake".to_string(),
+                amount: "100".to_string(),
+            }),
+            sender: a_chain.get_signer().unwrap(),
+            receiver: b_chain.get_signer().unwrap(),
+            timeout_height: b_chain.query_latest_height().unwrap().add(100),
+            timeout_timestamp: 0,
+        };
+        let events = a_chain.send_msgs(vec![transfer.to_any()]).unwrap();
+        assert!(
+            events.iter().any(|e| matches!(e, IbcEvent::SendPacket(_))),
+            "transfer failed with events {:?}",
+            events
+        );
+    }
+
+    #[test]
+    fn relay_transfer_packet() {
+        let a_cfg = get_basic_chain_config("chain_a");
+        let b_cfg = get_basic_chain_config("chain_b");
+
+        let rt = Arc::new(TokioRuntime::new().unwrap());
+        let (a_chain, _) = ChainRuntime::<MockChain>::spawn(a_cfg, rt.clone()).unwrap();
+        let (b_chain, _) = ChainRuntime::<MockChain>::spawn(b_cfg, rt).unwrap();
+
+        let (a_channel_id, b_channel_id) = open_transfer_channel(a_chain.clone(), b_chain.clone());
+        send_transfer(&*a_chain, &*b_chain, &a_channel_id);
+        let port_id = PortId::from_str(TRANSFER_PORT).unwrap();
+
+        // Relay the packet to chain b, which acknowledges it.
+        let mut a_to_b = Link::new_from_opts(
+            a_chain.clone(),
+            b_chain.clone(),
+            LinkParameters {
+                src_port_id: port_id.clone(),
+                src_channel_id: a_channel_id.clone(),
+            },
+        )
+        .unwrap();
+        let events = a_to_b.build_and_send_recv_packet_messages().unwrap();
+        assert!(
+            events
+                .iter()
+                .any(|e| matches!(e, IbcEvent::WriteAcknowledgement(_))),
+            "packet not received, events {:?}",
+            events
+        );
+
+        // Relay the acknowledgement back to chain a.
+        let mut b_to_a = Link::new_from_opts(
+            b_chain,
+            a_chain.clone(),
+            LinkParameters {
+                src_port_id: port_id.clone(),
+                src_channel_id: b_channel_id,
+            },
+        )
+        .unwrap();
+        let events = b_to_a.build_and_send_ack_packet_messages().unwrap();
+        assert!(
+            events
+                .iter()
+                .any(|e| matches!(e, IbcEvent::AcknowledgePacket(_))),
+            "acknowledgement not relayed, events {:?}",
+            events
+        );
+
+        // Chain a deletes the commitment of the packet once it is acknowledged.
+        let (commitments, _) = a_chain
+            .query_packet_commitments(QueryPacketCommitmentsRequest {
+                port_id: port_id.to_string(),
+                channel_id: a_channel_id.to_string(),
+                pagination: ibc_proto::cosmos::base::query::pagination::all(),
+            })
+            .unwrap();
+        assert!(commitments.is_empty());
+    }
+
+    /// Tests that the operational data of a transaction which failed after being reported as
+    /// pending is scheduled anew, and relayed by the next execution of the schedule.
+    #[test]
+    fn reschedule_failed_pending_tx() {
+        let a_cfg = get_basic_chain_config("chain_pending_a");
+        let mut b_cfg = get_basic_chain_config("chain_pending_b");
+        b_cfg.broadcast_mode = BroadcastMode::Sync;
+
+        let rt = Arc::new(TokioRuntime::new().unwrap());
+        let (a_chain, _) = ChainRuntime::<MockChain>::spawn(a_cfg, rt.clone()).unwrap();
+        let (b_chain, _) = ChainRuntime::<MockChain>::spawn(b_cfg, rt).unwrap();
+
+        let (a_channel_id, b_channel_id) = open_transfer_channel(a_chain.clone(), b_chain.clone());
+        send_transfer(&*a_chain, &*b_chain, &a_channel_id);
+
+        let unreceived_packets = || {
+            b_chain
+                .query_unreceived_packets(QueryUnreceivedPacketsRequest {
+                    port_id: TRANSFER_PORT.to_string(),
+                    channel_id: b_channel_id.to_string(),
+                    packet_commitment_sequences: vec![1],
+                })
+                .unwrap()
+        };
+
+        let mut link = Link::new_from_opts(
+            a_chain.clone(),
+            b_chain.clone(),
+            LinkParameters {
+                src_port_id: PortId::from_str(TRANSFER_PORT).unwrap(),
+                src_channel_id: a_channel_id,
+            },
+        )
+        .unwrap();
+
+        // The transaction relaying the packet fails once included in a block.
+        reject_pending_txs(&b_chain.id(), 1);
+        link.a_to_b
+            .build_recv_packet_and_timeout_msgs(None)
+            .unwrap();
+        link.a_to_b.execute_schedule().unwrap();
+        assert_eq!(unreceived_packets(), vec![1]);
+
+        // The next execution of the schedule relays the packet again.
+        link.a_to_b.execute_schedule().unwrap();
+        assert!(unreceived_packets().is_empty());
+    }
+
+    /// Tests that the operational data sent in several transactions which fail once included
+    /// in a block is scheduled anew only once.
+    #[test]
+    fn reschedule_failed_pending_txs_once() {
+        let a_cfg = get_basic_chain_config("chain_pending_batch_a");
+        let mut b_cfg = get_basic_chain_config("chain_pending_batch_b");
+        b_cfg.broadcast_mode = BroadcastMode::Sync;
+        b_cfg.max_msg_num = Some(1);
+
+        let rt = Arc::new(TokioRuntime::new().unwrap());
+        let (a_chain, _) = ChainRuntime::<MockChain>::spawn(a_cfg, rt.clone()).unwrap();
+        let (b_chain, _) = ChainRuntime::<MockChain>::spawn(b_cfg, rt).unwrap();
+
+        let (a_channel_id, b_channel_id) = open_transfer_channel(a_chain.clone(), b_chain.clone());
+        send_transfer(&*a_chain, &*b_chain, &a_channel_id);
+        send_transfer(&*a_chain, &*b_chain, &a_channel_id);
+
+        let mut link = Link::new_from_opts(
+            a_chain,
+            b_chain.clone(),
+            LinkParameters {
+                src_port_id: PortId::from_str(TRANSFER_PORT).unwrap(),
+                src_channel_id: a_channel_id,
+            },
+        )
+        .unwrap();
+
+        // All the transactions relaying the packets, one message each, fail.
+        reject_pending_txs(&b_chain.id(), usize::MAX);
+        link.a_to_b
+            .build_recv_packet_and_timeout_msgs(None)
+            .unwrap();
+        link.a_to_b.execute_schedule().unwrap();
+        reject_pending_txs(&b_chain.id(), 0);
+
+        assert_eq!(link.a_to_b.pending_txs.len(), 1);
+        assert!(link.a_to_b.pending_txs[0].outcomes.len() > 1);
+
+        link.a_to_b.reschedule_failed_txs();
+        assert!(link.a_to_b.pending_txs.is_empty());
+        assert_eq!(link.a_to_b.dst_operational_data.len(), 1);
+
+        link.a_to_b.execute_schedule().unwrap();
+        let unreceived_packets = b_chain
+            .query_unreceived_packets(QueryUnreceivedPacketsRequest {
+                port_id: TRANSFER_PORT.to_string(),
+                channel_id: b_channel_id.to_string(),
+                packet_commitment_sequences: vec![1, 2],
+            })
+            .unwrap();
+        assert!(unreceived_packets.is_empty());
+    }
+}
@@ -1,18 +1,79 @@
-//! Registry for keeping track of [`ChainHandle`]s indexed by a `ChainId`.
+//! Registry for keeping track of [`ChainHandle`]s indexed by a `ChainId`,
+//! and of the `Chain` implementations the runtimes are spawned with, indexed by a [`ChainType`].
 
-use std::{collections::HashMap, sync::Arc};
+use std::{
+    collections::HashMap,
+    sync::{Arc, RwLock},
+};
 
 use anomaly::BoxError;
+use once_cell::sync::Lazy;
 use tokio::runtime::Runtime as TokioRuntime;
-use tracing::trace;
+use tracing::{trace, warn};
 
 use ibc::ics24_host::identifier::ChainId;
 
 use crate::{
-    chain::{handle::ChainHandle, runtime::ChainRuntime, CosmosSdkChain},
-    config::Config,
+    chain::{handle::ChainHandle, runtime::ChainRuntime, Chain, CosmosSdkChain},
+    config::{ChainConfig, ChainType, Config},
+    error::Error,
 };
 
+/// Spawns the runtime of a chain from its configuration, and returns the handle to it.
+pub type SpawnChainRuntime =
+    fn(ChainConfig, Arc<TokioRuntime>, RuntimeMode) -> Result<Box<dyn ChainHandle>, Error>;
+
+/// What the runtime of a chain is spawned with.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum RuntimeMode {
+    /// A light client and an event monitor, as needed to relay.
+    Full,
+    /// Neither a light client nor an event monitor, enough for one-off queries and transactions.
+    /// See [`ChainRuntime::spawn_minimal`].
+    Minimal,
+}
+
+/// The chain backends, ie. the functions spawning the runtime of the chains of each type.
+static CHAIN_BACKENDS: Lazy<RwLock<HashMap<ChainType, SpawnChainRuntime>>> = Lazy::new(|| {
+    let mut backends = HashMap::new();
+
+    backends.insert(
+        ChainType::new(ChainType::COSMOS_SDK),
+        spawn::<CosmosSdkChain> as SpawnChainRuntime,
+    );
+
+    #[cfg(test)]
+    backends.insert(
+        ChainType::new(ChainType::MOCK),
+        spawn::<crate::chain::mock::MockChain> as SpawnChainRuntime,
+    );
+
+    RwLock::new(backends)
+});
+
+/// Registers `C` as the implementation of the chains of type `chain_type`, replacing the
+/// previously registered one, if any. Chains whose configuration sets this `type` are then
+/// spawned with a `ChainRuntime<C>`.
+pub fn register_chain_backend<C: Chain + Send + 'static>(chain_type: ChainType) {
+    CHAIN_BACKENDS
+        .write()
+        .unwrap()
+        .insert(chain_type, spawn::<C>);
+}
+
+fn spawn<C: Chain + Send + 'static>(
+    config: ChainConfig,
+    rt: Arc<TokioRuntime>,
+    mode: RuntimeMode,
+) -> Result<Box<dyn ChainHandle>, Error> {
+    let (handle, _) = match mode {
+        RuntimeMode::Full => ChainRuntime::<C>::spawn(config, rt)?,
+        RuntimeMode::Minimal => ChainRuntime::<C>::spawn_minimal(config, rt)?,
+    };
+
+    Ok(handle)
+}
+
 /// Registry for keeping track of [`ChainHandle`]s indexed by a `ChainId`.
 ///
 /// The purpose of this type is to avoid spawning multiple runtimes for a single `ChainId`.
@@ -47,6 +108,30 @@ impl Registry {
 
         Ok(handle.clone())
     }
+
+    /// Get the [`ChainHandle`] associated with the given [`ChainId`], if its runtime is spawned.
+    pub fn get(&self, chain_id: &ChainId) -> Option<Box<dyn ChainHandle>> {
+        self.handles.get(chain_id).cloned()
+    }
+
+    /// Replace the [`Config`] from which the chain runtimes are spawned.
+    ///
+    /// The runtimes already spawned are left untouched, and must be shut down
+    /// with [`Registry::shutdown`] for a new configuration to apply to them.
+    pub fn update_config(&mut self, config: Config) {
+        self.config = config;
+    }
+
+    /// Shut down the runtime of the chain with the given [`ChainId`], if any.
+    pub fn shutdown(&mut self, chain_id: &ChainId) {
+        if let Some(handle) = self.handles.remove(chain_id) {
+            if let Err(e) = handle.shutdown() {
+                warn!("failed to shut down chain runtime for {}: {}", chain_id, e);
+            }
+
+            trace!("shut down chain runtime for chain identifier {}", chain_id);
+        }
+    }
 }
 
 /// Spawns a chain runtime from the configuration and given a chain identifier.
@@ -61,7 +146,86 @@ pub fn spawn_chain_runtime(
         .cloned()
         .ok_or_else(|| format!("missing chain for id ({}) in configuration file", chain_id))?;
 
-    let (handle, _) = ChainRuntime::<CosmosSdkChain>::spawn(chain_config, rt)?;
+    spawn_chain_runtime_with_config(chain_config, rt)
+}
 
-    Ok(handle)
+/// Spawns a chain runtime from the configuration of the chain, with the `Chain`
+/// implementation registered for its type. Returns the corresponding handle if successful.
+pub fn spawn_chain_runtime_with_config(
+    chain_config: ChainConfig,
+    rt: Arc<TokioRuntime>,
+) -> Result<Box<dyn ChainHandle>, BoxError> {
+    spawn_chain_runtime_with_mode(chain_config, rt, RuntimeMode::Full)
+}
+
+/// Spawns a chain runtime from the configuration of the chain, without a light client nor an
+/// event monitor. Returns the corresponding handle if successful.
+pub fn spawn_minimal_chain_runtime_with_config(
+    chain_config: ChainConfig,
+    rt: Arc<TokioRuntime>,
+) -> Result<Box<dyn ChainHandle>, BoxError> {
+    spawn_chain_runtime_with_mode(chain_config, rt, RuntimeMode::Minimal)
+}
+
+fn spawn_chain_runtime_with_mode(
+    chain_config: ChainConfig,
+    rt: Arc<TokioRuntime>,
+    mode: RuntimeMode,
+) -> Result<Box<dyn ChainHandle>, BoxError> {
+    let spawn = CHAIN_BACKENDS
+        .read()
+        .unwrap()
+        .get(&chain_config.chain_type)
+        .copied()
+        .ok_or_else(|| {
+            format!(
+                "unsupported type '{}' of chain {}",
+                chain_config.chain_type, chain_config.id
+            )
+        })?;
+
+    Ok(spawn(chain_config, rt, mode)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use tokio::runtime::Runtime as TokioRuntime;
+
+    use super::{spawn_chain_runtime_with_config, spawn_minimal_chain_runtime_with_config};
+    use crate::chain::mock::test_utils::get_basic_chain_config;
+    use crate::config::ChainType;
+
+    #[test]
+    fn spawn_chain_of_registered_type() {
+        let rt = Arc::new(TokioRuntime::new().unwrap());
+
+        let config = get_basic_chain_config("chain_a");
+        let handle = spawn_chain_runtime_with_config(config.clone(), rt.clone()).unwrap();
+        assert_eq!(handle.id(), config.id);
+        assert!(handle.query_latest_height().is_ok());
+
+        let config = crate::config::ChainConfig {
+            chain_type: ChainType::new("unknown"),
+            ..config
+        };
+        assert!(spawn_chain_runtime_with_config(config, rt).is_err());
+    }
+
+    #[test]
+    fn spawn_minimal_chain_runtime() {
+        let rt = Arc::new(TokioRuntime::new().unwrap());
+
+        let config = get_basic_chain_config("chain_minimal");
+        let handle = spawn_minimal_chain_runtime_with_config(config.clone(), rt).unwrap();
+        assert_eq!(handle.id(), config.id);
+
+        // The runtime serves queries, but cannot build headers without a light client.
+        let height = handle.query_latest_height().unwrap();
+        let client_state = handle.build_client_state(height).unwrap();
+        assert!(handle
+            .build_header(height, height.increment(), client_state)
+            .is_err());
+    }
 }
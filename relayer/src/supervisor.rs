@@ -1,13 +1,15 @@
 use std::{
     collections::HashMap,
     fmt,
+    path::PathBuf,
     thread::{self, JoinHandle},
-    time::Duration,
+    time::{Duration, Instant},
 };
 
 use anomaly::BoxError;
 use crossbeam_channel::{Receiver, Select, Sender};
 use itertools::Itertools;
+use serde::Serialize;
 use tracing::{debug, error, error_span, info, trace, warn};
 
 use ibc::{
@@ -16,37 +18,58 @@ use ibc::{
         client_state::ClientState,
         events::{NewBlock, UpdateClient},
     },
+    ics03_connection::events::Attributes as ConnectionAttributes,
     ics04_channel::{
         channel::IdentifiedChannelEnd,
         events::{Attributes, CloseInit, SendPacket, TimeoutPacket, WriteAcknowledgement},
     },
-    ics24_host::identifier::{ChainId, ChannelId, ClientId, PortId},
+    ics24_host::identifier::{ChainId, ChannelId, ClientId, ConnectionId, PortId},
     Height,
 };
 
 use ibc_proto::ibc::core::channel::v1::QueryChannelsRequest;
 
 use crate::{
+    admin::{AdminRequest, ChainStatus, WorkerStatus},
     chain::{
         counterparty::{channel_connection_client, get_counterparty_chain},
-        handle::ChainHandle,
+        handle::{ChainHandle, Subscription},
+        UpgradePlan,
     },
-    config::Config,
-    event::monitor::{EventBatch, UnwrapOrClone},
+    channel::Channel as RelayChannel,
+    config::{Config, Strategy},
+    connection::Connection as RelayConnection,
+    event::monitor::{EventBatch, Result as MonitorResult, UnwrapOrClone},
     foreign_client::{ForeignClient, ForeignClientError, MisbehaviourResults},
-    link::{Link, LinkParameters},
+    link::{Link, LinkError, LinkParameters},
     registry::Registry,
+    telemetry,
 };
 
 mod error;
 pub use error::Error;
 
+pub mod reload;
+use reload::{ConfigDiff, ConfigWatcher};
+
+/// How long the supervisor waits for events before checking for configuration changes.
+const RECV_TIMEOUT: Duration = Duration::from_secs(1);
+
+/// How often a client worker checks for an upgrade of the source chain of its client.
+const UPGRADE_CHECK_INTERVAL: Duration = Duration::from_secs(10);
+
 /// A command for a [`Worker`].
 pub enum WorkerCmd {
     /// A batch of packet events need to be relayed
     IbcEvents { batch: EventBatch },
     /// A batch of [`NewBlock`] events need to be relayed
     NewBlock { height: Height, new_block: NewBlock },
+    /// The worker must clear the pending packets of its path
+    ClearPackets,
+    /// The worker must refresh its client
+    RefreshClient,
+    /// The worker must stop
+    Shutdown,
 }
 
 /// Handle to a [`Worker`], for sending [`WorkerCmd`]s to it.
@@ -80,6 +103,17 @@ impl WorkerHandle {
         Ok(())
     }
 
+    /// Send a command to the worker.
+    fn send_cmd(&self, cmd: WorkerCmd) -> Result<(), String> {
+        self.tx.send(cmd).map_err(|e| e.to_string())
+    }
+
+    /// Ask the worker to stop, without waiting for it to finish.
+    pub fn shutdown(&self) -> Result<(), BoxError> {
+        self.tx.send(WorkerCmd::Shutdown)?;
+        Ok(())
+    }
+
     /// Wait for the worker thread to finish.
     pub fn join(self) -> thread::Result<()> {
         self.thread_handle.join()
@@ -103,7 +137,11 @@ impl ChainHandlePair {
     }
 }
 
-fn recv_multiple<K, T>(rs: &[(K, Receiver<T>)]) -> Result<(&K, T), BoxError> {
+/// Receive a value from any of the receivers, or `None` if none is received within `timeout`.
+fn recv_multiple<K, T>(
+    rs: &[(K, Receiver<T>)],
+    timeout: Duration,
+) -> Result<Option<(&K, T)>, BoxError> {
     // Build a list of operations.
     let mut sel = Select::new();
     for (_, r) in rs {
@@ -111,14 +149,17 @@ fn recv_multiple<K, T>(rs: &[(K, Receiver<T>)]) -> Result<(&K, T), BoxError> {
     }
 
     // Complete the selected operation.
-    let oper = sel.select();
+    let oper = match sel.select_timeout(timeout) {
+        Ok(oper) => oper,
+        Err(_) => return Ok(None),
+    };
     let index = oper.index();
 
     let (k, r) = &rs[index];
 
     let result = oper.recv(r)?;
 
-    Ok((k, result))
+    Ok(Some((k, result)))
 }
 
 /// The supervisor listens for events on multiple pairs of chains,
@@ -128,6 +169,10 @@ pub struct Supervisor {
     config: Config,
     registry: Registry,
     workers: HashMap<Object, WorkerHandle>,
+    /// The status of each chain, as of the latest batch of events received from it.
+    chain_statuses: HashMap<ChainId, ChainStatus>,
+    config_watcher: Option<ConfigWatcher>,
+    admin_requests: Option<Receiver<AdminRequest>>,
 }
 
 impl Supervisor {
@@ -139,9 +184,25 @@ impl Supervisor {
             config,
             registry,
             workers: HashMap::new(),
+            chain_statuses: HashMap::new(),
+            config_watcher: None,
+            admin_requests: None,
         })
     }
 
+    /// Returns a channel through which the admin service sends its requests to the supervisor.
+    pub fn admin_channel(&mut self) -> Sender<AdminRequest> {
+        let (tx, rx) = crossbeam_channel::unbounded();
+        self.admin_requests = Some(rx);
+        tx
+    }
+
+    /// Watch the configuration file at the given path, and apply its modifications
+    /// while the supervisor is running.
+    pub fn watch_config(&mut self, path: PathBuf) {
+        self.config_watcher = Some(ConfigWatcher::new(path));
+    }
+
     /// Collect the events we are interested in from an [`EventBatch`],
     /// and maps each [`IbcEvent`] to their corresponding [`Object`].
     pub fn collect_events(
@@ -180,6 +241,32 @@ impl Supervisor {
                         collected.per_object.entry(object).or_default().push(event);
                     }
                 }
+                IbcEvent::OpenInitConnection(ref open_init) => {
+                    if let Ok(object) =
+                        Object::for_conn_open_init(open_init.attributes(), src_chain)
+                    {
+                        self.collect_handshake_event(&mut collected, object, event);
+                    }
+                }
+                IbcEvent::OpenTryConnection(ref open_try) => {
+                    if let Ok(object) = Object::for_conn_open_try(open_try.attributes(), src_chain)
+                    {
+                        self.collect_handshake_event(&mut collected, object, event);
+                    }
+                }
+                IbcEvent::OpenInitChannel(ref open_init) => {
+                    if let Ok(object) =
+                        Object::for_chan_open_init(open_init.attributes(), src_chain)
+                    {
+                        self.collect_handshake_event(&mut collected, object, event);
+                    }
+                }
+                IbcEvent::OpenTryChannel(ref open_try) => {
+                    if let Ok(object) = Object::for_chan_open_try(open_try.attributes(), src_chain)
+                    {
+                        self.collect_handshake_event(&mut collected, object, event);
+                    }
+                }
                 IbcEvent::SendPacket(ref packet) => {
                     if let Ok(object) = Object::for_send_packet(packet, src_chain) {
                         collected.per_object.entry(object).or_default().push(event);
@@ -204,9 +291,65 @@ impl Supervisor {
             }
         }
 
+        // Drop the events of the objects excluded by the strategy or the packet filter.
+        collected.per_object.retain(|object, _| {
+            self.handled_by_strategy(object) && self.relay_packets_on_object(object)
+        });
+
         collected
     }
 
+    /// Whether the configured [`Strategy`] calls for a worker handling the given [`Object`].
+    fn handled_by_strategy(&self, object: &Object) -> bool {
+        match self.config.global.strategy {
+            Strategy::All => true,
+            Strategy::Packets => matches!(object, Object::UnidirectionalChannelPath(_)),
+            Strategy::ClientsOnly => matches!(object, Object::Client(_)),
+        }
+    }
+
+    /// Whether the packet filter of the source chain of the given [`Object`] allows
+    /// relaying on its channel. Always `true` for objects which are not channel paths.
+    fn relay_packets_on_object(&self, object: &Object) -> bool {
+        match object {
+            Object::UnidirectionalChannelPath(path) => self.relay_packets_on_channel(
+                &path.src_chain_id,
+                &path.src_port_id,
+                &path.src_channel_id,
+            ),
+            _ => true,
+        }
+    }
+
+    /// Whether the packet filter of the given chain allows relaying on the given channel.
+    fn relay_packets_on_channel(
+        &self,
+        chain_id: &ChainId,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> bool {
+        self.config
+            .find_chain(chain_id)
+            .map_or(false, |chain_config| {
+                chain_config.packet_filter.is_allowed(port_id, channel_id)
+            })
+    }
+
+    /// Collect a connection or channel handshake event, but only if both chains
+    /// involved in the handshake are present in the configuration.
+    fn collect_handshake_event(
+        &self,
+        collected: &mut CollectedEvents,
+        object: Object,
+        event: IbcEvent,
+    ) {
+        if self.config.find_chain(object.src_chain_id()).is_some()
+            && self.config.find_chain(object.dst_chain_id()).is_some()
+        {
+            collected.per_object.entry(object).or_default().push(event);
+        }
+    }
+
     fn spawn_workers(&mut self) {
         let req = QueryChannelsRequest {
             pagination: ibc_proto::cosmos::base::query::pagination::all(),
@@ -315,7 +458,9 @@ impl Supervisor {
             src_chain_id: client.client_state.chain_id(),
         });
 
-        self.worker_for_object(client_object, chain.clone(), counterparty_chain.clone());
+        if self.handled_by_strategy(&client_object) {
+            self.worker_for_object(client_object, chain.clone(), counterparty_chain.clone());
+        }
 
         // TODO: Only start the Uni worker if there are outstanding packets or ACKs.
         //  https://github.com/informalsystems/ibc-rs/issues/901
@@ -327,43 +472,67 @@ impl Supervisor {
             src_port_id: channel.port_id,
         });
 
-        self.worker_for_object(path_object, chain.clone(), counterparty_chain.clone());
+        if !self.handled_by_strategy(&path_object) {
+            return Ok(());
+        }
+
+        // The packet filter only excludes the packet worker of the channel, as its client
+        // still needs to be refreshed.
+        if self.relay_packets_on_object(&path_object) {
+            self.worker_for_object(path_object, chain.clone(), counterparty_chain.clone());
+        } else {
+            debug!(
+                "not relaying packets on channel {} on chain {} because of the packet filter",
+                channel.channel_id,
+                chain.id()
+            );
+        }
 
         Ok(())
     }
 
-    /// Run the supervisor event loop.
-    pub fn run(mut self) -> Result<(), BoxError> {
-        let mut subscriptions = Vec::with_capacity(self.config.chains.len());
-
-        for chain_config in &self.config.chains {
-            let chain = match self.registry.get_or_spawn(&chain_config.id) {
-                Ok(chain) => chain,
-                Err(e) => {
-                    error!(
-                        "failed to spawn chain runtime for {}: {}",
-                        chain_config.id, e
-                    );
-                    continue;
-                }
-            };
+    /// Spawn the runtime of the given chain and subscribe to its events.
+    fn subscribe(&mut self, chain_id: &ChainId) -> Option<(Box<dyn ChainHandle>, Subscription)> {
+        let chain = match self.registry.get_or_spawn(chain_id) {
+            Ok(chain) => chain,
+            Err(e) => {
+                error!("failed to spawn chain runtime for {}: {}", chain_id, e);
+                return None;
+            }
+        };
 
-            match chain.subscribe() {
-                Ok(subscription) => subscriptions.push((chain, subscription)),
-                Err(e) => error!(
-                    "failed to subscribe to events of {}: {}",
-                    chain_config.id, e
-                ),
+        match chain.subscribe() {
+            Ok(subscription) => Some((chain, subscription)),
+            Err(e) => {
+                error!("failed to subscribe to events of {}: {}", chain_id, e);
+                None
             }
         }
+    }
+
+    /// Run the supervisor event loop.
+    pub fn run(mut self) -> Result<(), BoxError> {
+        let chain_ids = self
+            .config
+            .chains
+            .iter()
+            .map(|c| c.id.clone())
+            .collect_vec();
+
+        let mut subscriptions = chain_ids
+            .iter()
+            .filter_map(|chain_id| self.subscribe(chain_id))
+            .collect_vec();
 
         self.spawn_workers();
 
         loop {
-            match recv_multiple(&subscriptions) {
-                Ok((chain, batch)) => {
+            match recv_multiple(&subscriptions, RECV_TIMEOUT) {
+                Ok(Some((chain, batch))) => {
+                    let batch = batch.unwrap_or_clone();
+                    self.update_chain_status(&chain.id(), &batch);
+
                     let result = batch
-                        .unwrap_or_clone()
                         .map_err(Into::into)
                         .and_then(|batch| self.process_batch(chain.clone(), batch));
 
@@ -371,8 +540,199 @@ impl Supervisor {
                         error!("[{}] error during batch processing: {}", chain.id(), e);
                     }
                 }
+                Ok(None) => (),
                 Err(e) => error!("error when waiting for events: {}", e),
             }
+
+            if let Some(config) = self.config_watcher.as_mut().and_then(|w| w.poll()) {
+                self.reload(config, &mut subscriptions);
+            }
+
+            if let Some(admin_requests) = self.admin_requests.clone() {
+                for request in admin_requests.try_iter() {
+                    self.handle_admin_request(request);
+                }
+            }
+        }
+    }
+
+    /// Record the height of a batch of events received from a chain, or the error reported
+    /// by its event monitor, so that the admin service can report the status of the chain
+    /// without querying it.
+    fn update_chain_status(&mut self, chain_id: &ChainId, batch: &MonitorResult<EventBatch>) {
+        let status = self
+            .chain_statuses
+            .entry(chain_id.clone())
+            .or_insert_with(|| ChainStatus {
+                id: chain_id.clone(),
+                spawned: true,
+                latest_height: None,
+                error: None,
+            });
+
+        match batch {
+            Ok(batch) => {
+                status.latest_height = Some(batch.height);
+                status.error = None;
+            }
+            Err(e) => status.error = Some(e.to_string()),
+        }
+    }
+
+    /// Handle a request of the admin service. Failures to reply are ignored,
+    /// as they only mean that the admin service stopped waiting for the reply.
+    fn handle_admin_request(&mut self, request: AdminRequest) {
+        debug!("handling admin request {:?}", request);
+
+        match request {
+            AdminRequest::Workers { reply_to } => {
+                let workers = self
+                    .workers
+                    .keys()
+                    .map(|object| WorkerStatus {
+                        worker_type: object.worker_type(),
+                        object: object.clone(),
+                    })
+                    .collect();
+
+                let _ = reply_to.send(workers);
+            }
+            AdminRequest::Chains { reply_to } => {
+                let chains = self
+                    .config
+                    .chains
+                    .iter()
+                    .map(|chain_config| {
+                        let status = self.chain_statuses.get(&chain_config.id);
+
+                        ChainStatus {
+                            id: chain_config.id.clone(),
+                            spawned: self.registry.get(&chain_config.id).is_some(),
+                            latest_height: status.and_then(|s| s.latest_height),
+                            error: status.and_then(|s| s.error.clone()),
+                        }
+                    })
+                    .collect();
+
+                let _ = reply_to.send(chains);
+            }
+            AdminRequest::ClearPackets {
+                chain_id,
+                port_id,
+                channel_id,
+                reply_to,
+            } => {
+                let worker = self.workers.iter().find(|(object, _)| match object {
+                    Object::UnidirectionalChannelPath(path) => {
+                        path.src_chain_id == chain_id
+                            && path.src_port_id == port_id
+                            && path.src_channel_id == channel_id
+                    }
+                    _ => false,
+                });
+
+                let result = match worker {
+                    Some((_, worker)) => worker.send_cmd(WorkerCmd::ClearPackets),
+                    None => Err(format!(
+                        "no worker for channel {}/{} on chain {}",
+                        port_id, channel_id, chain_id
+                    )),
+                };
+
+                let _ = reply_to.send(result);
+            }
+            AdminRequest::RefreshClient {
+                chain_id,
+                client_id,
+                reply_to,
+            } => {
+                let worker = self.workers.iter().find(|(object, _)| match object {
+                    Object::Client(client) => {
+                        client.dst_chain_id == chain_id && client.dst_client_id == client_id
+                    }
+                    _ => false,
+                });
+
+                let result = match worker {
+                    Some((_, worker)) => worker.send_cmd(WorkerCmd::RefreshClient),
+                    None => Err(format!(
+                        "no worker for client {} on chain {}",
+                        client_id, chain_id
+                    )),
+                };
+
+                let _ = reply_to.send(result);
+            }
+        }
+    }
+
+    /// Apply a new configuration: the runtimes and workers of the removed chains are stopped,
+    /// the workers excluded by the new strategy are stopped, and the workers for the new chains
+    /// are spawned.
+    ///
+    /// The changes are only tracked per chain: any change to the configuration of a chain,
+    /// including its packet filter, restarts its runtime along with all the workers relaying
+    /// to or from it. Only the workers between unchanged chains keep running.
+    fn reload(
+        &mut self,
+        config: Config,
+        subscriptions: &mut Vec<(Box<dyn ChainHandle>, Subscription)>,
+    ) {
+        let diff = ConfigDiff::new(&self.config, &config);
+        info!("reloading configuration: {:?}", diff);
+
+        for chain_id in diff.stopped_chains() {
+            let objects = self
+                .workers
+                .keys()
+                .filter(|o| o.src_chain_id() == chain_id || o.dst_chain_id() == chain_id)
+                .cloned()
+                .collect_vec();
+
+            for object in objects {
+                self.stop_worker(&object);
+            }
+
+            subscriptions.retain(|(chain, _)| chain.id() != *chain_id);
+            self.chain_statuses.remove(chain_id);
+            self.registry.shutdown(chain_id);
+        }
+
+        self.config = config.clone();
+        self.registry.update_config(config);
+
+        let excluded = self
+            .workers
+            .keys()
+            .filter(|o| !self.handled_by_strategy(o) || !self.relay_packets_on_object(o))
+            .cloned()
+            .collect_vec();
+
+        for object in excluded {
+            self.stop_worker(&object);
+        }
+
+        for chain_id in diff.started_chains() {
+            if let Some(subscription) = self.subscribe(chain_id) {
+                subscriptions.push(subscription);
+            }
+        }
+
+        self.spawn_workers();
+    }
+
+    /// Stop the worker handling the given [`Object`], if any.
+    fn stop_worker(&mut self, object: &Object) {
+        if let Some(worker) = self.workers.remove(object) {
+            debug!("stopping worker for object {}", object.short_name());
+
+            if let Err(e) = worker.shutdown() {
+                error!(
+                    "failed to stop worker for object {}: {}",
+                    object.short_name(),
+                    e
+                );
+            }
         }
     }
 
@@ -404,8 +764,35 @@ impl Supervisor {
             let src = self.registry.get_or_spawn(object.src_chain_id())?;
             let dst = self.registry.get_or_spawn(object.dst_chain_id())?;
 
-            let worker = self.worker_for_object(object, src, dst);
-            worker.send_events(height, events, chain_id.clone())?
+            let worker = self.worker_for_object(object.clone(), src.clone(), dst.clone());
+            if let Err(e) = worker.send_events(height, events.clone(), chain_id.clone()) {
+                match object {
+                    // Handshake workers stop once both ends are open, so late events for
+                    // their object are dropped along with the stale handle.
+                    Object::Connection(_) | Object::Channel(_) => {
+                        debug!(
+                            "handshake worker for object {} has finished, dropping its events: {}",
+                            object.short_name(),
+                            e
+                        );
+                        self.workers.remove(&object);
+                    }
+                    // The other workers only stop on failure, so they are restarted.
+                    _ => {
+                        error!(
+                            "worker for object {} has stopped unexpectedly, restarting it: {}",
+                            object.short_name(),
+                            e
+                        );
+                        self.workers.remove(&object);
+                        self.worker_for_object(object, src, dst).send_events(
+                            height,
+                            events,
+                            chain_id.clone(),
+                        )?;
+                    }
+                }
+            }
         }
 
         // If there is a NewBlock event, forward the event to any workers affected by it.
@@ -435,6 +822,8 @@ impl Supervisor {
         if self.workers.contains_key(&object) {
             &self.workers[&object]
         } else {
+            telemetry::global().worker_spawned(object.worker_type());
+
             let worker = Worker::spawn(ChainHandlePair { a: src, b: dst }, object.clone());
             let worker = self.workers.entry(object).or_insert(worker);
             worker
@@ -478,15 +867,21 @@ impl Worker {
         let span = error_span!("worker loop", worker = %object.short_name());
         let _guard = span.enter();
 
+        let worker_type = object.worker_type();
+
         let result = match object {
             Object::UnidirectionalChannelPath(path) => self.run_uni_chan_path(path),
             Object::Client(client) => self.run_client(client),
+            Object::Connection(connection) => self.run_connection(connection),
+            Object::Channel(channel) => self.run_channel(channel),
         };
 
         if let Err(e) = result {
             error!("worker error: {}", e);
         }
 
+        telemetry::global().worker_stopped(worker_type);
+
         info!("worker exits");
     }
 
@@ -513,6 +908,118 @@ impl Worker {
         }
     }
 
+    /// Upgrade the client once the source chain reaches the height of its scheduled upgrade.
+    ///
+    /// The chain no longer reports the plan of an upgrade once it is applied, so the plan is
+    /// kept by the worker from the moment it is seen. Returns the plan of the upgrade still
+    /// to be applied to the client, if any. The plans for which the chain commits to no
+    /// upgraded client state are skipped, as the client cannot be upgraded.
+    fn run_client_upgrade(
+        &self,
+        client: &ForeignClient,
+        plan: Option<UpgradePlan>,
+    ) -> Option<UpgradePlan> {
+        let src_chain = &self.chains.b;
+
+        let plan = match plan {
+            Some(plan) => plan,
+            None => match src_chain.query_upgrade_plan() {
+                Ok(Some(plan)) if !plan.has_upgraded_client_state => {
+                    debug!(
+                        "[{}] skipping upgrade '{}' of chain {}, which has no upgraded client state",
+                        client,
+                        plan.name,
+                        src_chain.id()
+                    );
+                    return None;
+                }
+                Ok(Some(plan)) => {
+                    info!(
+                        "[{}] chain {} scheduled upgrade '{}' at height {}",
+                        client,
+                        src_chain.id(),
+                        plan.name,
+                        plan.height
+                    );
+                    plan
+                }
+                Ok(None) => return None,
+                Err(e) => {
+                    debug!(
+                        "[{}] failed to query the upgrade plan of chain {}: {}",
+                        client,
+                        src_chain.id(),
+                        e
+                    );
+                    return None;
+                }
+            },
+        };
+
+        match src_chain.query_latest_height() {
+            Ok(height) if height >= plan.height => {}
+            Ok(_) => return Some(plan),
+            Err(e) => {
+                debug!(
+                    "[{}] failed to query the latest height of chain {}: {}",
+                    client,
+                    src_chain.id(),
+                    e
+                );
+                return Some(plan);
+            }
+        }
+
+        // The client may have been upgraded already, eg. by another relayer.
+        match client
+            .dst_chain()
+            .query_client_state(client.id(), Height::zero())
+        {
+            Ok(client_state) if client_state.latest_height() >= plan.height => {
+                info!(
+                    "[{}] client is already upgraded for '{}'",
+                    client, plan.name
+                );
+                return None;
+            }
+            Ok(_) => {}
+            Err(e) => {
+                error!("[{}] failed to query the client state: {}", client, e);
+                return Some(plan);
+            }
+        }
+
+        let result = client.upgrade(plan.height).and_then(|events| {
+            let rejection = events.into_iter().find_map(|event| match event {
+                IbcEvent::ChainError(reason) => Some(reason),
+                _ => None,
+            });
+
+            match rejection {
+                None => Ok(()),
+                Some(reason) => Err(ForeignClientError::ClientUpgrade(
+                    client.id().clone(),
+                    reason,
+                )),
+            }
+        });
+
+        match result {
+            Ok(()) => {
+                info!("[{}] client upgraded for '{}'", client, plan.name);
+                None
+            }
+            Err(e @ ForeignClientError::EmptyUpgradedClientState(..)) => {
+                error!("[{}] dropping upgrade '{}': {}", client, plan.name, e);
+                None
+            }
+            Err(e) => {
+                error!("[{}] failed to upgrade client: {}", client, e);
+                Some(plan)
+            }
+        }
+    }
+
     /// Run the event loop for events associated with a [`Client`].
     fn run_client(self, client: Client) -> Result<(), BoxError> {
         let mut client = ForeignClient::restore(
@@ -530,13 +1037,35 @@ impl Worker {
         let skip_misbehaviour = self.run_client_misbehaviour(&client, None);
 
         info!(
-            "running client worker (misbehaviour and refresh) for {}",
+            "running client worker (misbehaviour, refresh and upgrade) for {}",
             client
         );
 
+        let mut upgrade_plan = None;
+        let mut last_upgrade_check: Option<Instant> = None;
+
         loop {
             thread::sleep(Duration::from_millis(600));
 
+            let cmd = self.rx.try_recv().ok();
+            if let Some(WorkerCmd::Shutdown) = cmd {
+                return Ok(());
+            }
+
+            if let Some(WorkerCmd::RefreshClient) = cmd {
+                match client.build_latest_update_client_and_send() {
+                    Ok(_) => info!("client '{}' refreshed on demand", client),
+                    Err(e) => error!("failed to refresh client '{}' on demand: {}", client, e),
+                }
+            }
+
+            if last_upgrade_check
+                .map_or(true, |checked| checked.elapsed() >= UPGRADE_CHECK_INTERVAL)
+            {
+                upgrade_plan = self.run_client_upgrade(&client, upgrade_plan.take());
+                last_upgrade_check = Some(Instant::now());
+            }
+
             // Run client refresh, exit only if expired or frozen
             if let Err(e @ ForeignClientError::ExpiredOrFrozen(..)) = client.refresh() {
                 error!("failed to refresh client '{}': {}", client, e);
@@ -547,7 +1076,7 @@ impl Worker {
                 continue;
             }
 
-            if let Ok(WorkerCmd::IbcEvents { batch }) = self.rx.try_recv() {
+            if let Some(WorkerCmd::IbcEvents { batch }) = cmd {
                 trace!("client '{}' worker receives batch {:?}", client, batch);
 
                 for event in batch.events {
@@ -563,6 +1092,158 @@ impl Worker {
         }
     }
 
+    /// Run the event loop for events associated with a [`Connection`].
+    ///
+    /// Chain `a` is the chain on which the handshake was initialized, and chain `b`
+    /// its counterparty. Failures are logged and the worker waits for the next event,
+    /// as the handshake steps can safely be retried. The worker stops once the connection
+    /// is open on both chains.
+    fn run_connection(self, connection: Connection) -> Result<(), BoxError> {
+        info!("running connection worker for {}", connection.short_name());
+
+        loop {
+            thread::sleep(Duration::from_millis(200));
+
+            let cmd = self.rx.try_recv().ok();
+            if let Some(WorkerCmd::Shutdown) = cmd {
+                return Ok(());
+            }
+
+            if let Some(WorkerCmd::IbcEvents { batch }) = cmd {
+                trace!("connection worker receives batch {:?}", batch);
+
+                for event in batch.events {
+                    let result = match event {
+                        IbcEvent::OpenInitConnection(open_init) => {
+                            RelayConnection::restore_from_event(
+                                self.chains.a.clone(),
+                                self.chains.b.clone(),
+                                open_init.attributes(),
+                            )
+                            .and_then(|conn| conn.build_conn_try_and_send().map(|_| ()))
+                        }
+                        IbcEvent::OpenTryConnection(open_try) => {
+                            RelayConnection::restore_from_event(
+                                self.chains.b.clone(),
+                                self.chains.a.clone(),
+                                open_try.attributes(),
+                            )
+                            .and_then(|conn| conn.flipped().finish_handshake())
+                        }
+                        _ => Ok(()),
+                    };
+
+                    if let Err(e) = result {
+                        error!("connection handshake step failed: {}", e);
+                    }
+                }
+
+                if self.connection_open_on_both_ends(&connection) {
+                    info!(
+                        "connection {} is open on both chains",
+                        connection.short_name()
+                    );
+                    return Ok(());
+                }
+            }
+        }
+    }
+
+    /// Whether the connection is open on chain `a` and on chain `b`, which ends its handshake.
+    /// Query failures are treated as an unfinished handshake.
+    fn connection_open_on_both_ends(&self, connection: &Connection) -> bool {
+        let a_end = match self
+            .chains
+            .a
+            .query_connection(&connection.src_connection_id, Height::zero())
+        {
+            Ok(a_end) if a_end.is_open() => a_end,
+            _ => return false,
+        };
+
+        match a_end.counterparty().connection_id() {
+            Some(b_connection_id) => self
+                .chains
+                .b
+                .query_connection(b_connection_id, Height::zero())
+                .map_or(false, |b_end| b_end.is_open()),
+            None => false,
+        }
+    }
+
+    /// Run the event loop for events associated with a [`Channel`].
+    ///
+    /// Chain `a` is the chain on which the handshake was initialized, and chain `b`
+    /// its counterparty. Failures are logged and the worker waits for the next event,
+    /// as the handshake steps can safely be retried. The worker stops once the channel
+    /// is open on both chains.
+    fn run_channel(self, channel: Channel) -> Result<(), BoxError> {
+        info!("running channel worker for {}", channel.short_name());
+
+        loop {
+            thread::sleep(Duration::from_millis(200));
+
+            let cmd = self.rx.try_recv().ok();
+            if let Some(WorkerCmd::Shutdown) = cmd {
+                return Ok(());
+            }
+
+            if let Some(WorkerCmd::IbcEvents { batch }) = cmd {
+                trace!("channel worker receives batch {:?}", batch);
+
+                for event in batch.events {
+                    let result = match event {
+                        IbcEvent::OpenInitChannel(open_init) => RelayChannel::restore_from_event(
+                            self.chains.a.clone(),
+                            self.chains.b.clone(),
+                            open_init.attributes(),
+                        )
+                        .and_then(|chan| chan.build_chan_open_try_and_send().map(|_| ())),
+                        IbcEvent::OpenTryChannel(open_try) => RelayChannel::restore_from_event(
+                            self.chains.b.clone(),
+                            self.chains.a.clone(),
+                            open_try.attributes(),
+                        )
+                        .and_then(|chan| chan.flipped().finish_handshake()),
+                        _ => Ok(()),
+                    };
+
+                    if let Err(e) = result {
+                        error!("channel handshake step failed: {}", e);
+                    }
+                }
+
+                if self.channel_open_on_both_ends(&channel) {
+                    info!("channel {} is open on both chains", channel.short_name());
+                    return Ok(());
+                }
+            }
+        }
+    }
+
+    /// Whether the channel is open on chain `a` and on chain `b`, which ends its handshake.
+    /// Query failures are treated as an unfinished handshake.
+    fn channel_open_on_both_ends(&self, channel: &Channel) -> bool {
+        let a_end = match self.chains.a.query_channel(
+            &channel.src_port_id,
+            &channel.src_channel_id,
+            Height::zero(),
+        ) {
+            Ok(a_end) if a_end.is_open() => a_end,
+            _ => return false,
+        };
+
+        let b_port_id = a_end.counterparty().port_id();
+        match a_end.counterparty().channel_id() {
+            Some(b_channel_id) => self
+                .chains
+                .b
+                .query_channel(b_port_id, b_channel_id, Height::zero())
+                .map_or(false, |b_end| b_end.is_open()),
+            None => false,
+        }
+    }
+
     /// Run the event loop for events associated with a [`UnidirectionalChannelPath`].
     fn run_uni_chan_path(self, path: UnidirectionalChannelPath) -> Result<(), BoxError> {
         let mut link = Link::new_from_opts(
@@ -592,6 +1273,14 @@ impl Worker {
                         height,
                         new_block: _,
                     } => link.a_to_b.clear_packets(height),
+                    WorkerCmd::ClearPackets => self
+                        .chains
+                        .a
+                        .query_latest_height()
+                        .map_err(|e| LinkError::Failed(e.to_string()))
+                        .and_then(|height| link.a_to_b.force_clear_packets(height)),
+                    WorkerCmd::RefreshClient => Ok(()),
+                    WorkerCmd::Shutdown => return Ok(()),
                 };
 
                 if let Err(e) = result {
@@ -614,7 +1303,7 @@ impl Worker {
 }
 
 /// Client
-#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize)]
 pub struct Client {
     /// Destination chain identifier.
     pub dst_chain_id: ChainId,
@@ -636,7 +1325,7 @@ impl Client {
 }
 
 /// A unidirectional path from a source chain, channel and port.
-#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize)]
 pub struct UnidirectionalChannelPath {
     /// Destination chain identifier.
     pub dst_chain_id: ChainId,
@@ -660,18 +1349,69 @@ impl UnidirectionalChannelPath {
     }
 }
 
+/// A connection, identified by the chain on which its handshake was initialized.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize)]
+pub struct Connection {
+    /// Destination chain identifier.
+    pub dst_chain_id: ChainId,
+
+    /// Source chain identifier.
+    pub src_chain_id: ChainId,
+
+    /// Source connection identifier.
+    pub src_connection_id: ConnectionId,
+}
+
+impl Connection {
+    pub fn short_name(&self) -> String {
+        format!(
+            "{}:{}->{}",
+            self.src_connection_id, self.src_chain_id, self.dst_chain_id,
+        )
+    }
+}
+
+/// A channel, identified by the chain on which its handshake was initialized.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize)]
+pub struct Channel {
+    /// Destination chain identifier.
+    pub dst_chain_id: ChainId,
+
+    /// Source chain identifier.
+    pub src_chain_id: ChainId,
+
+    /// Source channel identifier.
+    pub src_channel_id: ChannelId,
+
+    /// Source port identifier.
+    pub src_port_id: PortId,
+}
+
+impl Channel {
+    pub fn short_name(&self) -> String {
+        format!(
+            "{}/{}:{}->{}",
+            self.src_channel_id, self.src_port_id, self.src_chain_id, self.dst_chain_id,
+        )
+    }
+}
+
 /// An object determines the amount of parallelism that can
 /// be exercised when processing [`IbcEvent`] between
 /// two chains. For each [`Object`], a corresponding
 /// [`Worker`] is spawned and all [`IbcEvent`]s mapped
 /// to an [`Object`] are sent to the associated [`Worker`]
 /// for processing.
-#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize)]
 pub enum Object {
     /// See [`Client`].
     Client(Client),
     /// See [`UnidirectionalChannelPath`].
     UnidirectionalChannelPath(UnidirectionalChannelPath),
+    /// See [`Connection`].
+    Connection(Connection),
+    /// See [`Channel`].
+    Channel(Channel),
 }
 
 impl Object {
@@ -682,6 +1422,8 @@ impl Object {
         match self {
             Object::UnidirectionalChannelPath(p) => p.src_chain_id == *src_chain_id,
             Object::Client(_) => false,
+            Object::Connection(_) => false,
+            Object::Channel(_) => false,
         }
     }
 }
@@ -698,11 +1440,25 @@ impl From<UnidirectionalChannelPath> for Object {
     }
 }
 
+impl From<Connection> for Object {
+    fn from(c: Connection) -> Self {
+        Self::Connection(c)
+    }
+}
+
+impl From<Channel> for Object {
+    fn from(c: Channel) -> Self {
+        Self::Channel(c)
+    }
+}
+
 impl Object {
     pub fn src_chain_id(&self) -> &ChainId {
         match self {
             Self::Client(ref client) => &client.src_chain_id,
             Self::UnidirectionalChannelPath(ref path) => &path.src_chain_id,
+            Self::Connection(ref connection) => &connection.src_chain_id,
+            Self::Channel(ref channel) => &channel.src_chain_id,
         }
     }
 
@@ -710,6 +1466,8 @@ impl Object {
         match self {
             Self::Client(ref client) => &client.dst_chain_id,
             Self::UnidirectionalChannelPath(ref path) => &path.dst_chain_id,
+            Self::Connection(ref connection) => &connection.dst_chain_id,
+            Self::Channel(ref channel) => &channel.dst_chain_id,
         }
     }
 
@@ -717,6 +1475,18 @@ impl Object {
         match self {
             Self::Client(ref client) => client.short_name(),
             Self::UnidirectionalChannelPath(ref path) => path.short_name(),
+            Self::Connection(ref connection) => connection.short_name(),
+            Self::Channel(ref channel) => channel.short_name(),
+        }
+    }
+
+    /// The type of the worker handling this object, as reported in the telemetry.
+    pub fn worker_type(&self) -> &'static str {
+        match self {
+            Self::Client(_) => "client",
+            Self::UnidirectionalChannelPath(_) => "packet",
+            Self::Connection(_) => "connection",
+            Self::Channel(_) => "channel",
         }
     }
 
@@ -773,6 +1543,90 @@ impl Object {
         .into())
     }
 
+    /// Build the connection object associated with the given `OpenInit` connection event,
+    /// emitted by the chain on which the handshake was initialized.
+    pub fn for_conn_open_init(
+        e: &ConnectionAttributes,
+        src_chain: &dyn ChainHandle,
+    ) -> Result<Self, BoxError> {
+        let src_connection_id = e
+            .connection_id
+            .clone()
+            .ok_or_else(|| format!("connection_id missing in OpenInit event '{:?}'", e))?;
+
+        let client_state = src_chain.query_client_state(&e.client_id, Height::zero())?;
+
+        Ok(Connection {
+            dst_chain_id: client_state.chain_id(),
+            src_chain_id: src_chain.id(),
+            src_connection_id,
+        }
+        .into())
+    }
+
+    /// Build the connection object associated with the given `OpenTry` connection event,
+    /// emitted by the counterparty of the chain on which the handshake was initialized.
+    pub fn for_conn_open_try(
+        e: &ConnectionAttributes,
+        dst_chain: &dyn ChainHandle,
+    ) -> Result<Self, BoxError> {
+        let src_connection_id = e.counterparty_connection_id.clone().ok_or_else(|| {
+            format!(
+                "counterparty connection_id missing in OpenTry event '{:?}'",
+                e
+            )
+        })?;
+
+        let client_state = dst_chain.query_client_state(&e.client_id, Height::zero())?;
+
+        Ok(Connection {
+            dst_chain_id: dst_chain.id(),
+            src_chain_id: client_state.chain_id(),
+            src_connection_id,
+        }
+        .into())
+    }
+
+    /// Build the channel object associated with the given `OpenInit` channel event,
+    /// emitted by the chain on which the handshake was initialized.
+    pub fn for_chan_open_init(
+        e: &Attributes,
+        src_chain: &dyn ChainHandle,
+    ) -> Result<Self, BoxError> {
+        let src_channel_id = e
+            .channel_id()
+            .clone()
+            .ok_or_else(|| format!("channel_id missing in OpenInit event '{:?}'", e))?;
+
+        Ok(Channel {
+            dst_chain_id: counterparty_chain_of_connection(src_chain, &e.connection_id)?,
+            src_chain_id: src_chain.id(),
+            src_channel_id,
+            src_port_id: e.port_id().clone(),
+        }
+        .into())
+    }
+
+    /// Build the channel object associated with the given `OpenTry` channel event,
+    /// emitted by the counterparty of the chain on which the handshake was initialized.
+    pub fn for_chan_open_try(
+        e: &Attributes,
+        dst_chain: &dyn ChainHandle,
+    ) -> Result<Self, BoxError> {
+        let src_channel_id = e
+            .counterparty_channel_id
+            .clone()
+            .ok_or_else(|| format!("counterparty channel_id missing in OpenTry event '{:?}'", e))?;
+
+        Ok(Channel {
+            dst_chain_id: dst_chain.id(),
+            src_chain_id: counterparty_chain_of_connection(dst_chain, &e.connection_id)?,
+            src_channel_id,
+            src_port_id: e.counterparty_port_id.clone(),
+        }
+        .into())
+    }
+
     /// Build the object associated with the given [`SendPacket`] event.
     pub fn for_send_packet(e: &SendPacket, src_chain: &dyn ChainHandle) -> Result<Self, BoxError> {
         let dst_chain_id =
@@ -841,6 +1695,17 @@ impl Object {
     }
 }
 
+/// Returns the identifier of the chain tracked by the client underlying the given connection.
+fn counterparty_chain_of_connection(
+    chain: &dyn ChainHandle,
+    connection_id: &ConnectionId,
+) -> Result<ChainId, BoxError> {
+    let connection_end = chain.query_connection(connection_id, Height::zero())?;
+    let client_state = chain.query_client_state(connection_end.client_id(), Height::zero())?;
+
+    Ok(client_state.chain_id())
+}
+
 /// Describes the result of [`collect_events`].
 #[derive(Clone, Debug)]
 pub struct CollectedEvents {
@@ -869,3 +1734,292 @@ impl CollectedEvents {
         self.new_block.is_some()
     }
 }
+
+/// Tests the handshake workers against chains built using `MockChain`.
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    use tokio::runtime::Runtime as TokioRuntime;
+
+    use ibc::events::IbcEvent;
+    use ibc::ics04_channel::channel::Order;
+    use ibc::ics24_host::identifier::PortId;
+    use ibc::Height;
+
+    use crate::chain::handle::{ChainHandle, Subscription};
+    use crate::chain::mock::test_utils::{get_basic_chain_config, schedule_upgrade};
+    use crate::chain::mock::{MockChain, TRANSFER_PORT};
+    use crate::chain::runtime::ChainRuntime;
+    use crate::chain::UpgradePlan;
+    use crate::channel::{Channel as RelayChannel, ChannelSide};
+    use crate::connection::{Connection as RelayConnection, ConnectionSide};
+    use crate::foreign_client::ForeignClient;
+
+    use super::{ChainHandlePair, Channel, Connection, Worker, WorkerHandle};
+
+    fn mock_chains(a_id: &str, b_id: &str) -> (Box<dyn ChainHandle>, Box<dyn ChainHandle>) {
+        let rt = Arc::new(TokioRuntime::new().unwrap());
+        let (a_chain, _) =
+            ChainRuntime::<MockChain>::spawn(get_basic_chain_config(a_id), rt.clone()).unwrap();
+        let (b_chain, _) =
+            ChainRuntime::<MockChain>::spawn(get_basic_chain_config(b_id), rt).unwrap();
+
+        (a_chain, b_chain)
+    }
+
+    /// Returns a worker which is not spawned, whose methods are called by the tests.
+    fn idle_worker(a: Box<dyn ChainHandle>, b: Box<dyn ChainHandle>) -> Worker {
+        Worker {
+            chains: ChainHandlePair { a, b },
+            rx: crossbeam_channel::never(),
+        }
+    }
+
+    /// Waits for the first event published by a chain which matches `predicate`.
+    fn wait_for_event(
+        subscription: &Subscription,
+        predicate: impl Fn(&IbcEvent) -> bool,
+    ) -> IbcEvent {
+        loop {
+            let batch = subscription
+                .recv_timeout(Duration::from_secs(10))
+                .expect("no matching event was published");
+
+            if let Ok(batch) = batch.as_ref() {
+                if let Some(event) = batch.events.iter().find(|e| predicate(e)) {
+                    return event.clone();
+                }
+            }
+        }
+    }
+
+    fn assert_worker_stops(worker: WorkerHandle) {
+        let (tx, rx) = crossbeam_channel::bounded(1);
+        std::thread::spawn(move || tx.send(worker.join().is_ok()));
+
+        assert_eq!(
+            rx.recv_timeout(Duration::from_secs(30)),
+            Ok(true),
+            "worker did not stop after the handshake"
+        );
+    }
+
+    #[test]
+    fn connection_worker_stops_once_open() {
+        let (a_chain, b_chain) = mock_chains("chain_a", "chain_b");
+        let client_on_a = ForeignClient::new(a_chain.clone(), b_chain.clone()).unwrap();
+        let client_on_b = ForeignClient::new(b_chain.clone(), a_chain.clone()).unwrap();
+        let b_events = b_chain.subscribe().unwrap();
+
+        // Initialize the handshake on chain a, then let the worker complete it.
+        let connection = RelayConnection {
+            delay_period: Duration::from_secs(0),
+            a_side: ConnectionSide::new(a_chain.clone(), client_on_a.id().clone(), None),
+            b_side: ConnectionSide::new(b_chain.clone(), client_on_b.id().clone(), None),
+        };
+        let open_init = connection.build_conn_init_and_send().unwrap();
+        let a_connection_id = match &open_init {
+            IbcEvent::OpenInitConnection(e) => e.connection_id().clone().unwrap(),
+            e => panic!("unexpected event {:?}", e),
+        };
+
+        let worker = Worker::spawn(
+            ChainHandlePair {
+                a: a_chain.clone(),
+                b: b_chain.clone(),
+            },
+            Connection {
+                dst_chain_id: b_chain.id(),
+                src_chain_id: a_chain.id(),
+                src_connection_id: a_connection_id.clone(),
+            }
+            .into(),
+        );
+        worker
+            .send_events(open_init.height(), vec![open_init], a_chain.id())
+            .unwrap();
+
+        // Forward the `OpenTry` event of chain b to the worker, as the supervisor would.
+        let open_try = wait_for_event(&b_events, |e| matches!(e, IbcEvent::OpenTryConnection(_)));
+        worker
+            .send_events(open_try.height(), vec![open_try], b_chain.id())
+            .unwrap();
+
+        assert_worker_stops(worker);
+
+        let a_end = a_chain
+            .query_connection(&a_connection_id, Height::zero())
+            .unwrap();
+        assert!(a_end.is_open());
+        let b_end = b_chain
+            .query_connection(
+                a_end.counterparty().connection_id().unwrap(),
+                Height::zero(),
+            )
+            .unwrap();
+        assert!(b_end.is_open());
+    }
+
+    #[test]
+    fn channel_worker_stops_once_open() {
+        let (a_chain, b_chain) = mock_chains("chain_a", "chain_b");
+        let client_on_a = ForeignClient::new(a_chain.clone(), b_chain.clone()).unwrap();
+        let client_on_b = ForeignClient::new(b_chain.clone(), a_chain.clone()).unwrap();
+        let connection =
+            RelayConnection::new(client_on_a, client_on_b, Duration::from_secs(0)).unwrap();
+        let b_events = b_chain.subscribe().unwrap();
+
+        // Initialize the handshake on chain a, then let the worker complete it.
+        let port_id = PortId::from_str(TRANSFER_PORT).unwrap();
+        let channel = RelayChannel {
+            ordering: Order::Unordered,
+            a_side: ChannelSide::new(
+                a_chain.clone(),
+                connection.src_client_id().clone(),
+                connection.src_connection_id().cloned().unwrap(),
+                port_id.clone(),
+                None,
+            ),
+            b_side: ChannelSide::new(
+                b_chain.clone(),
+                connection.dst_client_id().clone(),
+                connection.dst_connection_id().cloned().unwrap(),
+                port_id.clone(),
+                None,
+            ),
+            connection_delay: connection.delay_period,
+            version: None,
+        };
+        let open_init = channel.build_chan_open_init_and_send().unwrap();
+        let a_channel_id = match &open_init {
+            IbcEvent::OpenInitChannel(e) => e.channel_id().clone().unwrap(),
+            e => panic!("unexpected event {:?}", e),
+        };
+
+        // The counterparty channel end does not exist yet, so its identifier is unknown.
+        let restored = match &open_init {
+            IbcEvent::OpenInitChannel(e) => {
+                RelayChannel::restore_from_event(a_chain.clone(), b_chain.clone(), e.attributes())
+                    .unwrap()
+            }
+            e => panic!("unexpected event {:?}", e),
+        };
+        assert_eq!(restored.src_channel_id(), Some(&a_channel_id));
+        assert_eq!(restored.dst_channel_id(), None);
+
+        let worker = Worker::spawn(
+            ChainHandlePair {
+                a: a_chain.clone(),
+                b: b_chain.clone(),
+            },
+            Channel {
+                dst_chain_id: b_chain.id(),
+                src_chain_id: a_chain.id(),
+                src_channel_id: a_channel_id.clone(),
+                src_port_id: port_id.clone(),
+            }
+            .into(),
+        );
+        worker
+            .send_events(open_init.height(), vec![open_init], a_chain.id())
+            .unwrap();
+
+        // Forward the `OpenTry` event of chain b to the worker, as the supervisor would.
+        let open_try = wait_for_event(&b_events, |e| matches!(e, IbcEvent::OpenTryChannel(_)));
+        worker
+            .send_events(open_try.height(), vec![open_try], b_chain.id())
+            .unwrap();
+
+        assert_worker_stops(worker);
+
+        let a_end = a_chain
+            .query_channel(&port_id, &a_channel_id, Height::zero())
+            .unwrap();
+        assert!(a_end.is_open());
+        let b_end = b_chain
+            .query_channel(
+                &port_id,
+                a_end.counterparty().channel_id().unwrap(),
+                Height::zero(),
+            )
+            .unwrap();
+        assert!(b_end.is_open());
+    }
+
+    #[test]
+    fn client_worker_upgrades_client_once() {
+        let (a_chain, b_chain) = mock_chains("chain_upgrade_a", "chain_upgrade_b");
+        let client = ForeignClient::new(a_chain.clone(), b_chain.clone()).unwrap();
+        let a_events = a_chain.subscribe().unwrap();
+        let worker = idle_worker(a_chain.clone(), b_chain.clone());
+
+        let upgrade_height = b_chain.query_latest_height().unwrap().add(3);
+        schedule_upgrade(
+            &b_chain.id(),
+            UpgradePlan {
+                name: "upgrade".to_string(),
+                height: upgrade_height,
+                has_upgraded_client_state: true,
+            },
+        );
+
+        // The worker keeps the plan until chain b reaches the upgrade height.
+        let plan = worker.run_client_upgrade(&client, None);
+        assert!(plan.is_some());
+
+        // Each client created on chain b commits a block.
+        while b_chain.query_latest_height().unwrap() < upgrade_height {
+            ForeignClient::new(b_chain.clone(), a_chain.clone()).unwrap();
+        }
+
+        assert!(worker.run_client_upgrade(&client, plan).is_none());
+        wait_for_event(&a_events, |e| matches!(e, IbcEvent::UpgradeClient(_)));
+
+        // Chain b no longer reports the applied plan, so the client is not upgraded again.
+        for _ in 0..3 {
+            assert!(worker.run_client_upgrade(&client, None).is_none());
+        }
+        while let Ok(batch) = a_events.recv_timeout(Duration::from_secs(1)) {
+            if let Ok(batch) = batch.as_ref() {
+                assert!(
+                    !batch
+                        .events
+                        .iter()
+                        .any(|e| matches!(e, IbcEvent::UpgradeClient(_))),
+                    "client upgraded more than once"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn client_worker_skips_upgrades_without_client_state() {
+        let (a_chain, b_chain) = mock_chains("chain_no_upgrade_a", "chain_no_upgrade_b");
+        let client = ForeignClient::new(a_chain.clone(), b_chain.clone()).unwrap();
+        let worker = idle_worker(a_chain.clone(), b_chain.clone());
+
+        // A plan committing to no upgraded client state is ignored.
+        let upgrade_height = b_chain.query_latest_height().unwrap().add(3);
+        schedule_upgrade(
+            &b_chain.id(),
+            UpgradePlan {
+                name: "upgrade".to_string(),
+                height: upgrade_height,
+                has_upgraded_client_state: false,
+            },
+        );
+        assert!(worker.run_client_upgrade(&client, None).is_none());
+
+        // A plan for which the chain has no upgraded client state is dropped once reached.
+        ForeignClient::new(b_chain.clone(), a_chain.clone()).unwrap();
+        let plan = UpgradePlan {
+            name: "unknown".to_string(),
+            height: b_chain.query_latest_height().unwrap(),
+            has_upgraded_client_state: true,
+        };
+        assert!(worker.run_client_upgrade(&client, Some(plan)).is_none());
+    }
+}
@@ -0,0 +1,154 @@
+//! Reloading of the configuration of a running [`Supervisor`](super::Supervisor).
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    time::{Duration, Instant, SystemTime},
+};
+
+use tracing::error;
+
+use ibc::ics24_host::identifier::ChainId;
+
+use crate::config::{self, Config};
+
+/// How often the configuration file is checked for modifications.
+const CONFIG_POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+/// Watches the configuration file for modifications, by polling its modification time.
+#[derive(Debug)]
+pub struct ConfigWatcher {
+    path: PathBuf,
+    last_modified: Option<SystemTime>,
+    last_check: Instant,
+}
+
+impl ConfigWatcher {
+    pub fn new(path: PathBuf) -> Self {
+        let last_modified = modified(&path);
+
+        Self {
+            path,
+            last_modified,
+            last_check: Instant::now(),
+        }
+    }
+
+    /// Returns the new configuration if the file was modified since the last check,
+    /// and can be parsed. Checks the file at most once every `CONFIG_POLL_INTERVAL`.
+    pub fn poll(&mut self) -> Option<Config> {
+        if self.last_check.elapsed() < CONFIG_POLL_INTERVAL {
+            return None;
+        }
+
+        self.last_check = Instant::now();
+
+        let modified = modified(&self.path);
+        if modified == self.last_modified {
+            return None;
+        }
+
+        self.last_modified = modified;
+
+        match config::parse(&self.path) {
+            Ok(config) => Some(config),
+            Err(e) => {
+                error!(
+                    "failed to reload the configuration from {}, keeping the current one: {}",
+                    self.path.display(),
+                    e
+                );
+
+                None
+            }
+        }
+    }
+}
+
+fn modified(path: &Path) -> Option<SystemTime> {
+    fs::metadata(path).and_then(|m| m.modified()).ok()
+}
+
+/// The chains which differ between two configurations.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ConfigDiff {
+    /// Chains only present in the new configuration.
+    pub added_chains: Vec<ChainId>,
+    /// Chains only present in the old configuration.
+    pub removed_chains: Vec<ChainId>,
+    /// Chains present in both configurations, with a different configuration.
+    pub updated_chains: Vec<ChainId>,
+}
+
+impl ConfigDiff {
+    pub fn new(old: &Config, new: &Config) -> Self {
+        let mut diff = Self::default();
+
+        for chain in &new.chains {
+            match old.find_chain(&chain.id) {
+                None => diff.added_chains.push(chain.id.clone()),
+                Some(old_chain) if old_chain != chain => diff.updated_chains.push(chain.id.clone()),
+                Some(_) => {}
+            }
+        }
+
+        for chain in &old.chains {
+            if new.find_chain(&chain.id).is_none() {
+                diff.removed_chains.push(chain.id.clone());
+            }
+        }
+
+        diff
+    }
+
+    /// The chains whose runtime and workers must be stopped.
+    pub fn stopped_chains(&self) -> impl Iterator<Item = &ChainId> {
+        self.removed_chains.iter().chain(self.updated_chains.iter())
+    }
+
+    /// The chains whose runtime must be (re)started.
+    pub fn started_chains(&self) -> impl Iterator<Item = &ChainId> {
+        self.added_chains.iter().chain(self.updated_chains.iter())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::chain::mock::test_utils::get_basic_chain_config;
+    use crate::config::Config;
+
+    use super::ConfigDiff;
+
+    #[test]
+    fn diff_chains() {
+        let old = Config {
+            chains: vec![
+                get_basic_chain_config("chain_a"),
+                get_basic_chain_config("chain_b"),
+                get_basic_chain_config("chain_c"),
+            ],
+            ..Config::default()
+        };
+
+        let mut updated = get_basic_chain_config("chain_b");
+        updated.max_msg_num = Some(10);
+
+        let new = Config {
+            chains: vec![
+                get_basic_chain_config("chain_a"),
+                updated,
+                get_basic_chain_config("chain_d"),
+            ],
+            ..Config::default()
+        };
+
+        let diff = ConfigDiff::new(&old, &new);
+
+        let ids = |ids: &[&str]| ids.iter().map(|id| id.parse().unwrap()).collect::<Vec<_>>();
+        assert_eq!(diff.added_chains, ids(&["chain_d"]));
+        assert_eq!(diff.removed_chains, ids(&["chain_c"]));
+        assert_eq!(diff.updated_chains, ids(&["chain_b"]));
+
+        assert_eq!(ConfigDiff::new(&new, &new), ConfigDiff::default());
+    }
+}
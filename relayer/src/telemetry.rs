@@ -0,0 +1,21 @@
+//! Telemetry of the relayer, exposed in the Prometheus text format.
+//!
+//! The metrics are recorded in a global [`TelemetryState`], returned by [`global`], and served
+//! over HTTP at `/metrics` by the [`server`] when telemetry is enabled in the [`GlobalConfig`].
+//!
+//! [`GlobalConfig`]: crate::config::GlobalConfig
+
+use once_cell::sync::Lazy;
+
+pub mod server;
+pub mod state;
+
+pub use server::spawn;
+pub use state::TelemetryState;
+
+static TELEMETRY: Lazy<TelemetryState> = Lazy::new(TelemetryState::default);
+
+/// Returns the telemetry state of the relayer, shared by all its components.
+pub fn global() -> &'static TelemetryState {
+    &TELEMETRY
+}
@@ -0,0 +1,55 @@
+use std::thread;
+
+use tracing::{error, info};
+
+use crate::config::TelemetryConfig;
+use crate::error::{self, Error};
+
+use super::global;
+
+/// Spawns a thread serving the metrics of the relayer over HTTP at `/metrics`,
+/// on the address configured in `config`.
+pub fn spawn(config: &TelemetryConfig) -> Result<thread::JoinHandle<()>, Error> {
+    let address = (config.host.as_str(), config.port);
+    let server = tiny_http::Server::http(address).map_err(|e| error::Kind::Telemetry.context(e))?;
+
+    info!(
+        "telemetry service running, exposing metrics at http://{}:{}/metrics",
+        config.host, config.port
+    );
+
+    let handle = thread::spawn(move || {
+        for request in server.incoming_requests() {
+            let response = match request.url() {
+                "/metrics" => metrics_response(),
+                _ => tiny_http::Response::from_string("not found")
+                    .with_status_code(tiny_http::StatusCode(404)),
+            };
+
+            if let Err(e) = request.respond(response) {
+                error!("failed to respond to telemetry request: {}", e);
+            }
+        }
+    });
+
+    Ok(handle)
+}
+
+fn metrics_response() -> tiny_http::Response<std::io::Cursor<Vec<u8>>> {
+    match global().encode() {
+        Ok(metrics) => {
+            let content_type = tiny_http::Header::from_bytes(
+                &b"Content-Type"[..],
+                &b"text/plain; version=0.0.4"[..],
+            )
+            .unwrap();
+
+            tiny_http::Response::from_data(metrics).with_header(content_type)
+        }
+        Err(e) => {
+            error!("failed to encode metrics: {}", e);
+            tiny_http::Response::from_string("failed to encode metrics")
+                .with_status_code(tiny_http::StatusCode(500))
+        }
+    }
+}
@@ -0,0 +1,175 @@
+use prometheus::{Encoder, IntCounterVec, IntGaugeVec, Opts, Registry, TextEncoder};
+
+use ibc::ics24_host::identifier::{ChainId, ChannelId, ClientId, PortId};
+
+/// The metrics recorded by the relayer.
+#[derive(Clone, Debug)]
+pub struct TelemetryState {
+    registry: Registry,
+
+    /// Number of packet messages relayed, per unidirectional channel path
+    packets_relayed: IntCounterVec,
+
+    /// Number of transactions submitted, per chain
+    tx_submitted: IntCounterVec,
+
+    /// Number of transactions which failed, per chain
+    tx_failed: IntCounterVec,
+
+    /// Number of client update messages built, per chain and client
+    client_updates: IntCounterVec,
+
+    /// Number of workers spawned by the supervisor, per type of object
+    workers: IntGaugeVec,
+
+    /// Number of reconnections of the event monitor to the WebSocket endpoint, per chain
+    ws_reconnects: IntCounterVec,
+}
+
+impl TelemetryState {
+    pub fn packets_relayed(
+        &self,
+        src_chain: &ChainId,
+        src_channel: &ChannelId,
+        src_port: &PortId,
+        dst_chain: &ChainId,
+        count: u64,
+    ) {
+        self.packets_relayed
+            .with_label_values(&[
+                src_chain.to_string().as_str(),
+                src_channel.to_string().as_str(),
+                src_port.to_string().as_str(),
+                dst_chain.to_string().as_str(),
+            ])
+            .inc_by(count);
+    }
+
+    pub fn tx_submitted(&self, chain: &ChainId) {
+        self.tx_submitted
+            .with_label_values(&[chain.to_string().as_str()])
+            .inc();
+    }
+
+    pub fn tx_failed(&self, chain: &ChainId) {
+        self.tx_failed
+            .with_label_values(&[chain.to_string().as_str()])
+            .inc();
+    }
+
+    pub fn client_update(&self, dst_chain: &ChainId, client: &ClientId) {
+        self.client_updates
+            .with_label_values(&[dst_chain.to_string().as_str(), client.to_string().as_str()])
+            .inc();
+    }
+
+    pub fn worker_spawned(&self, worker_type: &str) {
+        self.workers.with_label_values(&[worker_type]).inc();
+    }
+
+    pub fn worker_stopped(&self, worker_type: &str) {
+        self.workers.with_label_values(&[worker_type]).dec();
+    }
+
+    pub fn ws_reconnect(&self, chain: &ChainId) {
+        self.ws_reconnects
+            .with_label_values(&[chain.to_string().as_str()])
+            .inc();
+    }
+
+    /// Encodes all the metrics in the Prometheus text format.
+    pub fn encode(&self) -> Result<Vec<u8>, prometheus::Error> {
+        let mut buffer = Vec::new();
+        TextEncoder::new().encode(&self.registry.gather(), &mut buffer)?;
+        Ok(buffer)
+    }
+}
+
+impl Default for TelemetryState {
+    fn default() -> Self {
+        let registry = Registry::new_custom(Some("hermes".to_string()), None).unwrap();
+
+        let packets_relayed = IntCounterVec::new(
+            Opts::new("packets_relayed", "Number of packet messages relayed"),
+            &["src_chain", "src_channel", "src_port", "dst_chain"],
+        )
+        .unwrap();
+
+        let tx_submitted = IntCounterVec::new(
+            Opts::new("tx_submitted", "Number of transactions submitted"),
+            &["chain"],
+        )
+        .unwrap();
+
+        let tx_failed = IntCounterVec::new(
+            Opts::new("tx_failed", "Number of transactions which failed"),
+            &["chain"],
+        )
+        .unwrap();
+
+        let client_updates = IntCounterVec::new(
+            Opts::new("client_updates", "Number of client update messages built"),
+            &["chain", "client"],
+        )
+        .unwrap();
+
+        let workers = IntGaugeVec::new(
+            Opts::new("workers", "Number of workers spawned by the supervisor"),
+            &["type"],
+        )
+        .unwrap();
+
+        let ws_reconnects = IntCounterVec::new(
+            Opts::new(
+                "ws_reconnects",
+                "Number of reconnections of the event monitor to the WebSocket endpoint",
+            ),
+            &["chain"],
+        )
+        .unwrap();
+
+        registry
+            .register(Box::new(packets_relayed.clone()))
+            .unwrap();
+        registry.register(Box::new(tx_submitted.clone())).unwrap();
+        registry.register(Box::new(tx_failed.clone())).unwrap();
+        registry.register(Box::new(client_updates.clone())).unwrap();
+        registry.register(Box::new(workers.clone())).unwrap();
+        registry.register(Box::new(ws_reconnects.clone())).unwrap();
+
+        Self {
+            registry,
+            packets_relayed,
+            tx_submitted,
+            tx_failed,
+            client_updates,
+            workers,
+            ws_reconnects,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use ibc::ics24_host::identifier::ChainId;
+
+    use super::TelemetryState;
+
+    #[test]
+    fn encode_metrics() {
+        let state = TelemetryState::default();
+        let chain = ChainId::from_str("ibc-0").unwrap();
+
+        state.tx_submitted(&chain);
+        state.tx_submitted(&chain);
+        state.tx_failed(&chain);
+        state.worker_spawned("client");
+
+        let encoded = String::from_utf8(state.encode().unwrap()).unwrap();
+        assert!(encoded.contains("hermes_tx_submitted{chain=\"ibc-0\"} 2"));
+        assert!(encoded.contains("hermes_tx_failed{chain=\"ibc-0\"} 1"));
+        assert!(encoded.contains("hermes_workers{type=\"client\"} 1"));
+    }
+}
@@ -6,7 +6,7 @@ use ibc::events::IbcEvent;
 use ibc::ics24_host::identifier::{ChainId, ChannelId, PortId};
 use ibc::tx_msg::Msg;
 
-use crate::chain::{Chain, CosmosSdkChain};
+use crate::chain::handle::ChainHandle;
 use crate::config::ChainConfig;
 use crate::error::Error;
 
@@ -38,8 +38,8 @@ pub struct TransferOptions {
 }
 
 pub fn build_and_send_transfer_messages(
-    mut packet_src_chain: CosmosSdkChain, // the chain whose account is debited
-    mut packet_dst_chain: CosmosSdkChain, // the chain where the transfer is sent
+    packet_src_chain: Box<dyn ChainHandle>, // the chain whose account is debited
+    packet_dst_chain: Box<dyn ChainHandle>, // the chain where the transfer is sent
     opts: TransferOptions,
 ) -> Result<Vec<IbcEvent>, PacketError> {
     let receiver = match &opts.receiver {
@@ -74,7 +74,7 @@ pub fn build_and_send_transfer_messages(
 
     let events = packet_src_chain
         .send_msgs(msgs)
-        .map_err(|e| PacketError::SubmitError(packet_src_chain.id().clone(), e))?;
+        .map_err(|e| PacketError::SubmitError(packet_src_chain.id(), e))?;
 
     // Check if the chain rejected the transaction
     let result = events
@@ -4,6 +4,7 @@ use prost_types::Any;
 use thiserror::Error;
 use tracing::error;
 
+use ibc::downcast;
 use ibc::ics02_client::client_state::AnyClientState;
 use ibc::ics02_client::height::Height;
 use ibc::ics24_host::identifier::{ChainId, ClientId};
@@ -11,7 +12,7 @@ use ibc::{events::IbcEvent, ics07_tendermint::client_state::ClientState};
 use ibc_proto::cosmos::gov::v1beta1::MsgSubmitProposal;
 use ibc_proto::cosmos::upgrade::v1beta1::{Plan, SoftwareUpgradeProposal};
 
-use crate::chain::{Chain, CosmosSdkChain};
+use crate::chain::handle::ChainHandle;
 use crate::config::ChainConfig;
 use crate::error::Error;
 
@@ -39,8 +40,8 @@ pub struct UpdatePlanOptions {
 }
 
 pub fn build_and_send_upgrade_chain_message(
-    mut dst_chain: CosmosSdkChain, // the chain whose account is debited
-    src_chain: CosmosSdkChain,     // the chain where the transfer is sent
+    dst_chain: Box<dyn ChainHandle>, // the chain whose account is debited
+    src_chain: Box<dyn ChainHandle>, // the chain where the transfer is sent
     opts: &UpdatePlanOptions,
 ) -> Result<Vec<IbcEvent>, UpgradeChainError> {
     // build a proposal Plan
@@ -52,6 +53,12 @@ pub fn build_and_send_upgrade_chain_message(
     let client_state = src_chain
         .query_client_state(&opts.src_client_id, Height::zero())
         .unwrap();
+    let client_state = downcast!(client_state => AnyClientState::Tendermint).ok_or_else(|| {
+        UpgradeChainError::Failed(format!(
+            "client {} is not a Tendermint client",
+            opts.src_client_id
+        ))
+    })?;
 
     let mut upgraded_client_state = ClientState::zero_custom_fields(client_state);
     upgraded_client_state.latest_height = upgrade_height.increment();
@@ -106,7 +113,7 @@ pub fn build_and_send_upgrade_chain_message(
 
     let events = dst_chain
         .send_msgs(vec![any_msg])
-        .map_err(|e| UpgradeChainError::SubmitError(dst_chain.id().clone(), e))?;
+        .map_err(|e| UpgradeChainError::SubmitError(dst_chain.id(), e))?;
 
     // Check if the chain rejected the transaction
     let result = events.iter().find_map(|event| match event {
@@ -1,6 +1,7 @@
 mod block_on;
 pub use block_on::block_on;
 
+pub mod failover;
 pub mod iter;
 pub mod retry;
 pub mod sled;
@@ -0,0 +1,108 @@
+//! Rotation among equivalent endpoints, skipping the ones which recently failed.
+
+use std::time::{Duration, Instant};
+
+/// How long an endpoint which failed is considered unhealthy.
+const UNHEALTHY_PERIOD: Duration = Duration::from_secs(60);
+
+/// A non-empty list of equivalent endpoints, of which one is in use at a time.
+///
+/// When the endpoint in use fails, it is considered unhealthy for a while, and the next
+/// healthy endpoint is used instead. If all the endpoints are unhealthy, the next one is used.
+#[derive(Clone, Debug)]
+pub struct Failover<T> {
+    endpoints: Vec<T>,
+    unhealthy_until: Vec<Option<Instant>>,
+    current: usize,
+}
+
+impl<T> Failover<T> {
+    /// Creates a rotation starting with the first endpoint.
+    ///
+    /// ## Panics
+    /// If `endpoints` is empty.
+    pub fn new(endpoints: Vec<T>) -> Self {
+        assert!(!endpoints.is_empty(), "no endpoint to fail over to");
+
+        Self {
+            unhealthy_until: vec![None; endpoints.len()],
+            endpoints,
+            current: 0,
+        }
+    }
+
+    /// The endpoint in use.
+    pub fn current(&self) -> &T {
+        &self.endpoints[self.current]
+    }
+
+    /// The number of endpoints in the rotation.
+    pub fn len(&self) -> usize {
+        self.endpoints.len()
+    }
+
+    /// Always `false`, as a rotation has at least one endpoint.
+    pub fn is_empty(&self) -> bool {
+        self.endpoints.is_empty()
+    }
+
+    /// Marks the endpoint in use as unhealthy, and switches to the next endpoint,
+    /// which is returned.
+    pub fn failed(&mut self) -> &T {
+        self.failed_at(Instant::now())
+    }
+
+    fn failed_at(&mut self, now: Instant) -> &T {
+        let len = self.endpoints.len();
+        self.unhealthy_until[self.current] = Some(now + UNHEALTHY_PERIOD);
+
+        let unhealthy_until = &self.unhealthy_until;
+        let current = self.current;
+
+        self.current = (1..=len)
+            .map(|offset| (current + offset) % len)
+            .find(|&index| unhealthy_until[index].map_or(true, |until| until <= now))
+            .unwrap_or((current + 1) % len);
+
+        self.current()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Instant;
+
+    use super::{Failover, UNHEALTHY_PERIOD};
+
+    #[test]
+    fn skips_unhealthy_endpoints() {
+        let now = Instant::now();
+        let mut endpoints = Failover::new(vec!["a", "b", "c"]);
+        assert_eq!(*endpoints.current(), "a");
+
+        assert_eq!(*endpoints.failed_at(now), "b");
+        assert_eq!(*endpoints.failed_at(now), "c");
+
+        // All the endpoints are unhealthy, so the next one is used.
+        assert_eq!(*endpoints.failed_at(now), "a");
+
+        // `b` and `c` are healthy again, unlike `a` which just failed again.
+        let later = now + UNHEALTHY_PERIOD;
+        assert_eq!(*endpoints.failed_at(later), "b");
+        assert_eq!(*endpoints.failed_at(later), "c");
+
+        // All the endpoints failed again, so the next one is used.
+        assert_eq!(*endpoints.failed_at(later), "a");
+
+        // The endpoints are healthy again, but for `a` which just failed.
+        let even_later = later + UNHEALTHY_PERIOD;
+        assert_eq!(*endpoints.failed_at(even_later), "b");
+    }
+
+    #[test]
+    fn single_endpoint() {
+        let mut endpoints = Failover::new(vec!["a"]);
+        assert_eq!(*endpoints.failed(), "a");
+        assert_eq!(endpoints.len(), 1);
+    }
+}
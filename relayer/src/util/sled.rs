@@ -95,4 +95,25 @@ where
 
         Ok(())
     }
+
+    pub fn remove(&self, db: &sled::Db, key: &K) -> Result<(), error::Error> {
+        let key_bytes = serde_cbor::to_vec(&key).map_err(|e| error::Kind::Store.context(e))?;
+
+        let prefixed_key_bytes = self.prefixed_key(key_bytes);
+
+        db.remove(prefixed_key_bytes)
+            .map(|_| ())
+            .map_err(|e| error::Kind::Store.context(e))?;
+
+        Ok(())
+    }
+
+    /// Iterate over all the values stored under the prefix of this `KeyValueDb`.
+    /// Entries which cannot be read or decoded are skipped.
+    pub fn values(&self, db: &sled::Db) -> impl Iterator<Item = V> {
+        db.scan_prefix(&self.prefix)
+            .values()
+            .filter_map(|bytes| bytes.ok())
+            .filter_map(|bytes| serde_cbor::from_slice(&bytes).ok())
+    }
 }